@@ -0,0 +1,71 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use serde_json::json;
+use std::hint::black_box;
+use tracks_rs::{
+    point_definition::vector3_point_definition::Vector3PointDefinition,
+    values::{AbstractValueProvider, ValueProvider, base_provider_context::BaseProviderContext},
+};
+
+const BASE: &str = "baseHeadPosition";
+
+/// Resolve the name on every read and copy the values out, like base providers used to
+fn values_by_name(context: &BaseProviderContext, n: u64) {
+    for _ in 0..n {
+        black_box(
+            context
+                .try_get_values(black_box(BASE))
+                .unwrap()
+                .as_slice()
+                .to_vec(),
+        );
+    }
+}
+
+/// Read through the id resolved at parse time, borrowing from the context
+fn values_by_id(provider: &ValueProvider, context: &BaseProviderContext, n: u64) {
+    for _ in 0..n {
+        black_box(provider.values(context));
+    }
+}
+
+fn point_step(n: u64) {
+    let mut context = BaseProviderContext::new();
+    let definition = Vector3PointDefinition::new(
+        json!([
+            [BASE, 0.0],
+            [BASE, [0.0, 1.0, 0.0, "opAdd"], 1.0, "easeInOutSine"]
+        ]),
+        &mut context,
+    );
+
+    let values: Vec<f64> = (0..=(n as usize)).map(|i| i as f64 / n as f64).collect();
+
+    values.into_iter().for_each(|x| {
+        black_box(definition.interpolate(x as f32, &context));
+    });
+}
+
+fn benchmark_all(n: u64, c: &mut Criterion) {
+    let mut group = c.benchmark_group("base_provider");
+    let mut context = BaseProviderContext::new();
+    let provider = context.try_get_value_provider(BASE).unwrap();
+
+    group.bench_with_input(criterion::BenchmarkId::new("by_id", n), &n, |b, n| {
+        b.iter(|| values_by_id(&provider, &context, *n))
+    });
+    group.bench_with_input(criterion::BenchmarkId::new("by_name", n), &n, |b, n| {
+        b.iter(|| values_by_name(&context, *n))
+    });
+    group.bench_with_input(criterion::BenchmarkId::new("vec3", n), &n, |b, n| {
+        b.iter(|| point_step(*n))
+    });
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    benchmark_all(1000, c);
+    benchmark_all(10000, c);
+    benchmark_all(100000, c);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,82 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use glam::Vec3;
+use serde_json::{Value, json};
+use std::hint::black_box;
+use tracks_rs::{
+    ffi::{WrapVec3, tracks_interpolate_vector3, tracks_interpolate_vector3_batch},
+    point_definition::vector3_point_definition::Vector3PointDefinition,
+    values::base_provider_context::BaseProviderContext,
+};
+
+/// Enough points that the binary search is not free
+fn definition(context: &mut BaseProviderContext) -> Vector3PointDefinition {
+    let points: Vec<Value> = (0..=32)
+        .map(|i| {
+            let t = i as f32 / 32.0;
+            json!([t.sin(), t * 2.0, t.cos(), t, "easeInOutSine"])
+        })
+        .collect();
+    Vector3PointDefinition::new(Value::Array(points), context)
+}
+
+/// Sorted times, like notes spread over the definition
+fn times(n: usize) -> Vec<f32> {
+    (0..n).map(|i| i as f32 / n as f32).collect()
+}
+
+fn benchmark_all(n: usize, c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch");
+    let mut context = BaseProviderContext::new();
+    let definition = definition(&mut context);
+    let times = times(n);
+    let mut out = vec![Vec3::ZERO; n];
+    let mut wrapped = vec![WrapVec3::from(Vec3::ZERO); n];
+
+    group.bench_with_input(BenchmarkId::new("scalar", n), &n, |b, _| {
+        b.iter(|| {
+            for (time, out) in times.iter().zip(out.iter_mut()) {
+                *out = definition.interpolate(black_box(*time), &context).0;
+            }
+            black_box(&out);
+        })
+    });
+    group.bench_with_input(BenchmarkId::new("many", n), &n, |b, _| {
+        b.iter(|| {
+            definition.interpolate_many(black_box(&times), &mut out, &context);
+            black_box(&out);
+        })
+    });
+    group.bench_with_input(BenchmarkId::new("ffi_scalar", n), &n, |b, _| {
+        b.iter(|| {
+            for (time, out) in times.iter().zip(wrapped.iter_mut()) {
+                *out = unsafe {
+                    tracks_interpolate_vector3(&definition, black_box(*time), &mut context)
+                }
+                .value;
+            }
+            black_box(&wrapped);
+        })
+    });
+    group.bench_with_input(BenchmarkId::new("ffi_batch", n), &n, |b, _| {
+        b.iter(|| {
+            unsafe {
+                tracks_interpolate_vector3_batch(
+                    &definition,
+                    black_box(times.as_ptr()),
+                    wrapped.as_mut_ptr(),
+                    n,
+                    &mut context,
+                )
+            };
+            black_box(&wrapped);
+        })
+    });
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    benchmark_all(100, c);
+    benchmark_all(1000, c);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -2,18 +2,18 @@ use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
 use serde_json::json;
 use std::hint::black_box;
 use tracks_rs::{
-    point_definition::{PointDefinition, vector4_point_definition::Vector4PointDefinition},
+    point_definition::vector4_point_definition::Vector4PointDefinition,
     values::base_provider_context::BaseProviderContext,
 };
 
 fn point_step(n: u64) {
-    let context = BaseProviderContext::new();
+    let mut context = BaseProviderContext::new();
     let definition = Vector4PointDefinition::new(
         json!([
             [0.0, 1.0, 0.0, 0.0, 0.0],
             [1.0, 0.0, 1.0, 1.0, 1.0, "easeInOutSine"]
         ]),
-        &context,
+        &mut context,
     );
 
     // let step = 1.0 / n as f32;
@@ -2,14 +2,14 @@ use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
 use serde_json::json;
 use std::hint::black_box;
 use tracks_rs::{
-    point_definition::{PointDefinition, float_point_definition::FloatPointDefinition},
+    point_definition::float_point_definition::FloatPointDefinition,
     values::base_provider_context::BaseProviderContext,
 };
 
 fn point_step(n: u64) {
-    let context = BaseProviderContext::new();
+    let mut context = BaseProviderContext::new();
     let definition =
-        FloatPointDefinition::new(json!([[0.0, 0.0], [1.0, 1.0, "easeInOutSine"]]), &context);
+        FloatPointDefinition::new(json!([[0.0, 0.0], [1.0, 1.0, "easeInOutSine"]]), &mut context);
 
     // let step = 1.0 / n as f32;
 
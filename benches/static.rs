@@ -0,0 +1,94 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use serde_json::{Value, json};
+use std::hint::black_box;
+use tracks_rs::{
+    point_definition::vector3_point_definition::Vector3PointDefinition,
+    values::base_provider_context::BaseProviderContext,
+};
+
+fn points() -> Value {
+    json!([
+        [0.0, 0.0, 0.0, 0.0],
+        [1.0, 2.0, 3.0, 0.25, "easeInOutSine"],
+        [-1.0, 0.0, 5.0, 0.5, "splineCatmullRom"],
+        [4.0, 4.0, 4.0, 0.75, "easeOutQuad"],
+        [0.0, 1.0, 0.0, 1.0, "easeInOutSine"]
+    ])
+}
+
+/// Same values, but reading a base provider keeps every point on the modifier path
+fn points_with_base() -> Value {
+    json!([
+        [0.0, 0.0, 0.0, 0.0],
+        [
+            1.0,
+            2.0,
+            3.0,
+            0.25,
+            "easeInOutSine",
+            ["baseHeadPosition", "opAdd"]
+        ],
+        [-1.0, 0.0, 5.0, 0.5, "splineCatmullRom"],
+        [4.0, 4.0, 4.0, 0.75, "easeOutQuad"],
+        [0.0, 1.0, 0.0, 1.0, "easeInOutSine"]
+    ])
+}
+
+fn times(n: u64) -> Vec<f32> {
+    (0..=n).map(|i| i as f32 / n as f32).collect()
+}
+
+fn point_step(definition: &Vector3PointDefinition, context: &BaseProviderContext, times: &[f32]) {
+    for time in times {
+        black_box(definition.interpolate(black_box(*time), context));
+    }
+}
+
+fn point_step_slow(
+    definition: &track_rs_old::point_definition::vector3_point_definition::Vector3PointDefinition,
+    context: &track_rs_old::values::base_provider_context::BaseProviderContext,
+    times: &[f32],
+) {
+    for time in times {
+        black_box(
+            track_rs_old::point_definition::PointDefinition::interpolate(
+                definition,
+                black_box(*time),
+                context,
+            ),
+        );
+    }
+}
+
+fn benchmark_all(n: u64, c: &mut Criterion) {
+    let mut group = c.benchmark_group("static");
+    let times = times(n);
+
+    let mut context = BaseProviderContext::new();
+    let baked = Vector3PointDefinition::new(points(), &mut context);
+    let unbaked = Vector3PointDefinition::new(points_with_base(), &mut context);
+
+    let old_context = track_rs_old::values::base_provider_context::BaseProviderContext::new();
+    let old = track_rs_old::point_definition::vector3_point_definition::Vector3PointDefinition::new(
+        &points(),
+        &old_context,
+    );
+
+    group.bench_with_input(BenchmarkId::new("baked", n), &n, |b, _| {
+        b.iter(|| point_step(&baked, &context, &times))
+    });
+    group.bench_with_input(BenchmarkId::new("unbaked", n), &n, |b, _| {
+        b.iter(|| point_step(&unbaked, &context, &times))
+    });
+    group.bench_with_input(BenchmarkId::new("slow", n), &n, |b, _| {
+        b.iter(|| point_step_slow(&old, &old_context, &times))
+    });
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    benchmark_all(1000, c);
+    benchmark_all(10000, c);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -15,6 +15,12 @@ fn main() {
             .with_namespaces(&["Tracks"])
             .with_cpp_compat(true)
             .with_pragma_once(true)
+            // passed as `u32` across the boundary, so nothing else pulls them in
+            .include_item("PropertyNames")
+            .include_item("PathPropertyNames")
+            .include_item("BaseValueType")
+            .include_item("WrapBaseValueType")
+            .include_item("JsonValueType")
             .generate()
             .expect("Unable to generate bindings")
             .write_to_file("./shared/bindings.h");
@@ -9,6 +9,25 @@
 namespace Tracks {
 #endif  // __cplusplus
 
+#define EASING_CUBIC_BEZIER 32
+
+#define EASING_STEPS 33
+
+
+
+/**
+ * The kind of value a [`BaseValue`] holds, without the value itself
+ *
+ */
+typedef enum BaseValueType {
+  BaseValueType_Float,
+  BaseValueType_Vector3,
+  BaseValueType_Vector4,
+  BaseValueType_Quaternion,
+  BaseValueType_Vector2,
+  BaseValueType_Bool,
+} BaseValueType;
+
 /**
  * JSON FFI
  */
@@ -17,33 +36,140 @@ typedef enum JsonValueType {
   Null,
   String,
   Array,
+  Bool,
+  Object,
 } JsonValueType;
 
+/**
+ * Names of the properties a track can assign a path animation to
+ *
+ */
+typedef enum PathPropertyNames {
+  PathPropertyNames_Position,
+  PathPropertyNames_Rotation,
+  PathPropertyNames_LocalRotation,
+  PathPropertyNames_Scale,
+  PathPropertyNames_DefinitePosition,
+  PathPropertyNames_Dissolve,
+  PathPropertyNames_DissolveArrow,
+  PathPropertyNames_Interactable,
+  PathPropertyNames_Color,
+} PathPropertyNames;
+
+/**
+ * Names of the properties a track can animate
+ *
+ */
+typedef enum PropertyNames {
+  PropertyNames_Position,
+  PropertyNames_LocalPosition,
+  PropertyNames_Rotation,
+  PropertyNames_LocalRotation,
+  PropertyNames_Scale,
+  PropertyNames_Dissolve,
+  PropertyNames_DissolveArrow,
+  PropertyNames_Interactable,
+  PropertyNames_Time,
+  PropertyNames_Color,
+} PropertyNames;
+
+typedef enum WrapBaseValueType {
+  Vec3 = 0,
+  Quat = 1,
+  Vec4 = 2,
+  Float = 3,
+  Vec2 = 4,
+  Boolean = 5,
+} WrapBaseValueType;
+
 typedef struct BaseFFIProviderValues BaseFFIProviderValues;
 
+/**
+ * Base provider values and the mutable state of the providers parsed against them.
+ *
+ * Parsed providers and point definitions never mutate, so they can be
+ * shared across threads and evaluated with a `&BaseProviderContext`;
+ * only `set_values` and `tick` need `&mut`.
+ */
 typedef struct BaseProviderContext BaseProviderContext;
 
-typedef struct FloatPointDefinition FloatPointDefinition;
+/**
+ * Drives every running animation event once per frame
+ */
+typedef struct CoroutineManager CoroutineManager;
 
-typedef struct QuaternionPointDefinition QuaternionPointDefinition;
+/**
+ * Every point definition of a map, named ones and the inline ones animations used.
+ *
+ * Animations give a definition either as the name of one in the map's
+ * point definitions or as an inline array, [`resolve`](Self::resolve) takes both.
+ */
+typedef struct PointDefinitionLibrary PointDefinitionLibrary;
 
-typedef struct Vector3PointDefinition Vector3PointDefinition;
+/**
+ * Points of type `T` interpolated over time, everything specific to `T` lives in its [`TrackValue`] impl
+ */
+typedef struct PointDefinition_Quat PointDefinition_Quat;
 
-typedef struct Vector4PointDefinition Vector4PointDefinition;
+/**
+ * Points of type `T` interpolated over time, everything specific to `T` lives in its [`TrackValue`] impl
+ */
+typedef struct PointDefinition_Vec2 PointDefinition_Vec2;
+
+/**
+ * Points of type `T` interpolated over time, everything specific to `T` lives in its [`TrackValue`] impl
+ */
+typedef struct PointDefinition_Vec3 PointDefinition_Vec3;
+
+/**
+ * Points of type `T` interpolated over time, everything specific to `T` lives in its [`TrackValue`] impl
+ */
+typedef struct PointDefinition_Vec4 PointDefinition_Vec4;
+
+/**
+ * Points of type `T` interpolated over time, everything specific to `T` lives in its [`TrackValue`] impl
+ */
+typedef struct PointDefinition_bool PointDefinition_bool;
+
+/**
+ * Points of type `T` interpolated over time, everything specific to `T` lives in its [`TrackValue`] impl
+ */
+typedef struct PointDefinition_f32 PointDefinition_f32;
+
+typedef struct Track Track;
+
+/**
+ * Owns every track of a map, looked up by name
+ */
+typedef struct TrackRegistry TrackRegistry;
 
 typedef struct JsonArray {
   const struct FFIJsonValue *elements;
   uintptr_t length;
 } JsonArray;
 
+/**
+ * `length` keys and the values at the same index
+ */
+typedef struct JsonObject {
+  const char *const *keys;
+  const struct FFIJsonValue *values;
+  uintptr_t length;
+} JsonObject;
+
 typedef union JsonValueData {
   double number_value;
   const char *string_value;
   const struct JsonArray *array;
+  bool bool_value;
+  const struct JsonObject *object;
 } JsonValueData;
 
+/**
+ * `value_type` is a `JsonValueType`, any other value is an error
+ */
 typedef struct FFIJsonValue {
-  enum JsonValueType value_type;
+  uint32_t value_type;
   union JsonValueData data;
 } FFIJsonValue;
 
@@ -54,10 +180,23 @@ typedef struct WrappedValues {
 
 typedef struct WrappedValues (*BaseFFIProvider)(const struct BaseProviderContext*, void*);
 
-typedef struct FloatInterpolationResult {
-  float value;
-  bool is_last;
-} FloatInterpolationResult;
+/**
+ * Interned name of a registered base provider.
+ *
+ * Ids index into the context that registered them. The built-in providers
+ * get the same ids in every context; ids of providers registered at runtime
+ * depend on registration order.
+ */
+typedef uint32_t BaseProviderId;
+
+/**
+ * Stable handle to a track in a [`TrackRegistry`]
+ */
+typedef uintptr_t TrackKey;
+/**
+ * Never handed out by a registry, returned by the C API when adding a track fails
+ */
+#define TrackKey_INVALID ~0
 
 typedef struct WrapVec3 {
   float x;
@@ -65,10 +204,12 @@ typedef struct WrapVec3 {
   float z;
 } WrapVec3;
 
-typedef struct Vector3InterpolationResult {
-  struct WrapVec3 value;
-  bool is_last;
-} Vector3InterpolationResult;
+typedef struct WrapQuat {
+  float x;
+  float y;
+  float z;
+  float w;
+} WrapQuat;
 
 typedef struct WrapVec4 {
   float x;
@@ -77,40 +218,140 @@ typedef struct WrapVec4 {
   float w;
 } WrapVec4;
 
+typedef struct WrapVec2 {
+  float x;
+  float y;
+} WrapVec2;
+
+/**
+ * `float` and `bool` are keywords in C, so those fields are exposed
+ * as `float_value` and `bool_value`
+ *
+ */
+typedef union WrapBaseValueUnion {
+  struct WrapVec3 vec3;
+  struct WrapQuat quat;
+  struct WrapVec4 vec4;
+  float float_value;
+  struct WrapVec2 vec2;
+  bool bool_value;
+} WrapBaseValueUnion;
+
+/**
+ * `ty` is a `WrapBaseValueType` telling which union field is set, any other value is an error
+ */
+typedef struct WrapBaseValue {
+  uint32_t ty;
+  union WrapBaseValueUnion value;
+} WrapBaseValue;
+
+typedef struct PointDefinition_f32 FloatPointDefinition;
+
+typedef struct FloatInterpolationResult {
+  float value;
+  bool is_last;
+} FloatInterpolationResult;
+
+typedef struct PointDefinition_Vec3 Vector3PointDefinition;
+
+typedef struct Vector3InterpolationResult {
+  struct WrapVec3 value;
+  bool is_last;
+} Vector3InterpolationResult;
+
+typedef struct PointDefinition_Vec4 Vector4PointDefinition;
+
 typedef struct Vector4InterpolationResult {
   struct WrapVec4 value;
   bool is_last;
 } Vector4InterpolationResult;
 
-typedef struct WrapQuat {
-  float x;
-  float y;
-  float z;
-  float w;
-} WrapQuat;
+typedef struct PointDefinition_Quat QuaternionPointDefinition;
 
 typedef struct QuaternionInterpolationResult {
   struct WrapQuat value;
   bool is_last;
 } QuaternionInterpolationResult;
 
+typedef struct PointDefinition_Vec2 Vector2PointDefinition;
+
+typedef struct Vector2InterpolationResult {
+  struct WrapVec2 value;
+  bool is_last;
+} Vector2InterpolationResult;
+
+typedef struct PointDefinition_bool BoolPointDefinition;
+
+typedef struct BoolInterpolationResult {
+  bool value;
+  bool is_last;
+} BoolInterpolationResult;
+
+/**
+ * An easing as passed through the C API
+ *
+ * Plain data rather than [`Functions`] itself, so any value C writes is sound to read.
+ * Build it with the `tracks_easing_*` constructors; entry points that take one reject
+ * unknown ids and invalid parameters.
+ */
+typedef struct WrapEasing {
+  uint32_t id;
+  uint32_t steps;
+  float bezier[4];
+} WrapEasing;
+
 
 
 #ifdef __cplusplus
 extern "C" {
 #endif // __cplusplus
 
+/**
+ * ERRORS
+ *
+ * Entry points never unwind into C. When one fails it returns false, null
+ * or a default value, and `tracks_last_error` tells why.
+ */
+const char *tracks_last_error(void);
+
 struct FFIJsonValue tracks_create_json_number(double value);
 
+/**
+ * The string is copied, `value` can be freed once this returns
+ */
 struct FFIJsonValue tracks_create_json_string(const char *value);
 
+struct FFIJsonValue tracks_create_json_bool(bool value);
+
+/**
+ * Moves `elements` into a new array, which then owns them.
+ * Free the returned value, not the elements, once done with it.
+ */
 struct FFIJsonValue tracks_create_json_array(const struct FFIJsonValue *elements, uintptr_t length);
 
+/**
+ * Copies the `length` keys and moves the `length` values into a new object,
+ * a later duplicate key replaces the earlier one.
+ * Free the returned value, not the values, once done with it.
+ */
+struct FFIJsonValue tracks_create_json_object(const char *const *keys,
+                                              const struct FFIJsonValue *values,
+                                              uintptr_t length);
+
+/**
+ * Frees everything `json_value` owns, nested arrays included, and resets it to null.
+ * The `FFIJsonValue` itself is the caller's.
+ */
 void tracks_free_json_value(struct FFIJsonValue *json_value);
 
 struct BaseFFIProviderValues *tracks_make_base_ffi_provider(const BaseFFIProvider *func,
                                                             void *user_value);
 
+void tracks_free_base_ffi_provider(struct BaseFFIProviderValues *func);
+
+/**
+ * Misspelled alias of `tracks_free_base_ffi_provider`, kept for existing callers
+ */
 void tracks_dipose_base_ffi_provider(struct BaseFFIProviderValues *func);
 
 /**
@@ -118,67 +359,596 @@ void tracks_dipose_base_ffi_provider(struct BaseFFIProviderValues *func);
  */
 struct BaseProviderContext *tracks_make_base_provider_context(void);
 
-void tracks_set_base_provider(struct BaseProviderContext *context,
+/**
+ * Point definitions made with the context can still be freed afterwards, just not interpolated
+ */
+void tracks_free_base_provider_context(struct BaseProviderContext *context);
+
+/**
+ * Declares a base provider at runtime, returns false if the name is already registered with another type.
+ * `ty` is a `BaseValueType`.
+ */
+bool tracks_register_base_provider(struct BaseProviderContext *context,
+                                   const char *base,
+                                   uint32_t ty);
+
+/**
+ * Reads `values` as the type the base provider was registered with: quaternions as
+ * `x, y, z, w` and bools as non-zero. Returns false if the provider is unknown or
+ * `count` does not fit its type.
+ */
+bool tracks_set_base_provider(struct BaseProviderContext *context,
                               const char *base,
-                              float *values,
-                              uintptr_t count,
-                              bool quat);
+                              const float *values,
+                              uintptr_t count);
+
+/**
+ * Resolves a base provider name once so it can be set every frame without a string lookup.
+ * Returns false if the name is unknown.
+ */
+bool tracks_get_base_provider_id(const struct BaseProviderContext *context,
+                                 const char *base,
+                                 BaseProviderId *out);
+
+/**
+ * Like `tracks_set_base_provider`, returns false if `count` does not fit the provider's type
+ */
+bool tracks_set_base_provider_by_id(struct BaseProviderContext *context,
+                                    BaseProviderId id,
+                                    const float *values,
+                                    uintptr_t count);
+
+/**
+ * Advances smoothed base providers (`.s<mult>`) by the frame's duration.
+ * Call once per frame after setting that frame's base values.
+ */
+bool tracks_context_tick(struct BaseProviderContext *context, float delta_seconds);
+
+/**
+ * TRACKS
+ */
+struct TrackRegistry *tracks_make_track_registry(void);
+
+void tracks_free_track_registry(struct TrackRegistry *registry);
+
+/**
+ * Returns the key of the named track, creating it if it does not exist yet.
+ * Returns `TrackKey_INVALID` on failure.
+ */
+TrackKey tracks_registry_add_track(struct TrackRegistry *registry, const char *name);
+
+/**
+ * Returns null if the key is not part of this registry.
+ * The pointer is invalidated when another track is added.
+ */
+struct Track *tracks_registry_get_track(struct TrackRegistry *registry, TrackKey key);
+
+/**
+ * Returns null if no track has this name.
+ * The pointer is invalidated when another track is added.
+ */
+struct Track *tracks_registry_get_track_by_name(struct TrackRegistry *registry, const char *name);
+
+/**
+ * `property` is a `PropertyNames`
+ */
+bool tracks_track_set_property(struct Track *track, uint32_t property, struct WrapBaseValue value);
+
+/**
+ * `property` is a `PropertyNames`
+ */
+bool tracks_track_clear_property(struct Track *track, uint32_t property);
+
+/**
+ * Writes the `PropertyNames` property into `out` and returns true if it currently has a value
+ */
+bool tracks_track_get_property(const struct Track *track,
+                               uint32_t property,
+                               struct WrapBaseValue *out);
+
+/**
+ * Samples a path animation at an object's normalized lifetime `time`.
+ * Writes the value into `out` and returns true if the track has a path for this property.
+ * `property` is a `PathPropertyNames`.
+ */
+bool tracks_track_get_path_property(const struct Track *track,
+                                    uint32_t property,
+                                    float time,
+                                    const struct BaseProviderContext *context,
+                                    struct WrapBaseValue *out);
 
 /**
  *FLOAT POINT DEFINITION
+ *
+ * Returns null if the JSON is not a valid point definition
  */
-const struct FloatPointDefinition *tracks_make_float_point_definition(const struct FFIJsonValue *json,
-                                                                      struct BaseProviderContext *context);
+const FloatPointDefinition *tracks_make_float_point_definition(const struct FFIJsonValue *json,
+                                                               struct BaseProviderContext *context);
+
+/**
+ * Parses `len` bytes of JSON text, returns null if it is not a valid point definition
+ */
+const FloatPointDefinition *tracks_make_float_point_definition_from_str(const char *json,
+                                                                        uintptr_t len,
+                                                                        struct BaseProviderContext *context);
 
-struct FloatInterpolationResult tracks_interpolate_float(const struct FloatPointDefinition *point_definition,
+/**
+ * Releases the handle, animations already using the definition keep it alive
+ */
+void tracks_free_float_point_definition(const FloatPointDefinition *point_definition);
+
+struct FloatInterpolationResult tracks_interpolate_float(const FloatPointDefinition *point_definition,
                                                          float time,
                                                          struct BaseProviderContext *context);
 
-uintptr_t tracks_float_count(const struct FloatPointDefinition *point_definition);
+/**
+ * Interpolates `count` times into `out`, sorted times are fastest
+ */
+bool tracks_interpolate_float_batch(const FloatPointDefinition *point_definition,
+                                    const float *times,
+                                    float *out,
+                                    uintptr_t count,
+                                    struct BaseProviderContext *context);
+
+uintptr_t tracks_float_count(const FloatPointDefinition *point_definition);
 
-bool tracks_float_has_base_provider(const struct FloatPointDefinition *point_definition);
+bool tracks_float_has_base_provider(const FloatPointDefinition *point_definition);
 
 /**
  *VECTOR3 POINT DEFINITION
+ *
+ * Returns null if the JSON is not a valid point definition
+ */
+const Vector3PointDefinition *tracks_make_vector3_point_definition(const struct FFIJsonValue *json,
+                                                                   struct BaseProviderContext *context);
+
+/**
+ * Parses `len` bytes of JSON text, returns null if it is not a valid point definition
  */
-const struct Vector3PointDefinition *tracks_make_vector3_point_definition(const struct FFIJsonValue *json,
-                                                                          struct BaseProviderContext *context);
+const Vector3PointDefinition *tracks_make_vector3_point_definition_from_str(const char *json,
+                                                                            uintptr_t len,
+                                                                            struct BaseProviderContext *context);
 
-struct Vector3InterpolationResult tracks_interpolate_vector3(const struct Vector3PointDefinition *point_definition,
+/**
+ * Releases the handle, animations already using the definition keep it alive
+ */
+void tracks_free_vector3_point_definition(const Vector3PointDefinition *point_definition);
+
+struct Vector3InterpolationResult tracks_interpolate_vector3(const Vector3PointDefinition *point_definition,
                                                              float time,
                                                              struct BaseProviderContext *context);
 
-uintptr_t tracks_vector3_count(const struct Vector3PointDefinition *point_definition);
+/**
+ * Interpolates `count` times into `out`, sorted times are fastest
+ */
+bool tracks_interpolate_vector3_batch(const Vector3PointDefinition *point_definition,
+                                      const float *times,
+                                      struct WrapVec3 *out,
+                                      uintptr_t count,
+                                      struct BaseProviderContext *context);
+
+uintptr_t tracks_vector3_count(const Vector3PointDefinition *point_definition);
 
-bool tracks_vector3_has_base_provider(const struct Vector3PointDefinition *point_definition);
+bool tracks_vector3_has_base_provider(const Vector3PointDefinition *point_definition);
 
 /**
  *VECTOR4 POINT DEFINITION
+ *
+ * Returns null if the JSON is not a valid point definition
  */
-const struct Vector4PointDefinition *tracks_make_vector4_point_definition(const struct FFIJsonValue *json,
-                                                                          struct BaseProviderContext *context);
+const Vector4PointDefinition *tracks_make_vector4_point_definition(const struct FFIJsonValue *json,
+                                                                   struct BaseProviderContext *context);
 
-struct Vector4InterpolationResult tracks_interpolate_vector4(const struct Vector4PointDefinition *point_definition,
+/**
+ * Parses `len` bytes of JSON text, returns null if it is not a valid point definition
+ */
+const Vector4PointDefinition *tracks_make_vector4_point_definition_from_str(const char *json,
+                                                                            uintptr_t len,
+                                                                            struct BaseProviderContext *context);
+
+/**
+ * Releases the handle, animations already using the definition keep it alive
+ */
+void tracks_free_vector4_point_definition(const Vector4PointDefinition *point_definition);
+
+struct Vector4InterpolationResult tracks_interpolate_vector4(const Vector4PointDefinition *point_definition,
                                                              float time,
                                                              struct BaseProviderContext *context);
 
-uintptr_t tracks_vector4_count(const struct Vector4PointDefinition *point_definition);
+/**
+ * Interpolates `count` times into `out`, sorted times are fastest
+ */
+bool tracks_interpolate_vector4_batch(const Vector4PointDefinition *point_definition,
+                                      const float *times,
+                                      struct WrapVec4 *out,
+                                      uintptr_t count,
+                                      struct BaseProviderContext *context);
 
-bool tracks_vector4_has_base_provider(const struct Vector4PointDefinition *point_definition);
+uintptr_t tracks_vector4_count(const Vector4PointDefinition *point_definition);
+
+bool tracks_vector4_has_base_provider(const Vector4PointDefinition *point_definition);
 
 /**
  *QUATERNION POINT DEFINITION
+ *
+ * Returns null if the JSON is not a valid point definition
+ */
+const QuaternionPointDefinition *tracks_make_quat_point_definition(const struct FFIJsonValue *json,
+                                                                   struct BaseProviderContext *context);
+
+/**
+ * Parses `len` bytes of JSON text, returns null if it is not a valid point definition
+ */
+const QuaternionPointDefinition *tracks_make_quat_point_definition_from_str(const char *json,
+                                                                            uintptr_t len,
+                                                                            struct BaseProviderContext *context);
+
+/**
+ * Releases the handle, animations already using the definition keep it alive
+ */
+void tracks_free_quat_point_definition(const QuaternionPointDefinition *point_definition);
+
+struct QuaternionInterpolationResult tracks_interpolate_quat(const QuaternionPointDefinition *point_definition,
+                                                             float time,
+                                                             struct BaseProviderContext *context);
+
+/**
+ * Interpolates `count` times into `out`, sorted times are fastest
+ */
+bool tracks_interpolate_quat_batch(const QuaternionPointDefinition *point_definition,
+                                   const float *times,
+                                   struct WrapQuat *out,
+                                   uintptr_t count,
+                                   struct BaseProviderContext *context);
+
+uintptr_t tracks_quat_count(const QuaternionPointDefinition *point_definition);
+
+bool tracks_quat_has_base_provider(const QuaternionPointDefinition *point_definition);
+
+/**
+ *VECTOR2 POINT DEFINITION
+ *
+ * Returns null if the JSON is not a valid point definition
+ */
+const Vector2PointDefinition *tracks_make_vector2_point_definition(const struct FFIJsonValue *json,
+                                                                   struct BaseProviderContext *context);
+
+/**
+ * Parses `len` bytes of JSON text, returns null if it is not a valid point definition
+ */
+const Vector2PointDefinition *tracks_make_vector2_point_definition_from_str(const char *json,
+                                                                            uintptr_t len,
+                                                                            struct BaseProviderContext *context);
+
+/**
+ * Releases the handle, animations already using the definition keep it alive
  */
-const struct QuaternionPointDefinition *tracks_make_quat_point_definition(const struct FFIJsonValue *json,
-                                                                          struct BaseProviderContext *context);
+void tracks_free_vector2_point_definition(const Vector2PointDefinition *point_definition);
 
-struct QuaternionInterpolationResult tracks_interpolate_quat(const struct QuaternionPointDefinition *point_definition,
+struct Vector2InterpolationResult tracks_interpolate_vector2(const Vector2PointDefinition *point_definition,
                                                              float time,
                                                              struct BaseProviderContext *context);
 
-uintptr_t tracks_quat_count(const struct QuaternionPointDefinition *point_definition);
+/**
+ * Interpolates `count` times into `out`, sorted times are fastest
+ */
+bool tracks_interpolate_vector2_batch(const Vector2PointDefinition *point_definition,
+                                      const float *times,
+                                      struct WrapVec2 *out,
+                                      uintptr_t count,
+                                      struct BaseProviderContext *context);
+
+uintptr_t tracks_vector2_count(const Vector2PointDefinition *point_definition);
+
+bool tracks_vector2_has_base_provider(const Vector2PointDefinition *point_definition);
+
+/**
+ *BOOL POINT DEFINITION
+ *
+ * Values step from one point to the next instead of blending.
+ * Returns null if the JSON is not a valid point definition
+ */
+const BoolPointDefinition *tracks_make_bool_point_definition(const struct FFIJsonValue *json,
+                                                             struct BaseProviderContext *context);
+
+/**
+ * Parses `len` bytes of JSON text, returns null if it is not a valid point definition
+ */
+const BoolPointDefinition *tracks_make_bool_point_definition_from_str(const char *json,
+                                                                      uintptr_t len,
+                                                                      struct BaseProviderContext *context);
+
+/**
+ * Releases the handle, animations already using the definition keep it alive
+ */
+void tracks_free_bool_point_definition(const BoolPointDefinition *point_definition);
+
+struct BoolInterpolationResult tracks_interpolate_bool(const BoolPointDefinition *point_definition,
+                                                       float time,
+                                                       struct BaseProviderContext *context);
+
+/**
+ * Interpolates `count` times into `out`, sorted times are fastest
+ */
+bool tracks_interpolate_bool_batch(const BoolPointDefinition *point_definition,
+                                   const float *times,
+                                   bool *out,
+                                   uintptr_t count,
+                                   struct BaseProviderContext *context);
+
+uintptr_t tracks_bool_count(const BoolPointDefinition *point_definition);
+
+bool tracks_bool_has_base_provider(const BoolPointDefinition *point_definition);
+
+/**
+ * POINT DEFINITION LIBRARY
+ *
+ * Named point definitions of a map, and the inline ones animations used.
+ * Definitions returned by the library are new handles,
+ * release them with the matching `tracks_free_*_point_definition`.
+ */
+struct PointDefinitionLibrary *tracks_make_point_definition_library(void);
+
+/**
+ * Loads the named definitions of `len` bytes of v2 or v3 beatmap JSON.
+ * Malformed definitions are left out, returns null if the text is not JSON.
+ */
+struct PointDefinitionLibrary *tracks_make_point_definition_library_from_beatmap(const char *json,
+                                                                                 uintptr_t len,
+                                                                                 struct BaseProviderContext *context);
+
+/**
+ * Definitions handed out by the library stay valid until they are freed themselves
+ */
+void tracks_free_point_definition_library(struct PointDefinitionLibrary *library);
+
+/**
+ * Amount of named definitions
+ */
+uintptr_t tracks_library_count(const struct PointDefinitionLibrary *library);
+
+/**
+ * Writes the value type of the named definition into `out`, returns false if there is none
+ */
+bool tracks_library_get_type(const struct PointDefinitionLibrary *library,
+                             const char *name,
+                             enum BaseValueType *out);
+
+/**
+ * Returns null if there is no float definition with this name
+ */
+const FloatPointDefinition *tracks_library_get_float(const struct PointDefinitionLibrary *library,
+                                                     const char *name);
+
+/**
+ * Resolves an animation value, either the name of a definition or an inline one.
+ * Inline definitions are parsed once and shared by later calls with the same JSON.
+ */
+const FloatPointDefinition *tracks_library_resolve_float(struct PointDefinitionLibrary *library,
+                                                         const struct FFIJsonValue *json,
+                                                         struct BaseProviderContext *context);
+
+/**
+ * Returns null if there is no vector3 definition with this name
+ */
+const Vector3PointDefinition *tracks_library_get_vector3(const struct PointDefinitionLibrary *library,
+                                                         const char *name);
+
+/**
+ * Resolves an animation value, either the name of a definition or an inline one.
+ * Inline definitions are parsed once and shared by later calls with the same JSON.
+ */
+const Vector3PointDefinition *tracks_library_resolve_vector3(struct PointDefinitionLibrary *library,
+                                                             const struct FFIJsonValue *json,
+                                                             struct BaseProviderContext *context);
+
+/**
+ * Returns null if there is no vector4 definition with this name
+ */
+const Vector4PointDefinition *tracks_library_get_vector4(const struct PointDefinitionLibrary *library,
+                                                         const char *name);
+
+/**
+ * Resolves an animation value, either the name of a definition or an inline one.
+ * Inline definitions are parsed once and shared by later calls with the same JSON.
+ */
+const Vector4PointDefinition *tracks_library_resolve_vector4(struct PointDefinitionLibrary *library,
+                                                             const struct FFIJsonValue *json,
+                                                             struct BaseProviderContext *context);
+
+/**
+ * Returns null if there is no quat definition with this name
+ */
+const QuaternionPointDefinition *tracks_library_get_quat(const struct PointDefinitionLibrary *library,
+                                                         const char *name);
+
+/**
+ * Resolves an animation value, either the name of a definition or an inline one.
+ * Inline definitions are parsed once and shared by later calls with the same JSON.
+ */
+const QuaternionPointDefinition *tracks_library_resolve_quat(struct PointDefinitionLibrary *library,
+                                                             const struct FFIJsonValue *json,
+                                                             struct BaseProviderContext *context);
+
+/**
+ * Returns null if there is no vector2 definition with this name
+ */
+const Vector2PointDefinition *tracks_library_get_vector2(const struct PointDefinitionLibrary *library,
+                                                         const char *name);
+
+/**
+ * Resolves an animation value, either the name of a definition or an inline one.
+ * Inline definitions are parsed once and shared by later calls with the same JSON.
+ */
+const Vector2PointDefinition *tracks_library_resolve_vector2(struct PointDefinitionLibrary *library,
+                                                             const struct FFIJsonValue *json,
+                                                             struct BaseProviderContext *context);
+
+/**
+ * Returns null if there is no bool definition with this name
+ */
+const BoolPointDefinition *tracks_library_get_bool(const struct PointDefinitionLibrary *library,
+                                                   const char *name);
+
+/**
+ * Resolves an animation value, either the name of a definition or an inline one.
+ * Inline definitions are parsed once and shared by later calls with the same JSON.
+ */
+const BoolPointDefinition *tracks_library_resolve_bool(struct PointDefinitionLibrary *library,
+                                                       const struct FFIJsonValue *json,
+                                                       struct BaseProviderContext *context);
+
+/**
+ * EASINGS
+ *
+ * Easings are passed by value as [`WrapEasing`], built by the constructors below.
+ * Writes the easing named like a point flag into `out`, e.g. `easeInQuad` or `steps(4)`
+ */
+bool tracks_easing_from_str(const char *name, struct WrapEasing *out);
+
+/**
+ * Writes a cubic bezier easing into `out`, fails unless both x are within 0 and 1
+ */
+bool tracks_easing_cubic_bezier(float x1, float y1, float x2, float y2, struct WrapEasing *out);
+
+/**
+ * Writes a staircase easing into `out`, fails for zero steps
+ */
+bool tracks_easing_steps(uint32_t steps, struct WrapEasing *out);
+
+/**
+ * Returns 0 and sets the last error for an invalid easing
+ */
+float tracks_easing_interpolate(struct WrapEasing easing, float time);
+
+/**
+ * ANIMATE TRACK
+ */
+struct CoroutineManager *tracks_make_coroutine_manager(void);
+
+void tracks_free_coroutine_manager(struct CoroutineManager *manager);
+
+/**
+ * `property` is a `PropertyNames`
+ */
+bool tracks_animate_track_float(struct CoroutineManager *manager,
+                                TrackKey track,
+                                uint32_t property,
+                                const FloatPointDefinition *point_definition,
+                                float start_time,
+                                float duration,
+                                struct WrapEasing easing,
+                                uint32_t repeat);
+
+/**
+ * `property` is a `PropertyNames`
+ */
+bool tracks_animate_track_vector3(struct CoroutineManager *manager,
+                                  TrackKey track,
+                                  uint32_t property,
+                                  const Vector3PointDefinition *point_definition,
+                                  float start_time,
+                                  float duration,
+                                  struct WrapEasing easing,
+                                  uint32_t repeat);
+
+/**
+ * `property` is a `PropertyNames`
+ */
+bool tracks_animate_track_vector4(struct CoroutineManager *manager,
+                                  TrackKey track,
+                                  uint32_t property,
+                                  const Vector4PointDefinition *point_definition,
+                                  float start_time,
+                                  float duration,
+                                  struct WrapEasing easing,
+                                  uint32_t repeat);
+
+/**
+ * `property` is a `PropertyNames`
+ */
+bool tracks_animate_track_quat(struct CoroutineManager *manager,
+                               TrackKey track,
+                               uint32_t property,
+                               const QuaternionPointDefinition *point_definition,
+                               float start_time,
+                               float duration,
+                               struct WrapEasing easing,
+                               uint32_t repeat);
+
+/**
+ * Equivalent of an `AnimateTrack` event with a null point definition.
+ * `property` is a `PropertyNames`.
+ */
+bool tracks_animate_track_clear(struct CoroutineManager *manager,
+                                TrackKey track,
+                                uint32_t property,
+                                float start_time);
 
-bool tracks_quat_has_base_provider(const struct QuaternionPointDefinition *point_definition);
+/**
+ * ASSIGN PATH ANIMATION
+ *
+ * `property` is a `PathPropertyNames`
+ */
+bool tracks_assign_path_animation_float(struct CoroutineManager *manager,
+                                        TrackKey track,
+                                        uint32_t property,
+                                        const FloatPointDefinition *point_definition,
+                                        float start_time,
+                                        float duration,
+                                        struct WrapEasing easing);
+
+/**
+ * `property` is a `PathPropertyNames`
+ */
+bool tracks_assign_path_animation_vector3(struct CoroutineManager *manager,
+                                          TrackKey track,
+                                          uint32_t property,
+                                          const Vector3PointDefinition *point_definition,
+                                          float start_time,
+                                          float duration,
+                                          struct WrapEasing easing);
+
+/**
+ * `property` is a `PathPropertyNames`
+ */
+bool tracks_assign_path_animation_vector4(struct CoroutineManager *manager,
+                                          TrackKey track,
+                                          uint32_t property,
+                                          const Vector4PointDefinition *point_definition,
+                                          float start_time,
+                                          float duration,
+                                          struct WrapEasing easing);
+
+/**
+ * `property` is a `PathPropertyNames`
+ */
+bool tracks_assign_path_animation_quat(struct CoroutineManager *manager,
+                                       TrackKey track,
+                                       uint32_t property,
+                                       const QuaternionPointDefinition *point_definition,
+                                       float start_time,
+                                       float duration,
+                                       struct WrapEasing easing);
+
+/**
+ * Equivalent of an `AssignPathAnimation` event with a null point definition.
+ * `property` is a `PathPropertyNames`.
+ */
+bool tracks_assign_path_animation_clear(struct CoroutineManager *manager,
+                                        TrackKey track,
+                                        uint32_t property,
+                                        float start_time,
+                                        float duration,
+                                        struct WrapEasing easing);
+
+/**
+ * Advance every running animation, call once per frame
+ */
+bool tracks_coroutine_manager_tick(struct CoroutineManager *manager,
+                                   float song_time,
+                                   struct TrackRegistry *registry,
+                                   const struct BaseProviderContext *context);
 
 #ifdef __cplusplus
 }  // extern "C"
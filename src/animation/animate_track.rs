@@ -0,0 +1,96 @@
+use crate::{
+    easings::functions::Functions,
+    point_definition::base_point_definition::BasePointDefinition,
+    tracks::{property::PropertyNames, track::Track, track_registry::TrackKey},
+    values::base_provider_context::BaseProviderContext,
+};
+
+/// A running `AnimateTrack` event for a single track property
+pub struct AnimateTrackCoroutine {
+    pub(crate) track: TrackKey,
+    pub(crate) property: PropertyNames,
+    /// `None` clears the property
+    point_definition: Option<BasePointDefinition>,
+    start_time: f32,
+    duration: f32,
+    easing: Functions,
+    repeat: u32,
+    skip: bool,
+}
+
+impl AnimateTrackCoroutine {
+    pub fn new(
+        track: TrackKey,
+        property: PropertyNames,
+        point_definition: Option<BasePointDefinition>,
+        start_time: f32,
+        duration: f32,
+        easing: Functions,
+        repeat: u32,
+    ) -> Self {
+        Self {
+            track,
+            property,
+            point_definition,
+            start_time,
+            duration,
+            easing,
+            repeat,
+            skip: false,
+        }
+    }
+
+    /// Advance the animation to `song_time` and write the result into the track.
+    /// Returns true once the animation has completed.
+    pub fn update(
+        &mut self,
+        song_time: f32,
+        track: &mut Track,
+        context: &BaseProviderContext,
+    ) -> bool {
+        let Some(point_definition) = &self.point_definition else {
+            let _ = track.set_property(self.property, None);
+            return true;
+        };
+
+        // not started yet
+        if song_time < self.start_time {
+            return false;
+        }
+
+        loop {
+            let elapsed_time = song_time - self.start_time;
+
+            if !self.skip {
+                let normalized_time = if self.duration > 0.0 {
+                    (elapsed_time / self.duration).min(1.0)
+                } else {
+                    1.0
+                };
+                let time = self.easing.interpolate(normalized_time);
+                let (value, is_last) = point_definition.interpolate(time, context);
+                // a point definition of the wrong type can never apply, so stop here
+                if track.set_property(self.property, Some(value)).is_err() {
+                    return true;
+                }
+
+                // base providers can change every frame, so keep evaluating those
+                if is_last && !point_definition.has_base_provider() {
+                    self.skip = true;
+                }
+            }
+
+            if elapsed_time < self.duration {
+                return false;
+            }
+
+            if self.repeat == 0 {
+                return true;
+            }
+
+            self.repeat -= 1;
+            self.start_time += self.duration;
+            self.skip = false;
+        }
+    }
+}
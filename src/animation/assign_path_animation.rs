@@ -0,0 +1,65 @@
+use crate::{
+    easings::functions::Functions,
+    point_definition::base_point_definition::BasePointDefinition,
+    tracks::{path_property::PathPropertyNames, track::Track, track_registry::TrackKey},
+};
+
+/// A running `AssignPathAnimation` event for a single track path
+pub struct AssignPathAnimationCoroutine {
+    pub(crate) track: TrackKey,
+    pub(crate) property: PathPropertyNames,
+    /// Taken when the transition starts, `None` transitions into no path
+    point_definition: Option<BasePointDefinition>,
+    start_time: f32,
+    duration: f32,
+    easing: Functions,
+    started: bool,
+}
+
+impl AssignPathAnimationCoroutine {
+    pub fn new(
+        track: TrackKey,
+        property: PathPropertyNames,
+        point_definition: Option<BasePointDefinition>,
+        start_time: f32,
+        duration: f32,
+        easing: Functions,
+    ) -> Self {
+        Self {
+            track,
+            property,
+            point_definition,
+            start_time,
+            duration,
+            easing,
+            started: false,
+        }
+    }
+
+    /// Advance the transition to `song_time`.
+    /// Returns true once the new path has fully replaced the previous one.
+    pub fn update(&mut self, song_time: f32, track: &mut Track) -> bool {
+        // not started yet
+        if song_time < self.start_time {
+            return false;
+        }
+
+        let path_property = track.path_properties.get_property_mut(self.property);
+
+        if !self.started {
+            path_property.init(self.point_definition.take());
+            self.started = true;
+        }
+
+        let elapsed_time = song_time - self.start_time;
+        if elapsed_time < self.duration {
+            let normalized_time = elapsed_time / self.duration;
+            path_property.set_time(self.easing.interpolate(normalized_time));
+            return false;
+        }
+
+        path_property.set_time(1.0);
+        path_property.finish();
+        true
+    }
+}
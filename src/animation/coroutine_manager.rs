@@ -0,0 +1,136 @@
+use crate::{
+    easings::functions::Functions,
+    error::PropertyError,
+    point_definition::base_point_definition::BasePointDefinition,
+    tracks::{
+        path_property::PathPropertyNames, property::PropertyNames, track_registry::TrackKey,
+        track_registry::TrackRegistry,
+    },
+    values::base_provider_context::BaseProviderContext,
+};
+
+use super::{
+    animate_track::AnimateTrackCoroutine, assign_path_animation::AssignPathAnimationCoroutine,
+};
+
+/// Drives every running animation event once per frame
+#[derive(Default)]
+pub struct CoroutineManager {
+    coroutines: Vec<AnimateTrackCoroutine>,
+    path_coroutines: Vec<AssignPathAnimationCoroutine>,
+}
+
+impl CoroutineManager {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Start animating a track property, replacing any animation
+    /// already running on the same property.
+    ///
+    /// A `None` point definition clears the property instead.
+    #[allow(clippy::too_many_arguments)]
+    pub fn start_animate_track(
+        &mut self,
+        track: TrackKey,
+        property: PropertyNames,
+        point_definition: Option<BasePointDefinition>,
+        start_time: f32,
+        duration: f32,
+        easing: Functions,
+        repeat: u32,
+    ) -> Result<(), PropertyError> {
+        if let Some(definition) = &point_definition
+            && definition.get_type() != property.get_type()
+        {
+            return Err(PropertyError::TypeMismatch {
+                expected: property.get_type(),
+                found: definition.get_type(),
+            });
+        }
+
+        self.coroutines
+            .retain(|c| !(c.track == track && c.property == property));
+
+        self.coroutines.push(AnimateTrackCoroutine::new(
+            track,
+            property,
+            point_definition,
+            start_time,
+            duration,
+            easing,
+            repeat,
+        ));
+        Ok(())
+    }
+
+    /// Start transitioning a track path to a new point definition,
+    /// replacing any transition already running on the same path.
+    ///
+    /// A `None` point definition transitions into no path.
+    pub fn start_assign_path_animation(
+        &mut self,
+        track: TrackKey,
+        property: PathPropertyNames,
+        point_definition: Option<BasePointDefinition>,
+        start_time: f32,
+        duration: f32,
+        easing: Functions,
+    ) -> Result<(), PropertyError> {
+        if let Some(definition) = &point_definition
+            && definition.get_type() != property.get_type()
+        {
+            return Err(PropertyError::TypeMismatch {
+                expected: property.get_type(),
+                found: definition.get_type(),
+            });
+        }
+
+        self.path_coroutines
+            .retain(|c| !(c.track == track && c.property == property));
+
+        self.path_coroutines.push(AssignPathAnimationCoroutine::new(
+            track,
+            property,
+            point_definition,
+            start_time,
+            duration,
+            easing,
+        ));
+        Ok(())
+    }
+
+    /// Advance every animation to `song_time`, dropping the finished ones
+    pub fn tick(
+        &mut self,
+        song_time: f32,
+        registry: &mut TrackRegistry,
+        context: &BaseProviderContext,
+    ) {
+        self.coroutines
+            .retain_mut(|coroutine| match registry.get_track_mut(coroutine.track) {
+                Some(track) => !coroutine.update(song_time, track, context),
+                None => false,
+            });
+        self.path_coroutines.retain_mut(|coroutine| {
+            match registry.get_track_mut(coroutine.track) {
+                Some(track) => !coroutine.update(song_time, track),
+                None => false,
+            }
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        self.coroutines.len() + self.path_coroutines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.coroutines.is_empty() && self.path_coroutines.is_empty()
+    }
+
+    /// Stop every running animation without touching the tracks
+    pub fn clear(&mut self) {
+        self.coroutines.clear();
+        self.path_coroutines.clear();
+    }
+}
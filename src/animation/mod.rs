@@ -0,0 +1,3 @@
+pub mod animate_track;
+pub mod assign_path_animation;
+pub mod coroutine_manager;
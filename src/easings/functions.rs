@@ -1,8 +1,8 @@
-use std::{fmt, str::FromStr};
+use std::{borrow::Cow, fmt, str::FromStr};
 
 use super::implementations::*;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[allow(dead_code, clippy::enum_variant_names)]
 pub enum Functions {
     EaseLinear,
@@ -37,6 +37,17 @@ pub enum Functions {
     EaseInBounce,
     EaseOutBounce,
     EaseInOutBounce,
+    /// CSS `cubic-bezier(x1, y1, x2, y2)`, the curve from (0, 0) to (1, 1) with these control points
+    EaseCubicBezier {
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+    },
+    /// CSS `steps(n)`, `steps` equal jumps at the end of each interval
+    EaseSteps {
+        steps: u32,
+    },
 }
 
 impl Functions {
@@ -74,8 +85,118 @@ impl Functions {
             Functions::EaseInBounce => ease_in_bounce(t),
             Functions::EaseOutBounce => ease_out_bounce(t),
             Functions::EaseInOutBounce => ease_in_out_bounce(t),
+            Functions::EaseCubicBezier { x1, y1, x2, y2 } => {
+                ease_cubic_bezier(*x1, *y1, *x2, *y2, t)
+            }
+            Functions::EaseSteps { steps } => ease_steps(*steps, t),
         }
     }
+
+    /// Rate of change of [`interpolate`](Self::interpolate) at `t`
+    pub fn derivative(&self, t: f32) -> f32 {
+        match self {
+            Functions::EaseLinear => ease_linear_derivative(t),
+            Functions::EaseStep => ease_step_derivative(t),
+            Functions::EaseInQuad => ease_in_quad_derivative(t),
+            Functions::EaseOutQuad => ease_out_quad_derivative(t),
+            Functions::EaseInOutQuad => ease_in_out_quad_derivative(t),
+            Functions::EaseInCubic => ease_in_cubic_derivative(t),
+            Functions::EaseOutCubic => ease_out_cubic_derivative(t),
+            Functions::EaseInOutCubic => ease_in_out_cubic_derivative(t),
+            Functions::EaseInQuart => ease_in_quart_derivative(t),
+            Functions::EaseOutQuart => ease_out_quart_derivative(t),
+            Functions::EaseInOutQuart => ease_in_out_quart_derivative(t),
+            Functions::EaseInQuint => ease_in_quint_derivative(t),
+            Functions::EaseOutQuint => ease_out_quint_derivative(t),
+            Functions::EaseInOutQuint => ease_in_out_quint_derivative(t),
+            Functions::EaseInSine => ease_in_sine_derivative(t),
+            Functions::EaseOutSine => ease_out_sine_derivative(t),
+            Functions::EaseInOutSine => ease_in_out_sine_derivative(t),
+            Functions::EaseInCirc => ease_in_circ_derivative(t),
+            Functions::EaseOutCirc => ease_out_circ_derivative(t),
+            Functions::EaseInOutCirc => ease_in_out_circ_derivative(t),
+            Functions::EaseInExpo => ease_in_expo_derivative(t),
+            Functions::EaseOutExpo => ease_out_expo_derivative(t),
+            Functions::EaseInOutExpo => ease_in_out_expo_derivative(t),
+            Functions::EaseInElastic => ease_in_elastic_derivative(t),
+            Functions::EaseOutElastic => ease_out_elastic_derivative(t),
+            Functions::EaseInOutElastic => ease_in_out_elastic_derivative(t),
+            Functions::EaseInBack => ease_in_back_derivative(t),
+            Functions::EaseOutBack => ease_out_back_derivative(t),
+            Functions::EaseInOutBack => ease_in_out_back_derivative(t),
+            Functions::EaseInBounce => ease_in_bounce_derivative(t),
+            Functions::EaseOutBounce => ease_out_bounce_derivative(t),
+            Functions::EaseInOutBounce => ease_in_out_bounce_derivative(t),
+            Functions::EaseCubicBezier { x1, y1, x2, y2 } => {
+                ease_cubic_bezier_derivative(*x1, *y1, *x2, *y2, t)
+            }
+            Functions::EaseSteps { steps } => ease_steps_derivative(*steps, t),
+        }
+    }
+
+    /// A cubic bezier easing, `None` unless both x are within 0 and 1 so the curve is a function of time
+    pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Option<Self> {
+        let valid = (0.0..=1.0).contains(&x1)
+            && (0.0..=1.0).contains(&x2)
+            && y1.is_finite()
+            && y2.is_finite();
+        valid.then_some(Functions::EaseCubicBezier { x1, y1, x2, y2 })
+    }
+
+    /// A staircase easing, `None` for zero steps
+    pub fn steps(steps: u32) -> Option<Self> {
+        (steps > 0).then_some(Functions::EaseSteps { steps })
+    }
+
+    /// Whether a point flag is meant as an easing, even one that does not parse
+    pub fn is_easing_flag(flag: &str) -> bool {
+        flag.starts_with("ease") || flag.starts_with("cubic-bezier") || flag.starts_with("steps")
+    }
+}
+
+impl Functions {
+    /// The name of the easing in JSON, the inverse of [`FromStr`]
+    pub fn name(&self) -> Cow<'static, str> {
+        let name = match self {
+            Functions::EaseLinear => "easeLinear",
+            Functions::EaseStep => "easeStep",
+            Functions::EaseInQuad => "easeInQuad",
+            Functions::EaseOutQuad => "easeOutQuad",
+            Functions::EaseInOutQuad => "easeInOutQuad",
+            Functions::EaseInCubic => "easeInCubic",
+            Functions::EaseOutCubic => "easeOutCubic",
+            Functions::EaseInOutCubic => "easeInOutCubic",
+            Functions::EaseInQuart => "easeInQuart",
+            Functions::EaseOutQuart => "easeOutQuart",
+            Functions::EaseInOutQuart => "easeInOutQuart",
+            Functions::EaseInQuint => "easeInQuint",
+            Functions::EaseOutQuint => "easeOutQuint",
+            Functions::EaseInOutQuint => "easeInOutQuint",
+            Functions::EaseInSine => "easeInSine",
+            Functions::EaseOutSine => "easeOutSine",
+            Functions::EaseInOutSine => "easeInOutSine",
+            Functions::EaseInCirc => "easeInCirc",
+            Functions::EaseOutCirc => "easeOutCirc",
+            Functions::EaseInOutCirc => "easeInOutCirc",
+            Functions::EaseInExpo => "easeInExpo",
+            Functions::EaseOutExpo => "easeOutExpo",
+            Functions::EaseInOutExpo => "easeInOutExpo",
+            Functions::EaseInElastic => "easeInElastic",
+            Functions::EaseOutElastic => "easeOutElastic",
+            Functions::EaseInOutElastic => "easeInOutElastic",
+            Functions::EaseInBack => "easeInBack",
+            Functions::EaseOutBack => "easeOutBack",
+            Functions::EaseInOutBack => "easeInOutBack",
+            Functions::EaseInBounce => "easeInBounce",
+            Functions::EaseOutBounce => "easeOutBounce",
+            Functions::EaseInOutBounce => "easeInOutBounce",
+            Functions::EaseCubicBezier { x1, y1, x2, y2 } => {
+                return format!("cubic-bezier({x1}, {y1}, {x2}, {y2})").into();
+            }
+            Functions::EaseSteps { steps } => return format!("steps({steps})").into(),
+        };
+        name.into()
+    }
 }
 
 impl FromStr for Functions {
@@ -115,13 +236,30 @@ impl FromStr for Functions {
             "easeInBounce" => Self::EaseInBounce,
             "easeOutBounce" => Self::EaseOutBounce,
             "easeInOutBounce" => Self::EaseInOutBounce,
-            _ => return Err(()),
+            _ => return parse_parameterised(s).ok_or(()),
         };
 
         Ok(f)
     }
 }
 
+/// `cubic-bezier(x1, y1, x2, y2)` or `steps(n)`
+fn parse_parameterised(s: &str) -> Option<Functions> {
+    let (name, arguments) = s.strip_suffix(')')?.split_once('(')?;
+    let arguments: Vec<&str> = arguments.split(',').map(str::trim).collect();
+
+    match (name, arguments.as_slice()) {
+        ("cubic-bezier", [x1, y1, x2, y2]) => Functions::cubic_bezier(
+            x1.parse().ok()?,
+            y1.parse().ok()?,
+            x2.parse().ok()?,
+            y2.parse().ok()?,
+        ),
+        ("steps", [steps]) => Functions::steps(steps.parse().ok()?),
+        _ => None,
+    }
+}
+
 impl fmt::Display for Functions {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{:?}", self)
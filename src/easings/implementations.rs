@@ -98,7 +98,7 @@ pub fn ease_out_circ(p: f32) -> f32 {
 
 pub fn ease_in_out_circ(p: f32) -> f32 {
     if p < 0.5 {
-        0.5 * (1.0 - (4.0 * p * p).sqrt())
+        0.5 * (1.0 - (1.0 - (4.0 * p * p)).sqrt())
     } else {
         0.5 * ((-((2.0 * p) - 3.0) * ((2.0 * p) - 1.0)).sqrt() + 1.0)
     }
@@ -191,3 +191,256 @@ pub const fn ease_in_out_bounce(p: f32) -> f32 {
         (0.5 * ease_out_bounce((2.0 * p) - 1.0)) + 0.5
     }
 }
+
+/// Polynomial coefficients of one axis of a cubic bezier from 0 to 1,
+/// the axis being `((a * s + b) * s + c) * s`
+fn cubic_bezier_coefficients(p1: f32, p2: f32) -> (f64, f64, f64) {
+    let c = 3.0 * p1 as f64;
+    let b = 3.0 * (p2 as f64 - p1 as f64) - c;
+    (1.0 - c - b, b, c)
+}
+
+/// Solves the curve parameter whose x is `p` with Newton's method,
+/// bisecting where the curve is too flat for it to converge
+fn cubic_bezier_parameter(x1: f32, x2: f32, p: f32) -> f64 {
+    const EPSILON: f64 = 1e-7;
+
+    let (a, b, c) = cubic_bezier_coefficients(x1, x2);
+    let sample = |s: f64| ((a * s + b) * s + c) * s;
+
+    let p = p.clamp(0.0, 1.0) as f64;
+    let mut s = p;
+    for _ in 0..8 {
+        let error = sample(s) - p;
+        if error.abs() < EPSILON {
+            return s;
+        }
+        let slope = (3.0 * a * s + 2.0 * b) * s + c;
+        if slope.abs() < 1e-6 {
+            break;
+        }
+        s -= error / slope;
+    }
+
+    let (mut low, mut high) = (0.0, 1.0);
+    s = p;
+    for _ in 0..64 {
+        let x = sample(s);
+        if (x - p).abs() < EPSILON {
+            break;
+        }
+        if x < p {
+            low = s;
+        } else {
+            high = s;
+        }
+        s = (low + high) / 2.0;
+    }
+    s
+}
+
+pub fn ease_cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, p: f32) -> f32 {
+    let s = cubic_bezier_parameter(x1, x2, p);
+    let (a, b, c) = cubic_bezier_coefficients(y1, y2);
+    (((a * s + b) * s + c) * s) as f32
+}
+
+pub fn ease_steps(steps: u32, p: f32) -> f32 {
+    // zero steps would divide by zero, treat it as a single step
+    let steps = steps.max(1) as f32;
+    (p.clamp(0.0, 1.0) * steps).floor() / steps
+}
+
+// Derivatives of the easings above with respect to `p`
+
+pub const fn ease_linear_derivative(_p: f32) -> f32 {
+    1.0
+}
+
+pub const fn ease_step_derivative(_p: f32) -> f32 {
+    0.0
+}
+
+pub const fn ease_in_quad_derivative(p: f32) -> f32 {
+    2.0 * p
+}
+
+pub const fn ease_out_quad_derivative(p: f32) -> f32 {
+    2.0 - (2.0 * p)
+}
+
+pub const fn ease_in_out_quad_derivative(p: f32) -> f32 {
+    if p < 0.5 { 4.0 * p } else { (-4.0 * p) + 4.0 }
+}
+
+pub const fn ease_in_cubic_derivative(p: f32) -> f32 {
+    3.0 * p * p
+}
+
+pub const fn ease_out_cubic_derivative(p: f32) -> f32 {
+    let f = p - 1.0;
+    3.0 * f * f
+}
+
+pub const fn ease_in_out_cubic_derivative(p: f32) -> f32 {
+    if p < 0.5 {
+        12.0 * p * p
+    } else {
+        let f = (2.0 * p) - 2.0;
+        3.0 * f * f
+    }
+}
+
+pub const fn ease_in_quart_derivative(p: f32) -> f32 {
+    4.0 * p * p * p
+}
+
+pub const fn ease_out_quart_derivative(p: f32) -> f32 {
+    let f = p - 1.0;
+    -4.0 * f * f * f
+}
+
+pub const fn ease_in_out_quart_derivative(p: f32) -> f32 {
+    if p < 0.5 {
+        32.0 * p * p * p
+    } else {
+        let f = p - 1.0;
+        -32.0 * f * f * f
+    }
+}
+
+pub const fn ease_in_quint_derivative(p: f32) -> f32 {
+    5.0 * p * p * p * p
+}
+
+pub const fn ease_out_quint_derivative(p: f32) -> f32 {
+    let f = p - 1.0;
+    5.0 * f * f * f * f
+}
+
+pub const fn ease_in_out_quint_derivative(p: f32) -> f32 {
+    if p < 0.5 {
+        80.0 * p * p * p * p
+    } else {
+        let f = (2.0 * p) - 2.0;
+        5.0 * f * f * f * f
+    }
+}
+
+pub fn ease_in_sine_derivative(p: f32) -> f32 {
+    std::f32::consts::FRAC_PI_2 * ((p - 1.0) * std::f32::consts::FRAC_PI_2).cos()
+}
+
+pub fn ease_out_sine_derivative(p: f32) -> f32 {
+    std::f32::consts::FRAC_PI_2 * (p * std::f32::consts::FRAC_PI_2).cos()
+}
+
+pub fn ease_in_out_sine_derivative(p: f32) -> f32 {
+    std::f32::consts::FRAC_PI_2 * (p * std::f32::consts::PI).sin()
+}
+
+pub fn ease_in_circ_derivative(p: f32) -> f32 {
+    p / (1.0 - (p * p)).sqrt()
+}
+
+pub fn ease_out_circ_derivative(p: f32) -> f32 {
+    (1.0 - p) / ((2.0 - p) * p).sqrt()
+}
+
+pub fn ease_in_out_circ_derivative(p: f32) -> f32 {
+    if p < 0.5 {
+        (2.0 * p) / (1.0 - (4.0 * p * p)).sqrt()
+    } else {
+        (2.0 - (2.0 * p)) / (-((2.0 * p) - 3.0) * ((2.0 * p) - 1.0)).sqrt()
+    }
+}
+
+pub fn ease_in_expo_derivative(p: f32) -> f32 {
+    10.0 * std::f32::consts::LN_2 * 2.0f32.powf(10.0 * (p - 1.0))
+}
+
+pub fn ease_out_expo_derivative(p: f32) -> f32 {
+    10.0 * std::f32::consts::LN_2 * 2.0f32.powf(-10.0 * p)
+}
+
+pub fn ease_in_out_expo_derivative(p: f32) -> f32 {
+    if p < 0.5 {
+        10.0 * std::f32::consts::LN_2 * 2.0f32.powf((20.0 * p) - 10.0)
+    } else {
+        10.0 * std::f32::consts::LN_2 * 2.0f32.powf((-20.0 * p) + 10.0)
+    }
+}
+
+pub fn ease_in_elastic_derivative(p: f32) -> f32 {
+    let frequency = 13.0 * std::f32::consts::FRAC_PI_2;
+    let angle = frequency * p;
+    2.0f32.powf(10.0 * (p - 1.0))
+        * ((frequency * angle.cos()) + (10.0 * std::f32::consts::LN_2 * angle.sin()))
+}
+
+pub fn ease_out_elastic_derivative(p: f32) -> f32 {
+    let frequency = 13.0 * std::f32::consts::FRAC_PI_2;
+    let angle = -frequency * (p + 1.0);
+    let decay = 2.0f32.powf(-10.0 * p);
+    -decay * ((frequency * angle.cos()) + (10.0 * std::f32::consts::LN_2 * angle.sin()))
+}
+
+pub fn ease_in_out_elastic_derivative(p: f32) -> f32 {
+    if p < 0.5 {
+        ease_in_elastic_derivative(2.0 * p)
+    } else {
+        ease_out_elastic_derivative((2.0 * p) - 1.0)
+    }
+}
+
+pub fn ease_in_back_derivative(p: f32) -> f32 {
+    let angle = p * std::f32::consts::PI;
+    (3.0 * p * p) - angle.sin() - (angle * angle.cos())
+}
+
+pub fn ease_out_back_derivative(p: f32) -> f32 {
+    ease_in_back_derivative(1.0 - p)
+}
+
+pub fn ease_in_out_back_derivative(p: f32) -> f32 {
+    if p < 0.5 {
+        ease_in_back_derivative(2.0 * p)
+    } else {
+        ease_in_back_derivative(2.0 - (2.0 * p))
+    }
+}
+
+pub const fn ease_out_bounce_derivative(p: f32) -> f32 {
+    if p < 4.0 / 11.0 {
+        121.0 / 8.0 * p
+    } else if p < 8.0 / 11.0 {
+        (363.0 / 20.0 * p) - (99.0 / 10.0)
+    } else if p < 9.0 / 10.0 {
+        (8712.0 / 361.0 * p) - (35442.0 / 1805.0)
+    } else {
+        (108.0 / 5.0 * p) - (513.0 / 25.0)
+    }
+}
+
+pub const fn ease_in_bounce_derivative(p: f32) -> f32 {
+    ease_out_bounce_derivative(1.0 - p)
+}
+
+pub const fn ease_in_out_bounce_derivative(p: f32) -> f32 {
+    if p < 0.5 {
+        ease_in_bounce_derivative(2.0 * p)
+    } else {
+        ease_out_bounce_derivative((2.0 * p) - 1.0)
+    }
+}
+
+/// Rate of change of y over x at the curve parameter solved for `p`
+pub fn ease_cubic_bezier_derivative(x1: f32, y1: f32, x2: f32, y2: f32, p: f32) -> f32 {
+    let s = cubic_bezier_parameter(x1, x2, p);
+    let slope = |(a, b, c): (f64, f64, f64)| (3.0 * a * s + 2.0 * b) * s + c;
+    (slope(cubic_bezier_coefficients(y1, y2)) / slope(cubic_bezier_coefficients(x1, x2))) as f32
+}
+
+pub const fn ease_steps_derivative(_steps: u32, _p: f32) -> f32 {
+    0.0
+}
@@ -0,0 +1,201 @@
+use std::fmt;
+
+use thiserror::Error;
+
+use crate::values::value::BaseValueType;
+
+/// Location of a value inside a JSON document, e.g. `$[1][0]`
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct JsonPath(String);
+
+impl JsonPath {
+    pub fn root() -> Self {
+        Self("$".to_owned())
+    }
+
+    pub fn index(&self, index: usize) -> Self {
+        Self(format!("{}[{}]", self.0, index))
+    }
+
+    pub fn key(&self, key: &str) -> Self {
+        Self(format!("{}.{}", self.0, key))
+    }
+
+    /// Re-root this path under `parent`
+    pub fn rebase(&self, parent: &JsonPath) -> Self {
+        let relative = self.0.strip_prefix('$').unwrap_or(&self.0);
+        Self(format!("{}{}", parent.0, relative))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for JsonPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum ParseError {
+    #[error("{path}: expected {expected}")]
+    UnexpectedType {
+        path: JsonPath,
+        expected: &'static str,
+    },
+
+    #[error("{path}: expected {expected} numbers, found {found}")]
+    WrongArity {
+        path: JsonPath,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("{path}: no values found")]
+    MissingValues { path: JsonPath },
+
+    #[error("{path}: modifier has no operation")]
+    MissingOperation { path: JsonPath },
+
+    #[error("{path}: unknown operation \"{operation}\"")]
+    UnknownOperation { path: JsonPath, operation: String },
+
+    #[error("{path}: invalid easing \"{easing}\"")]
+    InvalidEasing { path: JsonPath, easing: String },
+
+    #[error("{path}: unknown base provider \"{name}\"")]
+    UnknownBaseProvider { path: JsonPath, name: String },
+
+    #[error("{path}: invalid suffix \"{suffix}\" on base provider \"{name}\"")]
+    InvalidBaseSuffix {
+        path: JsonPath,
+        name: String,
+        suffix: String,
+    },
+
+    #[error("{path}: cannot infer the value type of point definition \"{name}\"")]
+    UnknownValueType { path: JsonPath, name: String },
+
+    #[error("{path}: unknown point definition \"{name}\"")]
+    UnknownPointDefinition { path: JsonPath, name: String },
+
+    #[error("{path}: point definition \"{name}\" holds {found:?} values, expected {expected:?}")]
+    ValueTypeMismatch {
+        path: JsonPath,
+        name: String,
+        expected: BaseValueType,
+        found: BaseValueType,
+    },
+}
+
+impl ParseError {
+    pub fn path(&self) -> &JsonPath {
+        match self {
+            ParseError::UnexpectedType { path, .. }
+            | ParseError::WrongArity { path, .. }
+            | ParseError::MissingValues { path }
+            | ParseError::MissingOperation { path }
+            | ParseError::UnknownOperation { path, .. }
+            | ParseError::InvalidEasing { path, .. }
+            | ParseError::UnknownBaseProvider { path, .. }
+            | ParseError::InvalidBaseSuffix { path, .. }
+            | ParseError::UnknownValueType { path, .. }
+            | ParseError::UnknownPointDefinition { path, .. }
+            | ParseError::ValueTypeMismatch { path, .. } => path,
+        }
+    }
+
+    /// Re-root the error location under `parent`,
+    /// for definitions embedded in a larger document
+    pub fn rebase(mut self, parent: &JsonPath) -> Self {
+        let path = match &mut self {
+            ParseError::UnexpectedType { path, .. }
+            | ParseError::WrongArity { path, .. }
+            | ParseError::MissingValues { path }
+            | ParseError::MissingOperation { path }
+            | ParseError::UnknownOperation { path, .. }
+            | ParseError::InvalidEasing { path, .. }
+            | ParseError::UnknownBaseProvider { path, .. }
+            | ParseError::InvalidBaseSuffix { path, .. }
+            | ParseError::UnknownValueType { path, .. }
+            | ParseError::UnknownPointDefinition { path, .. }
+            | ParseError::ValueTypeMismatch { path, .. } => path,
+        };
+        *path = path.rebase(parent);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum BaseProviderError {
+    #[error("unknown base provider \"{0}\"")]
+    Unknown(String),
+
+    #[error("invalid suffix \"{suffix}\" on base provider \"{name}\"")]
+    InvalidSuffix { name: String, suffix: String },
+
+    #[error("base provider \"{name}\" is already registered as {ty:?}")]
+    AlreadyRegistered { name: String, ty: BaseValueType },
+
+    #[error("no base value holds {0} numbers")]
+    InvalidValueCount(usize),
+
+    #[error("base provider \"{name}\" holds {expected:?} values, got {found:?}")]
+    TypeMismatch {
+        name: String,
+        expected: BaseValueType,
+        found: BaseValueType,
+    },
+
+    #[error("base provider \"{name}\" holds {ty:?} values, got {found} numbers")]
+    WrongValueCount {
+        name: String,
+        ty: BaseValueType,
+        found: usize,
+    },
+}
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum PropertyError {
+    #[error("property holds {expected:?} values, got {found:?}")]
+    TypeMismatch {
+        expected: BaseValueType,
+        found: BaseValueType,
+    },
+}
+
+/// Why a call into the C API failed, see `tracks_last_error`
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum FfiError {
+    #[error("{0} is null")]
+    NullPointer(&'static str),
+
+    #[error("{0} is not valid UTF-8")]
+    InvalidUtf8(&'static str),
+
+    #[error("JSON numbers must be finite, got {0}")]
+    NonFiniteNumber(f64),
+
+    #[error("invalid JSON: {0}")]
+    InvalidJson(String),
+
+    #[error("invalid easing {0}")]
+    InvalidEasing(String),
+
+    #[error("{value} is not a valid {name}")]
+    InvalidEnum { name: &'static str, value: u32 },
+
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+
+    #[error(transparent)]
+    BaseProvider(#[from] BaseProviderError),
+
+    #[error(transparent)]
+    Property(#[from] PropertyError),
+
+    #[error("panicked: {0}")]
+    Panic(String),
+}
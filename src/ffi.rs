@@ -1,17 +1,44 @@
+use crate::animation::coroutine_manager::CoroutineManager;
+use crate::easings::functions::Functions;
+use crate::error::FfiError;
+use crate::panic_message;
+use crate::point_definition::base_point_definition::BasePointDefinition;
+use crate::point_definition::bool_point_definition::BoolPointDefinition;
 use crate::point_definition::float_point_definition::FloatPointDefinition;
+use crate::point_definition::library::PointDefinitionLibrary;
 use crate::point_definition::quaternion_point_definition::QuaternionPointDefinition;
+use crate::point_definition::vector2_point_definition::Vector2PointDefinition;
 use crate::point_definition::vector4_point_definition::Vector4PointDefinition;
-use crate::point_definition::{PointDefinition, vector3_point_definition::Vector3PointDefinition};
+use crate::point_definition::{
+    PointDefinition, TrackValue, vector3_point_definition::Vector3PointDefinition,
+};
+use crate::tracks::path_property::PathPropertyNames;
+use crate::tracks::property::PropertyNames;
+use crate::tracks::track::Track;
+use crate::tracks::track_registry::{TrackKey, TrackRegistry};
 use crate::values::base_ffi::{BaseFFIProvider, BaseFFIProviderValues};
-use crate::values::base_provider_context::{BaseProviderContext};
-use crate::values::value::BaseValue;
-use std::ffi::{CStr, c_char};
+use crate::values::base_provider_context::{BaseProviderContext, BaseProviderId};
+use crate::values::value::{BaseValue, BaseValueType};
+use glam::{Quat, Vec2, Vec3, Vec4};
+use std::cell::RefCell;
+use std::ffi::{CStr, CString, c_char};
 use std::os::raw::c_void;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
 use std::slice;
-use tracing::info;
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::error;
 
 #[repr(C)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WrapVec2 {
+    x: f32,
+    y: f32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct WrapVec3 {
     x: f32,
     y: f32,
@@ -19,7 +46,7 @@ pub struct WrapVec3 {
 }
 
 #[repr(C)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct WrapVec4 {
     x: f32,
     y: f32,
@@ -28,7 +55,7 @@ pub struct WrapVec4 {
 }
 
 #[repr(C)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct WrapQuat {
     x: f32,
     y: f32,
@@ -36,6 +63,138 @@ pub struct WrapQuat {
     w: f32,
 }
 
+impl From<Vec2> for WrapVec2 {
+    fn from(v: Vec2) -> Self {
+        WrapVec2 { x: v.x, y: v.y }
+    }
+}
+
+impl From<Vec3> for WrapVec3 {
+    fn from(v: Vec3) -> Self {
+        WrapVec3 {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl From<Vec4> for WrapVec4 {
+    fn from(v: Vec4) -> Self {
+        WrapVec4 {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+            w: v.w,
+        }
+    }
+}
+
+impl From<Quat> for WrapQuat {
+    fn from(v: Quat) -> Self {
+        WrapQuat {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+            w: v.w,
+        }
+    }
+}
+
+/// An easing as passed through the C API
+///
+/// Plain data rather than [`Functions`] itself, so any value C writes is sound to read.
+/// Build it with the `tracks_easing_*` constructors; entry points that take one reject
+/// unknown ids and invalid parameters.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WrapEasing {
+    pub id: u32,
+    pub steps: u32,
+    pub bezier: [f32; 4],
+}
+
+pub const EASING_CUBIC_BEZIER: u32 = 32;
+pub const EASING_STEPS: u32 = 33;
+
+/// The easings without parameters, indexed by their id
+const SIMPLE_EASINGS: [Functions; 32] = [
+    Functions::EaseLinear,
+    Functions::EaseStep,
+    Functions::EaseInQuad,
+    Functions::EaseOutQuad,
+    Functions::EaseInOutQuad,
+    Functions::EaseInCubic,
+    Functions::EaseOutCubic,
+    Functions::EaseInOutCubic,
+    Functions::EaseInQuart,
+    Functions::EaseOutQuart,
+    Functions::EaseInOutQuart,
+    Functions::EaseInQuint,
+    Functions::EaseOutQuint,
+    Functions::EaseInOutQuint,
+    Functions::EaseInSine,
+    Functions::EaseOutSine,
+    Functions::EaseInOutSine,
+    Functions::EaseInCirc,
+    Functions::EaseOutCirc,
+    Functions::EaseInOutCirc,
+    Functions::EaseInExpo,
+    Functions::EaseOutExpo,
+    Functions::EaseInOutExpo,
+    Functions::EaseInElastic,
+    Functions::EaseOutElastic,
+    Functions::EaseInOutElastic,
+    Functions::EaseInBack,
+    Functions::EaseOutBack,
+    Functions::EaseInOutBack,
+    Functions::EaseInBounce,
+    Functions::EaseOutBounce,
+    Functions::EaseInOutBounce,
+];
+
+impl From<Functions> for WrapEasing {
+    fn from(easing: Functions) -> Self {
+        let mut wrapped = WrapEasing {
+            id: 0,
+            steps: 0,
+            bezier: [0.0; 4],
+        };
+        match easing {
+            Functions::EaseCubicBezier { x1, y1, x2, y2 } => {
+                wrapped.id = EASING_CUBIC_BEZIER;
+                wrapped.bezier = [x1, y1, x2, y2];
+            }
+            Functions::EaseSteps { steps } => {
+                wrapped.id = EASING_STEPS;
+                wrapped.steps = steps;
+            }
+            simple => {
+                wrapped.id = SIMPLE_EASINGS
+                    .iter()
+                    .position(|f| *f == simple)
+                    .expect("every easing without parameters has an id")
+                    as u32;
+            }
+        }
+        wrapped
+    }
+}
+
+impl TryFrom<WrapEasing> for Functions {
+    type Error = FfiError;
+
+    fn try_from(easing: WrapEasing) -> Result<Self, FfiError> {
+        let [x1, y1, x2, y2] = easing.bezier;
+        let function = match easing.id {
+            EASING_CUBIC_BEZIER => Functions::cubic_bezier(x1, y1, x2, y2),
+            EASING_STEPS => Functions::steps(easing.steps),
+            id => SIMPLE_EASINGS.get(id as usize).cloned(),
+        };
+        function.ok_or_else(|| FfiError::InvalidEasing(format!("{easing:?}")))
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub enum WrapBaseValueType {
@@ -43,8 +202,14 @@ pub enum WrapBaseValueType {
     Quat = 1,
     Vec4 = 2,
     Float = 3,
+    Vec2 = 4,
+    Boolean = 5,
 }
 
+/// `float` and `bool` are keywords in C, so those fields are exposed
+/// as `float_value` and `bool_value`
+///
+/// cbindgen:field-names=[vec3, quat, vec4, float_value, vec2, bool_value]
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union WrapBaseValueUnion {
@@ -52,15 +217,102 @@ pub union WrapBaseValueUnion {
     quat: WrapQuat,
     vec4: WrapVec4,
     float: f32,
+    vec2: WrapVec2,
+    bool: bool,
 }
 
+/// `ty` is a `WrapBaseValueType` telling which union field is set, any other value is an error
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct WrapBaseValue {
-    ty: WrapBaseValueType,
+    ty: u32,
     value: WrapBaseValueUnion,
 }
 
+impl From<BaseValue> for WrapBaseValue {
+    fn from(value: BaseValue) -> Self {
+        match value {
+            BaseValue::Float(v) => WrapBaseValue {
+                ty: WrapBaseValueType::Float as u32,
+                value: WrapBaseValueUnion { float: v },
+            },
+            BaseValue::Vector2(v) => WrapBaseValue {
+                ty: WrapBaseValueType::Vec2 as u32,
+                value: WrapBaseValueUnion {
+                    vec2: WrapVec2 { x: v.x, y: v.y },
+                },
+            },
+            BaseValue::Vector3(v) => WrapBaseValue {
+                ty: WrapBaseValueType::Vec3 as u32,
+                value: WrapBaseValueUnion {
+                    vec3: WrapVec3 {
+                        x: v.x,
+                        y: v.y,
+                        z: v.z,
+                    },
+                },
+            },
+            BaseValue::Vector4(v) => WrapBaseValue {
+                ty: WrapBaseValueType::Vec4 as u32,
+                value: WrapBaseValueUnion {
+                    vec4: WrapVec4 {
+                        x: v.x,
+                        y: v.y,
+                        z: v.z,
+                        w: v.w,
+                    },
+                },
+            },
+            BaseValue::Quaternion(v) => WrapBaseValue {
+                ty: WrapBaseValueType::Quat as u32,
+                value: WrapBaseValueUnion {
+                    quat: WrapQuat {
+                        x: v.x,
+                        y: v.y,
+                        z: v.z,
+                        w: v.w,
+                    },
+                },
+            },
+            BaseValue::Bool(v) => WrapBaseValue {
+                ty: WrapBaseValueType::Boolean as u32,
+                value: WrapBaseValueUnion { bool: v },
+            },
+        }
+    }
+}
+
+impl TryFrom<WrapBaseValue> for BaseValue {
+    type Error = FfiError;
+
+    fn try_from(value: WrapBaseValue) -> Result<Self, FfiError> {
+        // the tag tells us which union field was written
+        let value = unsafe {
+            match enum_arg(value.ty)? {
+                WrapBaseValueType::Float => BaseValue::Float(value.value.float),
+                WrapBaseValueType::Vec3 => {
+                    let v = value.value.vec3;
+                    BaseValue::Vector3(Vec3::new(v.x, v.y, v.z))
+                }
+                WrapBaseValueType::Vec4 => {
+                    let v = value.value.vec4;
+                    BaseValue::Vector4(Vec4::new(v.x, v.y, v.z, v.w))
+                }
+                WrapBaseValueType::Quat => {
+                    let v = value.value.quat;
+                    BaseValue::Quaternion(Quat::from_xyzw(v.x, v.y, v.z, v.w))
+                }
+                WrapBaseValueType::Vec2 => {
+                    let v = value.value.vec2;
+                    BaseValue::Vector2(Vec2::new(v.x, v.y))
+                }
+                WrapBaseValueType::Boolean => BaseValue::Bool(value.value.bool),
+            }
+        };
+        Ok(value)
+    }
+}
+
 #[derive(Copy, Clone)]
 #[repr(C)]
 pub struct WrappedValues {
@@ -74,6 +326,12 @@ pub struct FloatInterpolationResult {
     pub is_last: bool,
 }
 
+#[repr(C)]
+pub struct Vector2InterpolationResult {
+    pub value: WrapVec2,
+    pub is_last: bool,
+}
+
 #[repr(C)]
 pub struct Vector3InterpolationResult {
     pub value: WrapVec3,
@@ -92,19 +350,189 @@ pub struct QuaternionInterpolationResult {
     pub is_last: bool,
 }
 
+#[repr(C)]
+pub struct BoolInterpolationResult {
+    pub value: bool,
+    pub is_last: bool,
+}
+
+/// ERRORS
+///
+/// Entry points never unwind into C. When one fails it returns false, null
+/// or a default value, and `tracks_last_error` tells why.
+#[unsafe(no_mangle)]
+pub extern "C" fn tracks_last_error() -> *const c_char {
+    LAST_ERROR.with_borrow(|e| e.as_ref().map_or(ptr::null(), |e| e.as_ptr()))
+}
+
+thread_local! {
+    /// Why the last call on this thread failed, `None` if it succeeded
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Runs the body of an entry point, catching panics since unwinding out of
+/// an `extern "C"` function aborts. Failures return `fallback`.
+fn ffi_try<T>(fallback: T, body: impl FnOnce() -> Result<T, FfiError>) -> T {
+    let result = panic::catch_unwind(AssertUnwindSafe(body))
+        .unwrap_or_else(|payload| Err(FfiError::Panic(panic_message(&*payload).to_owned())));
+
+    match result {
+        Ok(value) => {
+            LAST_ERROR.set(None);
+            value
+        }
+        Err(e) => {
+            error!("{}", e);
+            let message = CString::new(e.to_string().replace('\0', "")).unwrap_or_default();
+            LAST_ERROR.set(Some(message));
+            fallback
+        }
+    }
+}
+
+unsafe fn deref<'a, T>(ptr: *const T, name: &'static str) -> Result<&'a T, FfiError> {
+    unsafe { ptr.as_ref() }.ok_or(FfiError::NullPointer(name))
+}
+
+unsafe fn deref_mut<'a, T>(ptr: *mut T, name: &'static str) -> Result<&'a mut T, FfiError> {
+    unsafe { ptr.as_mut() }.ok_or(FfiError::NullPointer(name))
+}
+
+unsafe fn c_str<'a>(ptr: *const c_char, name: &'static str) -> Result<&'a str, FfiError> {
+    if ptr.is_null() {
+        return Err(FfiError::NullPointer(name));
+    }
+    unsafe { CStr::from_ptr(ptr) }
+        .to_str()
+        .map_err(|_| FfiError::InvalidUtf8(name))
+}
+
+/// `ptr` may be null when `len` is 0
+unsafe fn slice_arg<'a, T>(
+    ptr: *const T,
+    len: usize,
+    name: &'static str,
+) -> Result<&'a [T], FfiError> {
+    match len {
+        0 => Ok(&[]),
+        _ if ptr.is_null() => Err(FfiError::NullPointer(name)),
+        _ => Ok(unsafe { slice::from_raw_parts(ptr, len) }),
+    }
+}
+
+/// `ptr` may be null when `len` is 0
+unsafe fn slice_arg_mut<'a, T>(
+    ptr: *mut T,
+    len: usize,
+    name: &'static str,
+) -> Result<&'a mut [T], FfiError> {
+    match len {
+        0 => Ok(&mut []),
+        _ if ptr.is_null() => Err(FfiError::NullPointer(name)),
+        _ => Ok(unsafe { slice::from_raw_parts_mut(ptr, len) }),
+    }
+}
+
+/// A C enum passed as its `u32` value, since C may hold any value in an enum.
+/// `VARIANTS` lists the variants in declaration order, indexed by value.
+trait FfiEnum: Copy + 'static {
+    const NAME: &'static str;
+    const VARIANTS: &'static [Self];
+}
+
+impl FfiEnum for PropertyNames {
+    const NAME: &'static str = "PropertyNames";
+    const VARIANTS: &'static [Self] = &[
+        PropertyNames::Position,
+        PropertyNames::LocalPosition,
+        PropertyNames::Rotation,
+        PropertyNames::LocalRotation,
+        PropertyNames::Scale,
+        PropertyNames::Dissolve,
+        PropertyNames::DissolveArrow,
+        PropertyNames::Interactable,
+        PropertyNames::Time,
+        PropertyNames::Color,
+    ];
+}
+
+impl FfiEnum for PathPropertyNames {
+    const NAME: &'static str = "PathPropertyNames";
+    const VARIANTS: &'static [Self] = &[
+        PathPropertyNames::Position,
+        PathPropertyNames::Rotation,
+        PathPropertyNames::LocalRotation,
+        PathPropertyNames::Scale,
+        PathPropertyNames::DefinitePosition,
+        PathPropertyNames::Dissolve,
+        PathPropertyNames::DissolveArrow,
+        PathPropertyNames::Interactable,
+        PathPropertyNames::Color,
+    ];
+}
+
+impl FfiEnum for BaseValueType {
+    const NAME: &'static str = "BaseValueType";
+    const VARIANTS: &'static [Self] = &[
+        BaseValueType::Float,
+        BaseValueType::Vector3,
+        BaseValueType::Vector4,
+        BaseValueType::Quaternion,
+        BaseValueType::Vector2,
+        BaseValueType::Bool,
+    ];
+}
+
+impl FfiEnum for WrapBaseValueType {
+    const NAME: &'static str = "WrapBaseValueType";
+    const VARIANTS: &'static [Self] = &[
+        WrapBaseValueType::Vec3,
+        WrapBaseValueType::Quat,
+        WrapBaseValueType::Vec4,
+        WrapBaseValueType::Float,
+        WrapBaseValueType::Vec2,
+        WrapBaseValueType::Boolean,
+    ];
+}
+
+impl FfiEnum for JsonValueType {
+    const NAME: &'static str = "JsonValueType";
+    const VARIANTS: &'static [Self] = &[
+        JsonValueType::Number,
+        JsonValueType::Null,
+        JsonValueType::String,
+        JsonValueType::Array,
+        JsonValueType::Bool,
+        JsonValueType::Object,
+    ];
+}
+
+fn enum_arg<T: FfiEnum>(value: u32) -> Result<T, FfiError> {
+    T::VARIANTS
+        .get(value as usize)
+        .copied()
+        .ok_or(FfiError::InvalidEnum {
+            name: T::NAME,
+            value,
+        })
+}
+
 /// JSON FFI
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 pub enum JsonValueType {
     Number,
     Null,
     String,
     Array,
+    Bool,
+    Object,
 }
 
+/// `value_type` is a `JsonValueType`, any other value is an error
 #[repr(C)]
 pub struct FFIJsonValue {
-    pub value_type: JsonValueType,
+    pub value_type: u32,
     pub data: JsonValueData,
 }
 
@@ -113,6 +541,8 @@ pub union JsonValueData {
     pub number_value: f64,
     pub string_value: *const c_char,
     pub array: *const JsonArray,
+    pub bool_value: bool,
+    pub object: *const JsonObject,
 }
 
 #[repr(C)]
@@ -121,102 +551,274 @@ pub struct JsonArray {
     pub length: usize,
 }
 
-#[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_create_json_number(value: f64) -> FFIJsonValue {
+/// `length` keys and the values at the same index
+#[repr(C)]
+pub struct JsonObject {
+    pub keys: *const *const c_char,
+    pub values: *const FFIJsonValue,
+    pub length: usize,
+}
+
+fn json_null() -> FFIJsonValue {
     FFIJsonValue {
-        value_type: JsonValueType::Number,
-        data: JsonValueData {
-            number_value: value,
-        },
+        value_type: JsonValueType::Null as u32,
+        data: JsonValueData { number_value: 0.0 },
     }
 }
 
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_create_json_number(value: f64) -> FFIJsonValue {
+    ffi_try(json_null(), || {
+        Ok(FFIJsonValue {
+            value_type: JsonValueType::Number as u32,
+            data: JsonValueData {
+                number_value: value,
+            },
+        })
+    })
+}
+
+/// The string is copied, `value` can be freed once this returns
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn tracks_create_json_string(value: *const c_char) -> FFIJsonValue {
-    FFIJsonValue {
-        value_type: JsonValueType::String,
-        data: JsonValueData {
-            string_value: value,
-        },
-    }
+    ffi_try(json_null(), || {
+        if value.is_null() {
+            return Err(FfiError::NullPointer("value"));
+        }
+        let value = unsafe { CStr::from_ptr(value) }.to_owned();
+        Ok(FFIJsonValue {
+            value_type: JsonValueType::String as u32,
+            data: JsonValueData {
+                string_value: value.into_raw(),
+            },
+        })
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_create_json_bool(value: bool) -> FFIJsonValue {
+    ffi_try(json_null(), || {
+        Ok(FFIJsonValue {
+            value_type: JsonValueType::Bool as u32,
+            data: JsonValueData { bool_value: value },
+        })
+    })
 }
 
+/// Moves `elements` into a new array, which then owns them.
+/// Free the returned value, not the elements, once done with it.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn tracks_create_json_array(
     elements: *const FFIJsonValue,
     length: usize,
 ) -> FFIJsonValue {
-    // Allocate JsonArray on the heap so it persists after function returns
-    let array = Box::new(JsonArray { elements, length });
+    ffi_try(json_null(), || {
+        let elements = unsafe { slice_arg(elements, length, "elements")? };
+        let elements: Box<[FFIJsonValue]> =
+            elements.iter().map(|e| unsafe { ptr::read(e) }).collect();
 
-    // Leak the box to keep it alive (caller is responsible for freeing)
-    let array_ptr = Box::into_raw(array);
+        let array = Box::new(JsonArray {
+            length: elements.len(),
+            elements: Box::into_raw(elements) as *const FFIJsonValue,
+        });
 
-    FFIJsonValue {
-        value_type: JsonValueType::Array,
-        data: JsonValueData { array: array_ptr },
-    }
+        Ok(FFIJsonValue {
+            value_type: JsonValueType::Array as u32,
+            data: JsonValueData {
+                array: Box::into_raw(array),
+            },
+        })
+    })
+}
+
+/// Copies the `length` keys and moves the `length` values into a new object,
+/// a later duplicate key replaces the earlier one.
+/// Free the returned value, not the values, once done with it.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_create_json_object(
+    keys: *const *const c_char,
+    values: *const FFIJsonValue,
+    length: usize,
+) -> FFIJsonValue {
+    ffi_try(json_null(), || {
+        let keys = unsafe { slice_arg(keys, length, "keys")? };
+        let values = unsafe { slice_arg(values, length, "values")? };
+        if keys.iter().any(|key| key.is_null()) {
+            return Err(FfiError::NullPointer("keys"));
+        }
+
+        let keys: Box<[*const c_char]> = keys
+            .iter()
+            .map(|key| unsafe { CStr::from_ptr(*key) }.to_owned().into_raw() as *const c_char)
+            .collect();
+        let values: Box<[FFIJsonValue]> = values.iter().map(|v| unsafe { ptr::read(v) }).collect();
+
+        let object = Box::new(JsonObject {
+            length: keys.len(),
+            keys: Box::into_raw(keys) as *const *const c_char,
+            values: Box::into_raw(values) as *const FFIJsonValue,
+        });
+
+        Ok(FFIJsonValue {
+            value_type: JsonValueType::Object as u32,
+            data: JsonValueData {
+                object: Box::into_raw(object),
+            },
+        })
+    })
 }
 
 /// Convert the FFI JsonValue to a serde_json::Value
-unsafe fn convert_json_value_to_serde(json_value: *const FFIJsonValue) -> serde_json::Value {
+unsafe fn convert_json_value_to_serde(
+    json_value: *const FFIJsonValue,
+) -> Result<serde_json::Value, FfiError> {
     if json_value.is_null() {
-        return serde_json::Value::Null;
+        return Ok(serde_json::Value::Null);
     }
 
     let json_value = unsafe { &*json_value };
-    match json_value.value_type {
+    let value = match enum_arg(json_value.value_type)? {
         JsonValueType::Null => serde_json::Value::Null,
-        JsonValueType::Number => serde_json::Value::Number(
-            serde_json::Number::from_f64(unsafe { json_value.data.number_value }).unwrap(),
-        ),
+        JsonValueType::Number => {
+            let number = unsafe { json_value.data.number_value };
+            serde_json::Number::from_f64(number)
+                .ok_or(FfiError::NonFiniteNumber(number))?
+                .into()
+        }
         JsonValueType::String => {
-            let c_str = unsafe { CStr::from_ptr(json_value.data.string_value) };
-            let str_slice = c_str.to_str().unwrap_or_default();
-            serde_json::Value::String(str_slice.to_owned())
+            let string = unsafe { c_str(json_value.data.string_value, "string")? };
+            serde_json::Value::String(string.to_owned())
         }
         JsonValueType::Array => {
-            let array_ptr = unsafe { json_value.data.array };
-            if array_ptr.is_null() {
-                return serde_json::Value::Array(Vec::new());
-            }
-
-            let array = unsafe { &*array_ptr };
+            let Some(array) = (unsafe { json_value.data.array.as_ref() }) else {
+                return Ok(serde_json::Value::Array(Vec::new()));
+            };
 
             // Validate array length - prevent unreasonable allocations
             // 10 million elements should be more than enough for any reasonable JSON array
             // while preventing buffer overflows from corrupted memory
             const MAX_SAFE_ARRAY_LENGTH: usize = 10_000_000;
 
-            if array.elements.is_null() || array.length == 0 || array.length > MAX_SAFE_ARRAY_LENGTH
-            {
-                println!(
-                    "Invalid array length or null elements pointer: {}",
+            if array.length > MAX_SAFE_ARRAY_LENGTH {
+                return Err(FfiError::InvalidJson(format!(
+                    "array length {} is too large",
                     array.length
-                );
-                return serde_json::Value::Array(Vec::new());
+                )));
             }
 
-            // Create a safe slice from the raw parts
-            let elements = unsafe { slice::from_raw_parts(array.elements, array.length) };
-            let mut json_array = Vec::new(); // Don't pre-allocate with potentially corrupted capacity
+            let elements = unsafe { slice_arg(array.elements, array.length, "elements")? };
+            let json_array = elements
+                .iter()
+                .map(|element| unsafe { convert_json_value_to_serde(element) })
+                .collect::<Result<_, _>>()?;
 
-            for element in elements.iter() {
-                json_array.push(unsafe { convert_json_value_to_serde(element) });
+            serde_json::Value::Array(json_array)
+        }
+        JsonValueType::Bool => serde_json::Value::Bool(unsafe { json_value.data.bool_value }),
+        JsonValueType::Object => {
+            let object = unsafe { json_value.data.object };
+            let (keys, values) = match unsafe { object.as_ref() } {
+                Some(object) => unsafe {
+                    (
+                        slice_arg(object.keys, object.length, "keys")?,
+                        slice_arg(object.values, object.length, "values")?,
+                    )
+                },
+                None => (&[][..], &[][..]),
+            };
+
+            let mut json_object = serde_json::Map::new();
+            for (key, value) in keys.iter().zip(values) {
+                let key = unsafe { c_str(*key, "key")? };
+                json_object.insert(key.to_owned(), unsafe {
+                    convert_json_value_to_serde(value)?
+                });
             }
 
-            serde_json::Value::Array(json_array)
+            serde_json::Value::Object(json_object)
         }
-    }
+    };
+    Ok(value)
+}
+
+/// Parses `len` bytes of UTF-8 JSON text, which need not be null-terminated
+unsafe fn parse_json_str(json: *const c_char, len: usize) -> Result<serde_json::Value, FfiError> {
+    let json = unsafe { slice_arg(json as *const u8, len, "json")? };
+    serde_json::from_slice(json).map_err(|e| FfiError::InvalidJson(e.to_string()))
 }
 
+/// Frees everything `json_value` owns, nested arrays included, and resets it to null.
+/// The `FFIJsonValue` itself is the caller's.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn tracks_free_json_value(json_value: *mut FFIJsonValue) {
-    // Free the memory allocated for the JsonValue
-    // This is a simple implementation that doesn't handle nested structures
-    // For a complete implementation, you would need to recursively free all nested elements
-    if !json_value.is_null() {
-        drop(unsafe { Box::from_raw(json_value) });
+    ffi_try((), || {
+        if json_value.is_null() {
+            return Ok(());
+        }
+
+        let json_value = unsafe { &mut *json_value };
+        unsafe { free_json_contents(json_value) };
+        json_value.value_type = JsonValueType::Null as u32;
+        Ok(())
+    })
+}
+
+unsafe fn free_json_contents(json_value: &mut FFIJsonValue) {
+    // nothing of ours to free behind an invalid tag
+    let Ok(value_type) = enum_arg(json_value.value_type) else {
+        return;
+    };
+    match value_type {
+        JsonValueType::Number | JsonValueType::Null | JsonValueType::Bool => {}
+        JsonValueType::String => {
+            let string = unsafe { json_value.data.string_value };
+            if !string.is_null() {
+                drop(unsafe { CString::from_raw(string as *mut c_char) });
+            }
+        }
+        JsonValueType::Array => {
+            let array = unsafe { json_value.data.array };
+            if array.is_null() {
+                return;
+            }
+
+            let array = unsafe { Box::from_raw(array as *mut JsonArray) };
+            let mut elements = unsafe {
+                Box::from_raw(ptr::slice_from_raw_parts_mut(
+                    array.elements as *mut FFIJsonValue,
+                    array.length,
+                ))
+            };
+            for element in elements.iter_mut() {
+                unsafe { free_json_contents(element) };
+            }
+        }
+        JsonValueType::Object => {
+            let object = unsafe { json_value.data.object };
+            if object.is_null() {
+                return;
+            }
+
+            let object = unsafe { Box::from_raw(object as *mut JsonObject) };
+            let keys = unsafe {
+                Box::from_raw(ptr::slice_from_raw_parts_mut(
+                    object.keys as *mut *const c_char,
+                    object.length,
+                ))
+            };
+            let mut values = unsafe {
+                Box::from_raw(ptr::slice_from_raw_parts_mut(
+                    object.values as *mut FFIJsonValue,
+                    object.length,
+                ))
+            };
+            for key in keys.iter().filter(|key| !key.is_null()) {
+                drop(unsafe { CString::from_raw(*key as *mut c_char) });
+            }
+            for value in values.iter_mut() {
+                unsafe { free_json_contents(value) };
+            }
+        }
     }
 }
 
@@ -225,235 +827,1638 @@ pub unsafe extern "C" fn tracks_make_base_ffi_provider(
     func: *const BaseFFIProvider,
     user_value: *mut c_void,
 ) -> *mut BaseFFIProviderValues {
-    assert!(!func.is_null());
+    ffi_try(ptr::null_mut(), || {
+        if func.is_null() {
+            return Err(FfiError::NullPointer("func"));
+        }
 
-    let context = Box::new(BaseFFIProviderValues::new(func, user_value));
-    let context_ptr = Box::leak(context);
-    context_ptr
+        let context = Box::new(BaseFFIProviderValues::new(func, user_value));
+        Ok(Box::leak(context))
+    })
 }
 
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_dipose_base_ffi_provider(func: *mut BaseFFIProviderValues) {
-    assert!(!func.is_null());
+pub unsafe extern "C" fn tracks_free_base_ffi_provider(func: *mut BaseFFIProviderValues) {
+    ffi_try((), || {
+        if !func.is_null() {
+            drop(unsafe { Box::from_raw(func) });
+        }
+        Ok(())
+    })
+}
 
-    // destroy
-    unsafe {
-        let _ = Box::from_raw(func);
-    };
+/// Misspelled alias of `tracks_free_base_ffi_provider`, kept for existing callers
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_dipose_base_ffi_provider(func: *mut BaseFFIProviderValues) {
+    unsafe { tracks_free_base_ffi_provider(func) };
 }
 
 /// CONTEXT
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn tracks_make_base_provider_context() -> *mut BaseProviderContext {
-    let context = Box::new(BaseProviderContext::new());
-    let context_ptr = Box::leak(context);
-    context_ptr
+    ffi_try(ptr::null_mut(), || {
+        let context = Box::new(BaseProviderContext::new());
+        Ok(Box::leak(context))
+    })
 }
 
+/// Point definitions made with the context can still be freed afterwards, just not interpolated
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_set_base_provider(
-    context: *mut BaseProviderContext,
-    base: *const c_char,
-    values: *mut f32,
-    count: usize,
-    quat: bool,
-) {
-    let base_str = unsafe { CStr::from_ptr(base).to_str().unwrap() };
-    let context = unsafe { &mut *context };
-    context.set_values(base_str, unsafe {
-        let v = slice::from_raw_parts(values, count);
-        info!("v: {} {:?}", base_str, v);
-        BaseValue::from_slice(v, quat)
-    });
+pub unsafe extern "C" fn tracks_free_base_provider_context(context: *mut BaseProviderContext) {
+    ffi_try((), || {
+        if !context.is_null() {
+            drop(unsafe { Box::from_raw(context) });
+        }
+        Ok(())
+    })
 }
 
-///FLOAT POINT DEFINITION
+/// Declares a base provider at runtime, returns false if the name is already registered with another type.
+/// `ty` is a `BaseValueType`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_make_float_point_definition(
-    json: *const FFIJsonValue,
+pub unsafe extern "C" fn tracks_register_base_provider(
     context: *mut BaseProviderContext,
-) -> *const FloatPointDefinition {
-    let value = unsafe { convert_json_value_to_serde(json) };
-    let point_definition = Box::new(FloatPointDefinition::new(
-        value,
-        unsafe { &mut *context },
-    ));
-    let point_definition_ptr = Box::leak(point_definition);
-    point_definition_ptr
+    base: *const c_char,
+    ty: u32,
+) -> bool {
+    ffi_try(false, || {
+        let base_str = unsafe { c_str(base, "base")? };
+        let context = unsafe { deref_mut(context, "context")? };
+        context.register(base_str, enum_arg(ty)?)?;
+        Ok(true)
+    })
 }
 
+/// Reads `values` as the type the base provider was registered with: quaternions as
+/// `x, y, z, w` and bools as non-zero. Returns false if the provider is unknown or
+/// `count` does not fit its type.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_interpolate_float(
-    point_definition: *const FloatPointDefinition,
-    time: f32,
+pub unsafe extern "C" fn tracks_set_base_provider(
     context: *mut BaseProviderContext,
-) -> FloatInterpolationResult {
-    let point_definition = unsafe { &*point_definition };
-    let (value, is_last) = point_definition.interpolate(time, unsafe { &*context });
-    FloatInterpolationResult { value, is_last }
+    base: *const c_char,
+    values: *const f32,
+    count: usize,
+) -> bool {
+    ffi_try(false, || {
+        let base_str = unsafe { c_str(base, "base")? };
+        let context = unsafe { deref_mut(context, "context")? };
+        let v = unsafe { slice_arg(values, count, "values")? };
+        context.set_values_from_slice(base_str, v)?;
+        Ok(true)
+    })
 }
 
+/// Resolves a base provider name once so it can be set every frame without a string lookup.
+/// Returns false if the name is unknown.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_float_count(
-    point_definition: *const FloatPointDefinition,
-) -> usize {
-    let point_definition = unsafe { &*point_definition };
-    point_definition.get_count()
+pub unsafe extern "C" fn tracks_get_base_provider_id(
+    context: *const BaseProviderContext,
+    base: *const c_char,
+    out: *mut BaseProviderId,
+) -> bool {
+    ffi_try(false, || {
+        let base_str = unsafe { c_str(base, "base")? };
+        let context = unsafe { deref(context, "context")? };
+        let out = unsafe { deref_mut(out, "out")? };
+        match context.get_id(base_str) {
+            Some(id) => {
+                *out = id;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    })
 }
 
+/// Like `tracks_set_base_provider`, returns false if `count` does not fit the provider's type
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_float_has_base_provider(
-    point_definition: *const FloatPointDefinition,
+pub unsafe extern "C" fn tracks_set_base_provider_by_id(
+    context: *mut BaseProviderContext,
+    id: BaseProviderId,
+    values: *const f32,
+    count: usize,
 ) -> bool {
-    let point_definition = unsafe { &*point_definition };
-    point_definition.has_base_provider()
+    ffi_try(false, || {
+        let context = unsafe { deref_mut(context, "context")? };
+        let values = unsafe { slice_arg(values, count, "values")? };
+        context.set_values_from_slice_by_id(id, values)?;
+        Ok(true)
+    })
 }
 
-///VECTOR3 POINT DEFINITION
+/// Advances smoothed base providers (`.s<mult>`) by the frame's duration.
+/// Call once per frame after setting that frame's base values.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_make_vector3_point_definition(
-    json: *const FFIJsonValue,
+pub unsafe extern "C" fn tracks_context_tick(
     context: *mut BaseProviderContext,
-) -> *const Vector3PointDefinition {
-    let value = unsafe { convert_json_value_to_serde(json) };
-    let point_definition = Box::new(Vector3PointDefinition::new(
-        value,
-        unsafe { &mut *context },
-    ));
-    let point_definition_ptr = Box::leak(point_definition);
-    point_definition_ptr
+    delta_seconds: f32,
+) -> bool {
+    ffi_try(false, || {
+        let context = unsafe { deref_mut(context, "context")? };
+        context.tick(delta_seconds);
+        Ok(true)
+    })
 }
 
+/// TRACKS
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_interpolate_vector3(
-    point_definition: *const Vector3PointDefinition,
-    time: f32,
-    context: *mut BaseProviderContext,
-) -> Vector3InterpolationResult {
-    let point_definition = unsafe { &*point_definition };
-    let (value, is_last) = point_definition.interpolate(time, unsafe { &*context });
-    Vector3InterpolationResult {
-        value: WrapVec3 {
-            x: value.x,
-            y: value.y,
-            z: value.z,
-        },
-        is_last,
-    }
+pub unsafe extern "C" fn tracks_make_track_registry() -> *mut TrackRegistry {
+    ffi_try(ptr::null_mut(), || {
+        let registry = Box::new(TrackRegistry::new());
+        Ok(Box::leak(registry))
+    })
 }
 
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_vector3_count(
-    point_definition: *const Vector3PointDefinition,
-) -> usize {
-    let point_definition = unsafe { &*point_definition };
-    point_definition.get_count()
+pub unsafe extern "C" fn tracks_free_track_registry(registry: *mut TrackRegistry) {
+    ffi_try((), || {
+        if !registry.is_null() {
+            drop(unsafe { Box::from_raw(registry) });
+        }
+        Ok(())
+    })
 }
 
+/// Returns the key of the named track, creating it if it does not exist yet.
+/// Returns `TrackKey_INVALID` on failure.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_vector3_has_base_provider(
-    point_definition: *const Vector3PointDefinition,
-) -> bool {
-    let point_definition = unsafe { &*point_definition };
-    point_definition.has_base_provider()
+pub unsafe extern "C" fn tracks_registry_add_track(
+    registry: *mut TrackRegistry,
+    name: *const c_char,
+) -> TrackKey {
+    ffi_try(TrackKey::INVALID, || {
+        let name = unsafe { c_str(name, "name")? };
+        let registry = unsafe { deref_mut(registry, "registry")? };
+        Ok(registry.add_track(name))
+    })
 }
 
-///VECTOR4 POINT DEFINITION
+/// Returns null if the key is not part of this registry.
+/// The pointer is invalidated when another track is added.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_make_vector4_point_definition(
-    json: *const FFIJsonValue,
-    context: *mut BaseProviderContext,
-) -> *const Vector4PointDefinition {
-    let value = unsafe { convert_json_value_to_serde(json) };
-    let point_definition = Box::new(Vector4PointDefinition::new(
-        value,
-        unsafe { &mut *context },
-    ));
-    let point_definition_ptr = Box::leak(point_definition);
-    point_definition_ptr
+pub unsafe extern "C" fn tracks_registry_get_track(
+    registry: *mut TrackRegistry,
+    key: TrackKey,
+) -> *mut Track {
+    ffi_try(ptr::null_mut(), || {
+        let registry = unsafe { deref_mut(registry, "registry")? };
+        Ok(registry
+            .get_track_mut(key)
+            .map_or(std::ptr::null_mut(), |track| track as *mut Track))
+    })
 }
 
+/// Returns null if no track has this name.
+/// The pointer is invalidated when another track is added.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_interpolate_vector4(
-    point_definition: *const Vector4PointDefinition,
-    time: f32,
-    context: *mut BaseProviderContext,
-) -> Vector4InterpolationResult {
-    let point_definition = unsafe { &*point_definition };
-    let (value, is_last) = point_definition.interpolate(time, unsafe { &*context });
-    Vector4InterpolationResult {
-        value: WrapVec4 {
-            x: value.x,
-            y: value.y,
-            z: value.z,
-            w: value.w,
-        },
-        is_last,
-    }
+pub unsafe extern "C" fn tracks_registry_get_track_by_name(
+    registry: *mut TrackRegistry,
+    name: *const c_char,
+) -> *mut Track {
+    ffi_try(ptr::null_mut(), || {
+        let name = unsafe { c_str(name, "name")? };
+        let registry = unsafe { deref_mut(registry, "registry")? };
+        Ok(registry
+            .get_track_by_name_mut(name)
+            .map_or(std::ptr::null_mut(), |track| track as *mut Track))
+    })
 }
 
+/// `property` is a `PropertyNames`
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_vector4_count(
-    point_definition: *const Vector4PointDefinition,
-) -> usize {
-    let point_definition = unsafe { &*point_definition };
-    point_definition.get_count()
+pub unsafe extern "C" fn tracks_track_set_property(
+    track: *mut Track,
+    property: u32,
+    value: WrapBaseValue,
+) -> bool {
+    ffi_try(false, || {
+        let track = unsafe { deref_mut(track, "track")? };
+        track.set_property(enum_arg(property)?, Some(value.try_into()?))?;
+        Ok(true)
+    })
 }
 
+/// `property` is a `PropertyNames`
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_vector4_has_base_provider(
-    point_definition: *const Vector4PointDefinition,
-) -> bool {
-    let point_definition = unsafe { &*point_definition };
-    point_definition.has_base_provider()
+pub unsafe extern "C" fn tracks_track_clear_property(track: *mut Track, property: u32) -> bool {
+    ffi_try(false, || {
+        let track = unsafe { deref_mut(track, "track")? };
+        track.set_property(enum_arg(property)?, None)?;
+        Ok(true)
+    })
 }
 
-///QUATERNION POINT DEFINITION
+/// Writes the `PropertyNames` property into `out` and returns true if it currently has a value
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_make_quat_point_definition(
-    json: *const FFIJsonValue,
-    context: *mut BaseProviderContext,
-) -> *const QuaternionPointDefinition {
-    let value = unsafe { convert_json_value_to_serde(json) };
-    let point_definition = Box::new(QuaternionPointDefinition::new(
-        value,
-        unsafe { &mut *context },
-    ));
-    let point_definition_ptr = Box::leak(point_definition);
-    point_definition_ptr
+pub unsafe extern "C" fn tracks_track_get_property(
+    track: *const Track,
+    property: u32,
+    out: *mut WrapBaseValue,
+) -> bool {
+    ffi_try(false, || {
+        let track = unsafe { deref(track, "track")? };
+        let out = unsafe { deref_mut(out, "out")? };
+        match track.get_property(enum_arg(property)?) {
+            Some(value) => {
+                *out = value.into();
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    })
 }
 
+/// Samples a path animation at an object's normalized lifetime `time`.
+/// Writes the value into `out` and returns true if the track has a path for this property.
+/// `property` is a `PathPropertyNames`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_interpolate_quat(
-    point_definition: *const QuaternionPointDefinition,
+pub unsafe extern "C" fn tracks_track_get_path_property(
+    track: *const Track,
+    property: u32,
     time: f32,
+    context: *const BaseProviderContext,
+    out: *mut WrapBaseValue,
+) -> bool {
+    ffi_try(false, || {
+        let track = unsafe { deref(track, "track")? };
+        let context = unsafe { deref(context, "context")? };
+        let out = unsafe { deref_mut(out, "out")? };
+        match track.get_path_property(enum_arg(property)?, time, context) {
+            Some(value) => {
+                *out = value.into();
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    })
+}
+
+/// Values interpolated per chunk before converting them to their FFI type
+const BATCH_CHUNK: usize = 64;
+
+/// Interpolates `count` times into `out`, converting through a stack buffer
+/// so batches never allocate
+unsafe fn interpolate_batch<T: TrackValue, W>(
+    point_definition: *const PointDefinition<T>,
+    times: *const f32,
+    out: *mut W,
+    count: usize,
     context: *mut BaseProviderContext,
-) -> QuaternionInterpolationResult {
-    let point_definition = unsafe { &*point_definition };
-    let (value, is_last) = point_definition.interpolate(time, unsafe { &*context });
-    QuaternionInterpolationResult {
-        value: WrapQuat {
-            x: value.x,
-            y: value.y,
-            z: value.z,
-            w: value.w,
-        },
-        is_last,
+    convert: impl Fn(T) -> W,
+) -> Result<bool, FfiError> {
+    let point_definition = unsafe { deref(point_definition, "point_definition")? };
+    let context = unsafe { deref(context, "context")? };
+    let times = unsafe { slice_arg(times, count, "times")? };
+    let out = unsafe { slice_arg_mut(out, count, "out")? };
+
+    let mut buffer = [T::default(); BATCH_CHUNK];
+    for (times, out) in times.chunks(BATCH_CHUNK).zip(out.chunks_mut(BATCH_CHUNK)) {
+        let buffer = &mut buffer[..times.len()];
+        point_definition.interpolate_many(times, buffer, context);
+        for (out, value) in out.iter_mut().zip(buffer.iter()) {
+            *out = convert(*value);
+        }
     }
+    Ok(true)
 }
 
+///FLOAT POINT DEFINITION
+///
+/// Returns null if the JSON is not a valid point definition
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_quat_count(
-    point_definition: *const QuaternionPointDefinition,
-) -> usize {
-    let point_definition = unsafe { &*point_definition };
-    point_definition.get_count()
+pub unsafe extern "C" fn tracks_make_float_point_definition(
+    json: *const FFIJsonValue,
+    context: *mut BaseProviderContext,
+) -> *const FloatPointDefinition {
+    ffi_try(ptr::null(), || {
+        let value = unsafe { convert_json_value_to_serde(json)? };
+        let context = unsafe { deref_mut(context, "context")? };
+        let point_definition = Arc::new(FloatPointDefinition::try_new(value, context)?);
+        Ok(Arc::into_raw(point_definition))
+    })
 }
 
+/// Parses `len` bytes of JSON text, returns null if it is not a valid point definition
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn tracks_quat_has_base_provider(
-    point_definition: *const QuaternionPointDefinition,
+pub unsafe extern "C" fn tracks_make_float_point_definition_from_str(
+    json: *const c_char,
+    len: usize,
+    context: *mut BaseProviderContext,
+) -> *const FloatPointDefinition {
+    ffi_try(ptr::null(), || {
+        let value = unsafe { parse_json_str(json, len)? };
+        let context = unsafe { deref_mut(context, "context")? };
+        let point_definition = Arc::new(FloatPointDefinition::try_new(value, context)?);
+        Ok(Arc::into_raw(point_definition))
+    })
+}
+
+/// Releases the handle, animations already using the definition keep it alive
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_free_float_point_definition(
+    point_definition: *const FloatPointDefinition,
+) {
+    ffi_try((), || {
+        if !point_definition.is_null() {
+            drop(unsafe { Arc::from_raw(point_definition) });
+        }
+        Ok(())
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_interpolate_float(
+    point_definition: *const FloatPointDefinition,
+    time: f32,
+    context: *mut BaseProviderContext,
+) -> FloatInterpolationResult {
+    let fallback = FloatInterpolationResult {
+        value: 0.0,
+        is_last: false,
+    };
+    ffi_try(fallback, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        let context = unsafe { deref(context, "context")? };
+        let (value, is_last) = point_definition.interpolate(time, context);
+        Ok(FloatInterpolationResult { value, is_last })
+    })
+}
+
+/// Interpolates `count` times into `out`, sorted times are fastest
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_interpolate_float_batch(
+    point_definition: *const FloatPointDefinition,
+    times: *const f32,
+    out: *mut f32,
+    count: usize,
+    context: *mut BaseProviderContext,
+) -> bool {
+    ffi_try(false, || unsafe {
+        interpolate_batch(point_definition, times, out, count, context, |v| v)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_float_count(
+    point_definition: *const FloatPointDefinition,
+) -> usize {
+    ffi_try(0, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        Ok(point_definition.get_count())
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_float_has_base_provider(
+    point_definition: *const FloatPointDefinition,
+) -> bool {
+    ffi_try(false, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        Ok(point_definition.has_base_provider())
+    })
+}
+
+///VECTOR3 POINT DEFINITION
+///
+/// Returns null if the JSON is not a valid point definition
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_make_vector3_point_definition(
+    json: *const FFIJsonValue,
+    context: *mut BaseProviderContext,
+) -> *const Vector3PointDefinition {
+    ffi_try(ptr::null(), || {
+        let value = unsafe { convert_json_value_to_serde(json)? };
+        let context = unsafe { deref_mut(context, "context")? };
+        let point_definition = Arc::new(Vector3PointDefinition::try_new(value, context)?);
+        Ok(Arc::into_raw(point_definition))
+    })
+}
+
+/// Parses `len` bytes of JSON text, returns null if it is not a valid point definition
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_make_vector3_point_definition_from_str(
+    json: *const c_char,
+    len: usize,
+    context: *mut BaseProviderContext,
+) -> *const Vector3PointDefinition {
+    ffi_try(ptr::null(), || {
+        let value = unsafe { parse_json_str(json, len)? };
+        let context = unsafe { deref_mut(context, "context")? };
+        let point_definition = Arc::new(Vector3PointDefinition::try_new(value, context)?);
+        Ok(Arc::into_raw(point_definition))
+    })
+}
+
+/// Releases the handle, animations already using the definition keep it alive
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_free_vector3_point_definition(
+    point_definition: *const Vector3PointDefinition,
+) {
+    ffi_try((), || {
+        if !point_definition.is_null() {
+            drop(unsafe { Arc::from_raw(point_definition) });
+        }
+        Ok(())
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_interpolate_vector3(
+    point_definition: *const Vector3PointDefinition,
+    time: f32,
+    context: *mut BaseProviderContext,
+) -> Vector3InterpolationResult {
+    let fallback = Vector3InterpolationResult {
+        value: Vec3::ZERO.into(),
+        is_last: false,
+    };
+    ffi_try(fallback, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        let context = unsafe { deref(context, "context")? };
+        let (value, is_last) = point_definition.interpolate(time, context);
+        Ok(Vector3InterpolationResult {
+            value: WrapVec3 {
+                x: value.x,
+                y: value.y,
+                z: value.z,
+            },
+            is_last,
+        })
+    })
+}
+
+/// Interpolates `count` times into `out`, sorted times are fastest
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_interpolate_vector3_batch(
+    point_definition: *const Vector3PointDefinition,
+    times: *const f32,
+    out: *mut WrapVec3,
+    count: usize,
+    context: *mut BaseProviderContext,
+) -> bool {
+    ffi_try(false, || unsafe {
+        interpolate_batch(point_definition, times, out, count, context, WrapVec3::from)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_vector3_count(
+    point_definition: *const Vector3PointDefinition,
+) -> usize {
+    ffi_try(0, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        Ok(point_definition.get_count())
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_vector3_has_base_provider(
+    point_definition: *const Vector3PointDefinition,
+) -> bool {
+    ffi_try(false, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        Ok(point_definition.has_base_provider())
+    })
+}
+
+///VECTOR4 POINT DEFINITION
+///
+/// Returns null if the JSON is not a valid point definition
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_make_vector4_point_definition(
+    json: *const FFIJsonValue,
+    context: *mut BaseProviderContext,
+) -> *const Vector4PointDefinition {
+    ffi_try(ptr::null(), || {
+        let value = unsafe { convert_json_value_to_serde(json)? };
+        let context = unsafe { deref_mut(context, "context")? };
+        let point_definition = Arc::new(Vector4PointDefinition::try_new(value, context)?);
+        Ok(Arc::into_raw(point_definition))
+    })
+}
+
+/// Parses `len` bytes of JSON text, returns null if it is not a valid point definition
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_make_vector4_point_definition_from_str(
+    json: *const c_char,
+    len: usize,
+    context: *mut BaseProviderContext,
+) -> *const Vector4PointDefinition {
+    ffi_try(ptr::null(), || {
+        let value = unsafe { parse_json_str(json, len)? };
+        let context = unsafe { deref_mut(context, "context")? };
+        let point_definition = Arc::new(Vector4PointDefinition::try_new(value, context)?);
+        Ok(Arc::into_raw(point_definition))
+    })
+}
+
+/// Releases the handle, animations already using the definition keep it alive
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_free_vector4_point_definition(
+    point_definition: *const Vector4PointDefinition,
+) {
+    ffi_try((), || {
+        if !point_definition.is_null() {
+            drop(unsafe { Arc::from_raw(point_definition) });
+        }
+        Ok(())
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_interpolate_vector4(
+    point_definition: *const Vector4PointDefinition,
+    time: f32,
+    context: *mut BaseProviderContext,
+) -> Vector4InterpolationResult {
+    let fallback = Vector4InterpolationResult {
+        value: Vec4::ZERO.into(),
+        is_last: false,
+    };
+    ffi_try(fallback, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        let context = unsafe { deref(context, "context")? };
+        let (value, is_last) = point_definition.interpolate(time, context);
+        Ok(Vector4InterpolationResult {
+            value: WrapVec4 {
+                x: value.x,
+                y: value.y,
+                z: value.z,
+                w: value.w,
+            },
+            is_last,
+        })
+    })
+}
+
+/// Interpolates `count` times into `out`, sorted times are fastest
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_interpolate_vector4_batch(
+    point_definition: *const Vector4PointDefinition,
+    times: *const f32,
+    out: *mut WrapVec4,
+    count: usize,
+    context: *mut BaseProviderContext,
+) -> bool {
+    ffi_try(false, || unsafe {
+        interpolate_batch(point_definition, times, out, count, context, WrapVec4::from)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_vector4_count(
+    point_definition: *const Vector4PointDefinition,
+) -> usize {
+    ffi_try(0, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        Ok(point_definition.get_count())
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_vector4_has_base_provider(
+    point_definition: *const Vector4PointDefinition,
+) -> bool {
+    ffi_try(false, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        Ok(point_definition.has_base_provider())
+    })
+}
+
+///QUATERNION POINT DEFINITION
+///
+/// Returns null if the JSON is not a valid point definition
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_make_quat_point_definition(
+    json: *const FFIJsonValue,
+    context: *mut BaseProviderContext,
+) -> *const QuaternionPointDefinition {
+    ffi_try(ptr::null(), || {
+        let value = unsafe { convert_json_value_to_serde(json)? };
+        let context = unsafe { deref_mut(context, "context")? };
+        let point_definition = Arc::new(QuaternionPointDefinition::try_new(value, context)?);
+        Ok(Arc::into_raw(point_definition))
+    })
+}
+
+/// Parses `len` bytes of JSON text, returns null if it is not a valid point definition
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_make_quat_point_definition_from_str(
+    json: *const c_char,
+    len: usize,
+    context: *mut BaseProviderContext,
+) -> *const QuaternionPointDefinition {
+    ffi_try(ptr::null(), || {
+        let value = unsafe { parse_json_str(json, len)? };
+        let context = unsafe { deref_mut(context, "context")? };
+        let point_definition = Arc::new(QuaternionPointDefinition::try_new(value, context)?);
+        Ok(Arc::into_raw(point_definition))
+    })
+}
+
+/// Releases the handle, animations already using the definition keep it alive
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_free_quat_point_definition(
+    point_definition: *const QuaternionPointDefinition,
+) {
+    ffi_try((), || {
+        if !point_definition.is_null() {
+            drop(unsafe { Arc::from_raw(point_definition) });
+        }
+        Ok(())
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_interpolate_quat(
+    point_definition: *const QuaternionPointDefinition,
+    time: f32,
+    context: *mut BaseProviderContext,
+) -> QuaternionInterpolationResult {
+    let fallback = QuaternionInterpolationResult {
+        value: Quat::IDENTITY.into(),
+        is_last: false,
+    };
+    ffi_try(fallback, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        let context = unsafe { deref(context, "context")? };
+        let (value, is_last) = point_definition.interpolate(time, context);
+        Ok(QuaternionInterpolationResult {
+            value: WrapQuat {
+                x: value.x,
+                y: value.y,
+                z: value.z,
+                w: value.w,
+            },
+            is_last,
+        })
+    })
+}
+
+/// Interpolates `count` times into `out`, sorted times are fastest
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_interpolate_quat_batch(
+    point_definition: *const QuaternionPointDefinition,
+    times: *const f32,
+    out: *mut WrapQuat,
+    count: usize,
+    context: *mut BaseProviderContext,
+) -> bool {
+    ffi_try(false, || unsafe {
+        interpolate_batch(point_definition, times, out, count, context, WrapQuat::from)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_quat_count(
+    point_definition: *const QuaternionPointDefinition,
+) -> usize {
+    ffi_try(0, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        Ok(point_definition.get_count())
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_quat_has_base_provider(
+    point_definition: *const QuaternionPointDefinition,
+) -> bool {
+    ffi_try(false, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        Ok(point_definition.has_base_provider())
+    })
+}
+
+///VECTOR2 POINT DEFINITION
+///
+/// Returns null if the JSON is not a valid point definition
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_make_vector2_point_definition(
+    json: *const FFIJsonValue,
+    context: *mut BaseProviderContext,
+) -> *const Vector2PointDefinition {
+    ffi_try(ptr::null(), || {
+        let value = unsafe { convert_json_value_to_serde(json)? };
+        let context = unsafe { deref_mut(context, "context")? };
+        let point_definition = Arc::new(Vector2PointDefinition::try_new(value, context)?);
+        Ok(Arc::into_raw(point_definition))
+    })
+}
+
+/// Parses `len` bytes of JSON text, returns null if it is not a valid point definition
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_make_vector2_point_definition_from_str(
+    json: *const c_char,
+    len: usize,
+    context: *mut BaseProviderContext,
+) -> *const Vector2PointDefinition {
+    ffi_try(ptr::null(), || {
+        let value = unsafe { parse_json_str(json, len)? };
+        let context = unsafe { deref_mut(context, "context")? };
+        let point_definition = Arc::new(Vector2PointDefinition::try_new(value, context)?);
+        Ok(Arc::into_raw(point_definition))
+    })
+}
+
+/// Releases the handle, animations already using the definition keep it alive
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_free_vector2_point_definition(
+    point_definition: *const Vector2PointDefinition,
+) {
+    ffi_try((), || {
+        if !point_definition.is_null() {
+            drop(unsafe { Arc::from_raw(point_definition) });
+        }
+        Ok(())
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_interpolate_vector2(
+    point_definition: *const Vector2PointDefinition,
+    time: f32,
+    context: *mut BaseProviderContext,
+) -> Vector2InterpolationResult {
+    let fallback = Vector2InterpolationResult {
+        value: Vec2::ZERO.into(),
+        is_last: false,
+    };
+    ffi_try(fallback, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        let context = unsafe { deref(context, "context")? };
+        let (value, is_last) = point_definition.interpolate(time, context);
+        Ok(Vector2InterpolationResult {
+            value: value.into(),
+            is_last,
+        })
+    })
+}
+
+/// Interpolates `count` times into `out`, sorted times are fastest
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_interpolate_vector2_batch(
+    point_definition: *const Vector2PointDefinition,
+    times: *const f32,
+    out: *mut WrapVec2,
+    count: usize,
+    context: *mut BaseProviderContext,
+) -> bool {
+    ffi_try(false, || unsafe {
+        interpolate_batch(point_definition, times, out, count, context, WrapVec2::from)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_vector2_count(
+    point_definition: *const Vector2PointDefinition,
+) -> usize {
+    ffi_try(0, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        Ok(point_definition.get_count())
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_vector2_has_base_provider(
+    point_definition: *const Vector2PointDefinition,
+) -> bool {
+    ffi_try(false, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        Ok(point_definition.has_base_provider())
+    })
+}
+
+///BOOL POINT DEFINITION
+///
+/// Values step from one point to the next instead of blending.
+/// Returns null if the JSON is not a valid point definition
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_make_bool_point_definition(
+    json: *const FFIJsonValue,
+    context: *mut BaseProviderContext,
+) -> *const BoolPointDefinition {
+    ffi_try(ptr::null(), || {
+        let value = unsafe { convert_json_value_to_serde(json)? };
+        let context = unsafe { deref_mut(context, "context")? };
+        let point_definition = Arc::new(BoolPointDefinition::try_new(value, context)?);
+        Ok(Arc::into_raw(point_definition))
+    })
+}
+
+/// Parses `len` bytes of JSON text, returns null if it is not a valid point definition
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_make_bool_point_definition_from_str(
+    json: *const c_char,
+    len: usize,
+    context: *mut BaseProviderContext,
+) -> *const BoolPointDefinition {
+    ffi_try(ptr::null(), || {
+        let value = unsafe { parse_json_str(json, len)? };
+        let context = unsafe { deref_mut(context, "context")? };
+        let point_definition = Arc::new(BoolPointDefinition::try_new(value, context)?);
+        Ok(Arc::into_raw(point_definition))
+    })
+}
+
+/// Releases the handle, animations already using the definition keep it alive
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_free_bool_point_definition(
+    point_definition: *const BoolPointDefinition,
+) {
+    ffi_try((), || {
+        if !point_definition.is_null() {
+            drop(unsafe { Arc::from_raw(point_definition) });
+        }
+        Ok(())
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_interpolate_bool(
+    point_definition: *const BoolPointDefinition,
+    time: f32,
+    context: *mut BaseProviderContext,
+) -> BoolInterpolationResult {
+    let fallback = BoolInterpolationResult {
+        value: false,
+        is_last: false,
+    };
+    ffi_try(fallback, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        let context = unsafe { deref(context, "context")? };
+        let (value, is_last) = point_definition.interpolate(time, context);
+        Ok(BoolInterpolationResult { value, is_last })
+    })
+}
+
+/// Interpolates `count` times into `out`, sorted times are fastest
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_interpolate_bool_batch(
+    point_definition: *const BoolPointDefinition,
+    times: *const f32,
+    out: *mut bool,
+    count: usize,
+    context: *mut BaseProviderContext,
+) -> bool {
+    ffi_try(false, || unsafe {
+        interpolate_batch(point_definition, times, out, count, context, |v| v)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_bool_count(point_definition: *const BoolPointDefinition) -> usize {
+    ffi_try(0, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        Ok(point_definition.get_count())
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_bool_has_base_provider(
+    point_definition: *const BoolPointDefinition,
+) -> bool {
+    ffi_try(false, || {
+        let point_definition = unsafe { deref(point_definition, "point_definition")? };
+        Ok(point_definition.has_base_provider())
+    })
+}
+
+/// POINT DEFINITION LIBRARY
+///
+/// Named point definitions of a map, and the inline ones animations used.
+/// Definitions returned by the library are new handles,
+/// release them with the matching `tracks_free_*_point_definition`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_make_point_definition_library() -> *mut PointDefinitionLibrary {
+    ffi_try(ptr::null_mut(), || {
+        let library = Box::new(PointDefinitionLibrary::new());
+        Ok(Box::leak(library))
+    })
+}
+
+/// Loads the named definitions of `len` bytes of v2 or v3 beatmap JSON.
+/// Malformed definitions are left out, returns null if the text is not JSON.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_make_point_definition_library_from_beatmap(
+    json: *const c_char,
+    len: usize,
+    context: *mut BaseProviderContext,
+) -> *mut PointDefinitionLibrary {
+    ffi_try(ptr::null_mut(), || {
+        let beatmap = unsafe { parse_json_str(json, len)? };
+        let context = unsafe { deref_mut(context, "context")? };
+        let library = Box::new(PointDefinitionLibrary::from_beatmap(&beatmap, context));
+        Ok(Box::leak(library))
+    })
+}
+
+/// Definitions handed out by the library stay valid until they are freed themselves
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_free_point_definition_library(
+    library: *mut PointDefinitionLibrary,
+) {
+    ffi_try((), || {
+        if !library.is_null() {
+            drop(unsafe { Box::from_raw(library) });
+        }
+        Ok(())
+    })
+}
+
+/// Amount of named definitions
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_library_count(library: *const PointDefinitionLibrary) -> usize {
+    ffi_try(0, || {
+        let library = unsafe { deref(library, "library")? };
+        Ok(library.len())
+    })
+}
+
+/// Writes the value type of the named definition into `out`, returns false if there is none
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_library_get_type(
+    library: *const PointDefinitionLibrary,
+    name: *const c_char,
+    out: *mut BaseValueType,
+) -> bool {
+    ffi_try(false, || {
+        let library = unsafe { deref(library, "library")? };
+        let name = unsafe { c_str(name, "name")? };
+        let out = unsafe { deref_mut(out, "out")? };
+        match library.get(name) {
+            Some(definition) => {
+                *out = definition.get_type();
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    })
+}
+
+/// New handle to the named definition of type `ty`
+unsafe fn library_get<D>(
+    library: *const PointDefinitionLibrary,
+    name: *const c_char,
+    ty: BaseValueType,
+    typed: impl Fn(&BasePointDefinition) -> Option<&Arc<D>>,
+) -> Result<*const D, FfiError> {
+    let library = unsafe { deref(library, "library")? };
+    let name = unsafe { c_str(name, "name")? };
+    let definition = library.try_get(name, ty)?;
+    Ok(typed(definition).map_or(ptr::null(), |d| Arc::into_raw(d.clone())))
+}
+
+/// New handle to the definition an animation value of type `ty` refers to, null for JSON null
+unsafe fn library_resolve<D>(
+    library: *mut PointDefinitionLibrary,
+    json: *const FFIJsonValue,
+    context: *mut BaseProviderContext,
+    ty: BaseValueType,
+    typed: impl Fn(&BasePointDefinition) -> Option<&Arc<D>>,
+) -> Result<*const D, FfiError> {
+    let library = unsafe { deref_mut(library, "library")? };
+    let value = unsafe { convert_json_value_to_serde(json)? };
+    let context = unsafe { deref_mut(context, "context")? };
+    let definition = library.try_resolve(&value, ty, context)?;
+    Ok(definition
+        .as_ref()
+        .and_then(typed)
+        .map_or(ptr::null(), |d| Arc::into_raw(d.clone())))
+}
+
+/// Returns null if there is no float definition with this name
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_library_get_float(
+    library: *const PointDefinitionLibrary,
+    name: *const c_char,
+) -> *const FloatPointDefinition {
+    ffi_try(ptr::null(), || unsafe {
+        library_get(
+            library,
+            name,
+            BaseValueType::Float,
+            BasePointDefinition::as_float,
+        )
+    })
+}
+
+/// Resolves an animation value, either the name of a definition or an inline one.
+/// Inline definitions are parsed once and shared by later calls with the same JSON.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_library_resolve_float(
+    library: *mut PointDefinitionLibrary,
+    json: *const FFIJsonValue,
+    context: *mut BaseProviderContext,
+) -> *const FloatPointDefinition {
+    ffi_try(ptr::null(), || unsafe {
+        library_resolve(
+            library,
+            json,
+            context,
+            BaseValueType::Float,
+            BasePointDefinition::as_float,
+        )
+    })
+}
+
+/// Returns null if there is no vector3 definition with this name
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_library_get_vector3(
+    library: *const PointDefinitionLibrary,
+    name: *const c_char,
+) -> *const Vector3PointDefinition {
+    ffi_try(ptr::null(), || unsafe {
+        library_get(
+            library,
+            name,
+            BaseValueType::Vector3,
+            BasePointDefinition::as_vector3,
+        )
+    })
+}
+
+/// Resolves an animation value, either the name of a definition or an inline one.
+/// Inline definitions are parsed once and shared by later calls with the same JSON.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_library_resolve_vector3(
+    library: *mut PointDefinitionLibrary,
+    json: *const FFIJsonValue,
+    context: *mut BaseProviderContext,
+) -> *const Vector3PointDefinition {
+    ffi_try(ptr::null(), || unsafe {
+        library_resolve(
+            library,
+            json,
+            context,
+            BaseValueType::Vector3,
+            BasePointDefinition::as_vector3,
+        )
+    })
+}
+
+/// Returns null if there is no vector4 definition with this name
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_library_get_vector4(
+    library: *const PointDefinitionLibrary,
+    name: *const c_char,
+) -> *const Vector4PointDefinition {
+    ffi_try(ptr::null(), || unsafe {
+        library_get(
+            library,
+            name,
+            BaseValueType::Vector4,
+            BasePointDefinition::as_vector4,
+        )
+    })
+}
+
+/// Resolves an animation value, either the name of a definition or an inline one.
+/// Inline definitions are parsed once and shared by later calls with the same JSON.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_library_resolve_vector4(
+    library: *mut PointDefinitionLibrary,
+    json: *const FFIJsonValue,
+    context: *mut BaseProviderContext,
+) -> *const Vector4PointDefinition {
+    ffi_try(ptr::null(), || unsafe {
+        library_resolve(
+            library,
+            json,
+            context,
+            BaseValueType::Vector4,
+            BasePointDefinition::as_vector4,
+        )
+    })
+}
+
+/// Returns null if there is no quat definition with this name
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_library_get_quat(
+    library: *const PointDefinitionLibrary,
+    name: *const c_char,
+) -> *const QuaternionPointDefinition {
+    ffi_try(ptr::null(), || unsafe {
+        library_get(
+            library,
+            name,
+            BaseValueType::Quaternion,
+            BasePointDefinition::as_quaternion,
+        )
+    })
+}
+
+/// Resolves an animation value, either the name of a definition or an inline one.
+/// Inline definitions are parsed once and shared by later calls with the same JSON.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_library_resolve_quat(
+    library: *mut PointDefinitionLibrary,
+    json: *const FFIJsonValue,
+    context: *mut BaseProviderContext,
+) -> *const QuaternionPointDefinition {
+    ffi_try(ptr::null(), || unsafe {
+        library_resolve(
+            library,
+            json,
+            context,
+            BaseValueType::Quaternion,
+            BasePointDefinition::as_quaternion,
+        )
+    })
+}
+
+/// Returns null if there is no vector2 definition with this name
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_library_get_vector2(
+    library: *const PointDefinitionLibrary,
+    name: *const c_char,
+) -> *const Vector2PointDefinition {
+    ffi_try(ptr::null(), || unsafe {
+        library_get(
+            library,
+            name,
+            BaseValueType::Vector2,
+            BasePointDefinition::as_vector2,
+        )
+    })
+}
+
+/// Resolves an animation value, either the name of a definition or an inline one.
+/// Inline definitions are parsed once and shared by later calls with the same JSON.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_library_resolve_vector2(
+    library: *mut PointDefinitionLibrary,
+    json: *const FFIJsonValue,
+    context: *mut BaseProviderContext,
+) -> *const Vector2PointDefinition {
+    ffi_try(ptr::null(), || unsafe {
+        library_resolve(
+            library,
+            json,
+            context,
+            BaseValueType::Vector2,
+            BasePointDefinition::as_vector2,
+        )
+    })
+}
+
+/// Returns null if there is no bool definition with this name
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_library_get_bool(
+    library: *const PointDefinitionLibrary,
+    name: *const c_char,
+) -> *const BoolPointDefinition {
+    ffi_try(ptr::null(), || unsafe {
+        library_get(
+            library,
+            name,
+            BaseValueType::Bool,
+            BasePointDefinition::as_bool,
+        )
+    })
+}
+
+/// Resolves an animation value, either the name of a definition or an inline one.
+/// Inline definitions are parsed once and shared by later calls with the same JSON.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_library_resolve_bool(
+    library: *mut PointDefinitionLibrary,
+    json: *const FFIJsonValue,
+    context: *mut BaseProviderContext,
+) -> *const BoolPointDefinition {
+    ffi_try(ptr::null(), || unsafe {
+        library_resolve(
+            library,
+            json,
+            context,
+            BaseValueType::Bool,
+            BasePointDefinition::as_bool,
+        )
+    })
+}
+
+/// EASINGS
+///
+/// Easings are passed by value as [`WrapEasing`], built by the constructors below.
+/// Writes the easing named like a point flag into `out`, e.g. `easeInQuad` or `steps(4)`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_easing_from_str(name: *const c_char, out: *mut WrapEasing) -> bool {
+    ffi_try(false, || {
+        let name = unsafe { c_str(name, "name")? };
+        let out = unsafe { deref_mut(out, "out")? };
+        *out = Functions::from_str(name)
+            .map_err(|_| FfiError::InvalidEasing(format!("\"{name}\"")))?
+            .into();
+        Ok(true)
+    })
+}
+
+/// Writes a cubic bezier easing into `out`, fails unless both x are within 0 and 1
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_easing_cubic_bezier(
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+    out: *mut WrapEasing,
+) -> bool {
+    ffi_try(false, || {
+        let out = unsafe { deref_mut(out, "out")? };
+        *out = Functions::cubic_bezier(x1, y1, x2, y2)
+            .ok_or_else(|| {
+                FfiError::InvalidEasing(format!("cubic-bezier({x1}, {y1}, {x2}, {y2})"))
+            })?
+            .into();
+        Ok(true)
+    })
+}
+
+/// Writes a staircase easing into `out`, fails for zero steps
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_easing_steps(steps: u32, out: *mut WrapEasing) -> bool {
+    ffi_try(false, || {
+        let out = unsafe { deref_mut(out, "out")? };
+        *out = Functions::steps(steps)
+            .ok_or_else(|| FfiError::InvalidEasing(format!("steps({steps})")))?
+            .into();
+        Ok(true)
+    })
+}
+
+/// Returns 0 and sets the last error for an invalid easing
+#[unsafe(no_mangle)]
+pub extern "C" fn tracks_easing_interpolate(easing: WrapEasing, time: f32) -> f32 {
+    ffi_try(0.0, || Ok(Functions::try_from(easing)?.interpolate(time)))
+}
+
+/// ANIMATE TRACK
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_make_coroutine_manager() -> *mut CoroutineManager {
+    ffi_try(ptr::null_mut(), || {
+        let manager = Box::new(CoroutineManager::new());
+        Ok(Box::leak(manager))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_free_coroutine_manager(manager: *mut CoroutineManager) {
+    ffi_try((), || {
+        if !manager.is_null() {
+            drop(unsafe { Box::from_raw(manager) });
+        }
+        Ok(())
+    })
+}
+
+/// Takes a new reference to a point definition created by `tracks_make_*_point_definition`
+unsafe fn clone_point_definition<T>(point_definition: *const T) -> Result<Arc<T>, FfiError> {
+    if point_definition.is_null() {
+        return Err(FfiError::NullPointer("point_definition"));
+    }
+    unsafe {
+        Arc::increment_strong_count(point_definition);
+        Ok(Arc::from_raw(point_definition))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe fn start_animate_track(
+    manager: *mut CoroutineManager,
+    track: TrackKey,
+    property: u32,
+    point_definition: Option<BasePointDefinition>,
+    start_time: f32,
+    duration: f32,
+    easing: WrapEasing,
+    repeat: u32,
+) -> Result<bool, FfiError> {
+    let property = enum_arg(property)?;
+    let easing = Functions::try_from(easing)?;
+    let manager = unsafe { deref_mut(manager, "manager")? };
+    manager.start_animate_track(
+        track,
+        property,
+        point_definition,
+        start_time,
+        duration,
+        easing,
+        repeat,
+    )?;
+    Ok(true)
+}
+
+unsafe fn start_assign_path_animation(
+    manager: *mut CoroutineManager,
+    track: TrackKey,
+    property: u32,
+    point_definition: Option<BasePointDefinition>,
+    start_time: f32,
+    duration: f32,
+    easing: WrapEasing,
+) -> Result<bool, FfiError> {
+    let property = enum_arg(property)?;
+    let easing = Functions::try_from(easing)?;
+    let manager = unsafe { deref_mut(manager, "manager")? };
+    manager.start_assign_path_animation(
+        track,
+        property,
+        point_definition,
+        start_time,
+        duration,
+        easing,
+    )?;
+    Ok(true)
+}
+
+/// `property` is a `PropertyNames`
+#[unsafe(no_mangle)]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn tracks_animate_track_float(
+    manager: *mut CoroutineManager,
+    track: TrackKey,
+    property: u32,
+    point_definition: *const FloatPointDefinition,
+    start_time: f32,
+    duration: f32,
+    easing: WrapEasing,
+    repeat: u32,
+) -> bool {
+    ffi_try(false, || {
+        let point_definition =
+            BasePointDefinition::Float(unsafe { clone_point_definition(point_definition)? });
+        unsafe {
+            start_animate_track(
+                manager,
+                track,
+                property,
+                Some(point_definition),
+                start_time,
+                duration,
+                easing,
+                repeat,
+            )
+        }
+    })
+}
+
+/// `property` is a `PropertyNames`
+#[unsafe(no_mangle)]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn tracks_animate_track_vector3(
+    manager: *mut CoroutineManager,
+    track: TrackKey,
+    property: u32,
+    point_definition: *const Vector3PointDefinition,
+    start_time: f32,
+    duration: f32,
+    easing: WrapEasing,
+    repeat: u32,
+) -> bool {
+    ffi_try(false, || {
+        let point_definition =
+            BasePointDefinition::Vector3(unsafe { clone_point_definition(point_definition)? });
+        unsafe {
+            start_animate_track(
+                manager,
+                track,
+                property,
+                Some(point_definition),
+                start_time,
+                duration,
+                easing,
+                repeat,
+            )
+        }
+    })
+}
+
+/// `property` is a `PropertyNames`
+#[unsafe(no_mangle)]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn tracks_animate_track_vector4(
+    manager: *mut CoroutineManager,
+    track: TrackKey,
+    property: u32,
+    point_definition: *const Vector4PointDefinition,
+    start_time: f32,
+    duration: f32,
+    easing: WrapEasing,
+    repeat: u32,
+) -> bool {
+    ffi_try(false, || {
+        let point_definition =
+            BasePointDefinition::Vector4(unsafe { clone_point_definition(point_definition)? });
+        unsafe {
+            start_animate_track(
+                manager,
+                track,
+                property,
+                Some(point_definition),
+                start_time,
+                duration,
+                easing,
+                repeat,
+            )
+        }
+    })
+}
+
+/// `property` is a `PropertyNames`
+#[unsafe(no_mangle)]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn tracks_animate_track_quat(
+    manager: *mut CoroutineManager,
+    track: TrackKey,
+    property: u32,
+    point_definition: *const QuaternionPointDefinition,
+    start_time: f32,
+    duration: f32,
+    easing: WrapEasing,
+    repeat: u32,
+) -> bool {
+    ffi_try(false, || {
+        let point_definition =
+            BasePointDefinition::Quaternion(unsafe { clone_point_definition(point_definition)? });
+        unsafe {
+            start_animate_track(
+                manager,
+                track,
+                property,
+                Some(point_definition),
+                start_time,
+                duration,
+                easing,
+                repeat,
+            )
+        }
+    })
+}
+
+/// Equivalent of an `AnimateTrack` event with a null point definition.
+/// `property` is a `PropertyNames`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_animate_track_clear(
+    manager: *mut CoroutineManager,
+    track: TrackKey,
+    property: u32,
+    start_time: f32,
+) -> bool {
+    ffi_try(false, || unsafe {
+        start_animate_track(
+            manager,
+            track,
+            property,
+            None,
+            start_time,
+            0.0,
+            Functions::EaseLinear.into(),
+            0,
+        )
+    })
+}
+
+/// ASSIGN PATH ANIMATION
+///
+/// `property` is a `PathPropertyNames`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_assign_path_animation_float(
+    manager: *mut CoroutineManager,
+    track: TrackKey,
+    property: u32,
+    point_definition: *const FloatPointDefinition,
+    start_time: f32,
+    duration: f32,
+    easing: WrapEasing,
+) -> bool {
+    ffi_try(false, || {
+        let point_definition =
+            BasePointDefinition::Float(unsafe { clone_point_definition(point_definition)? });
+        unsafe {
+            start_assign_path_animation(
+                manager,
+                track,
+                property,
+                Some(point_definition),
+                start_time,
+                duration,
+                easing,
+            )
+        }
+    })
+}
+
+/// `property` is a `PathPropertyNames`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_assign_path_animation_vector3(
+    manager: *mut CoroutineManager,
+    track: TrackKey,
+    property: u32,
+    point_definition: *const Vector3PointDefinition,
+    start_time: f32,
+    duration: f32,
+    easing: WrapEasing,
+) -> bool {
+    ffi_try(false, || {
+        let point_definition =
+            BasePointDefinition::Vector3(unsafe { clone_point_definition(point_definition)? });
+        unsafe {
+            start_assign_path_animation(
+                manager,
+                track,
+                property,
+                Some(point_definition),
+                start_time,
+                duration,
+                easing,
+            )
+        }
+    })
+}
+
+/// `property` is a `PathPropertyNames`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_assign_path_animation_vector4(
+    manager: *mut CoroutineManager,
+    track: TrackKey,
+    property: u32,
+    point_definition: *const Vector4PointDefinition,
+    start_time: f32,
+    duration: f32,
+    easing: WrapEasing,
+) -> bool {
+    ffi_try(false, || {
+        let point_definition =
+            BasePointDefinition::Vector4(unsafe { clone_point_definition(point_definition)? });
+        unsafe {
+            start_assign_path_animation(
+                manager,
+                track,
+                property,
+                Some(point_definition),
+                start_time,
+                duration,
+                easing,
+            )
+        }
+    })
+}
+
+/// `property` is a `PathPropertyNames`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_assign_path_animation_quat(
+    manager: *mut CoroutineManager,
+    track: TrackKey,
+    property: u32,
+    point_definition: *const QuaternionPointDefinition,
+    start_time: f32,
+    duration: f32,
+    easing: WrapEasing,
+) -> bool {
+    ffi_try(false, || {
+        let point_definition =
+            BasePointDefinition::Quaternion(unsafe { clone_point_definition(point_definition)? });
+        unsafe {
+            start_assign_path_animation(
+                manager,
+                track,
+                property,
+                Some(point_definition),
+                start_time,
+                duration,
+                easing,
+            )
+        }
+    })
+}
+
+/// Equivalent of an `AssignPathAnimation` event with a null point definition.
+/// `property` is a `PathPropertyNames`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_assign_path_animation_clear(
+    manager: *mut CoroutineManager,
+    track: TrackKey,
+    property: u32,
+    start_time: f32,
+    duration: f32,
+    easing: WrapEasing,
+) -> bool {
+    ffi_try(false, || unsafe {
+        start_assign_path_animation(manager, track, property, None, start_time, duration, easing)
+    })
+}
+
+/// Advance every running animation, call once per frame
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tracks_coroutine_manager_tick(
+    manager: *mut CoroutineManager,
+    song_time: f32,
+    registry: *mut TrackRegistry,
+    context: *const BaseProviderContext,
 ) -> bool {
-    let point_definition = unsafe { &*point_definition };
-    point_definition.has_base_provider()
+    ffi_try(false, || {
+        let manager = unsafe { deref_mut(manager, "manager")? };
+        let registry = unsafe { deref_mut(registry, "registry")? };
+        let context = unsafe { deref(context, "context")? };
+        manager.tick(song_time, registry, context);
+        Ok(true)
+    })
 }
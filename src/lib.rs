@@ -5,18 +5,21 @@
 #![feature(type_alias_impl_trait)]
 #![feature(trait_alias)]
 
-use std::{backtrace::Backtrace, panic::PanicHookInfo};
+use std::{any::Any, backtrace::Backtrace, panic::PanicHookInfo};
 
 use tracing::error;
 use tracing_error::SpanTrace;
 
+pub mod animation;
 pub mod easings;
+pub mod error;
 
 #[cfg(feature = "ffi")]
 pub mod ffi;
 pub mod modifiers;
 pub mod point_data;
 pub mod point_definition;
+pub mod tracks;
 pub mod values;
 
 #[cfg(target_os = "android")]
@@ -38,13 +41,7 @@ pub fn panic_hook(
     // Mostly taken from https://doc.rust-lang.org/src/std/panicking.rs.html
     Box::new(move |info| {
         let location = info.location().unwrap();
-        let msg = match info.payload().downcast_ref::<&'static str>() {
-            Some(s) => *s,
-            None => match info.payload().downcast_ref::<String>() {
-                Some(s) => &s[..],
-                None => "Box<dyn Any>",
-            },
-        };
+        let msg = panic_message(info.payload());
 
         error!(target: "panic", "panicked at '{}', {}", msg, location);
         if backtrace {
@@ -55,3 +52,14 @@ pub fn panic_hook(
         }
     })
 }
+
+/// The message a panic was raised with
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match payload.downcast_ref::<&'static str>() {
+        Some(s) => s,
+        None => match payload.downcast_ref::<String>() {
+            Some(s) => &s[..],
+            None => "Box<dyn Any>",
+        },
+    }
+}
@@ -0,0 +1,5 @@
+use super::{Modifier, ModifierValues};
+
+/// Booleans are modified as numbers
+pub type BoolValues = ModifierValues<f32>;
+pub type BoolModifier = Modifier<bool>;
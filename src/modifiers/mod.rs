@@ -1,18 +1,19 @@
+pub mod bool_modifier;
 pub mod float_modifier;
 pub mod operation;
 pub mod quaternion_modifier;
+pub mod vector2_modifier;
 pub mod vector3_modifier;
 pub mod vector4_modifier;
 
-use float_modifier::FloatModifier;
-use glam::{Quat, Vec3, Vec4};
-use quaternion_modifier::QuaternionModifier;
-use vector3_modifier::Vector3Modifier;
-use vector4_modifier::Vector4Modifier;
-
 use crate::modifiers::operation::Operation;
+use crate::point_definition::TrackValue;
 use crate::values::base_provider_context::BaseProviderContext;
 use crate::values::{AbstractValueProvider, ValueProvider};
+#[cfg(feature = "json")]
+use crate::values::{json_number, serialize_values};
+#[cfg(feature = "json")]
+use serde_json::Value as JsonValue;
 
 #[derive(Clone, Debug)]
 pub enum ModifierValues<T> {
@@ -20,62 +21,122 @@ pub enum ModifierValues<T> {
     Dynamic(Vec<ValueProvider>),
 }
 
-pub enum Modifier {
-    Float(FloatModifier),
-    Vector3(Vector3Modifier),
-    Vector4(Vector4Modifier),
-    Quaternion(QuaternionModifier),
+/// A value and the modifiers applied to it, combined by each modifier's operation
+pub struct Modifier<T: TrackValue> {
+    values: ModifierValues<T::Raw>,
+    has_base_provider: bool,
+    modifiers: Vec<Modifier<T>>,
+    operation: Operation,
+    /// The final value when it does not depend on the context
+    resolved: Option<T>,
 }
 
-impl Modifier {
-    pub fn get_float(&self, context: &BaseProviderContext) -> f32 {
-        if let Modifier::Float(modifier) = self {
-            modifier.get_point(context)
-        } else {
-            panic!("Invalid modifier type");
+impl<T: TrackValue> Modifier<T> {
+    pub fn new(
+        point: ModifierValues<T::Raw>,
+        modifiers: Vec<Modifier<T>>,
+        operation: Operation,
+    ) -> Self {
+        let has_base_provider =
+            shared_has_base_provider(matches!(point, ModifierValues::Dynamic(_)), &modifiers);
+        let resolved = match point {
+            ModifierValues::Static(raw) if modifiers.is_empty() => Some(T::from_raw(raw)),
+            _ => None,
+        };
+        Self {
+            values: point,
+            has_base_provider,
+            modifiers,
+            operation,
+            resolved,
         }
     }
 
-    pub fn get_vector3(&self, context: &BaseProviderContext) -> Vec3 {
-        if let Modifier::Vector3(modifier) = self {
-            modifier.get_point(context)
-        } else {
-            panic!("Invalid modifier type");
+    pub fn get_point(&self, context: &BaseProviderContext) -> T {
+        match self.resolved {
+            Some(point) => point,
+            None => T::from_raw(self.get_raw_point(context)),
         }
     }
 
-    pub fn get_vector4(&self, context: &BaseProviderContext) -> Vec4 {
-        if let Modifier::Vector4(modifier) = self {
-            modifier.get_point(context)
-        } else {
-            panic!("Invalid modifier type");
-        }
-    }
-
-    pub fn get_quaternion(&self, context: &BaseProviderContext) -> Quat {
-        if let Modifier::Quaternion(modifier) = self {
-            modifier.get_point(context)
-        } else {
-            panic!("Invalid modifier type");
-        }
+    /// The modified value before it is converted, see [`TrackValue::Raw`]
+    pub fn get_raw_point(&self, context: &BaseProviderContext) -> T::Raw {
+        let original_point = match &self.values {
+            ModifierValues::Static(s) => *s,
+            ModifierValues::Dynamic(value_providers) => Self::convert(value_providers, context),
+        };
+        self.modifiers.iter().fold(original_point, |acc, x| {
+            x.get_operation().apply(acc, x.get_raw_point(context))
+        })
     }
 
     pub fn get_operation(&self) -> Operation {
-        match self {
-            Modifier::Float(modifier) => modifier.get_operation(),
-            Modifier::Vector3(modifier) => modifier.get_operation(),
-            Modifier::Vector4(modifier) => modifier.get_operation(),
-            Modifier::Quaternion(modifier) => modifier.get_operation(),
-        }
+        self.operation
     }
 
     pub fn has_base_provider(&self) -> bool {
-        match self {
-            Modifier::Float(modifier) => modifier.has_base_provider(),
-            Modifier::Vector3(modifier) => modifier.has_base_provider(),
-            Modifier::Vector4(modifier) => modifier.has_base_provider(),
-            Modifier::Quaternion(modifier) => modifier.has_base_provider(),
+        self.has_base_provider
+    }
+
+    /// The modifier as JSON, `[values..., modifiers..., operation]`
+    #[cfg(feature = "json")]
+    pub fn to_json(&self, context: &BaseProviderContext) -> JsonValue {
+        JsonValue::Array(self.json_values(context, None))
+    }
+
+    /// Elements of the modifier's JSON array, `time` follows static values
+    /// since dynamic ones already hold the time they were parsed with
+    #[cfg(feature = "json")]
+    pub(crate) fn json_values(
+        &self,
+        context: &BaseProviderContext,
+        time: Option<f32>,
+    ) -> Vec<JsonValue> {
+        let mut json = match &self.values {
+            ModifierValues::Static(raw) => {
+                let mut values = [0.0; 4];
+                let values = &mut values[..T::VALUE_COUNT];
+                T::raw_to_slice(*raw, values);
+                values
+                    .iter()
+                    .copied()
+                    .chain(time)
+                    .map(json_number)
+                    .collect()
+            }
+            ModifierValues::Dynamic(values) => serialize_values(values, context),
+        };
+        json.extend(self.modifiers.iter().map(|m| m.to_json(context)));
+        json.extend(self.operation.name().map(JsonValue::from));
+        json
+    }
+
+    /// Copies the provided values into `out` until it is full,
+    /// returning how many were written
+    fn fill_values(
+        ivals: &[ValueProvider],
+        context: &BaseProviderContext,
+        out: &mut [f32],
+    ) -> usize {
+        let mut count = 0;
+        for value in ivals {
+            for v in value.values(context).iter().copied() {
+                if count == out.len() {
+                    return count;
+                }
+                out[count] = v;
+                count += 1;
+            }
         }
+        count
+    }
+
+    fn convert(ivals: &[ValueProvider], context: &BaseProviderContext) -> T::Raw {
+        // no value has more than 4 components, keep them on the stack
+        let mut values = [0.0; 4];
+        let values = &mut values[..T::VALUE_COUNT];
+        Self::fill_values(ivals, context, values);
+        T::raw_from_slice(values)
     }
 }
 
@@ -95,37 +156,10 @@ impl<T> ModifierValues<T> {
     }
 }
 
-pub trait ModifierBase {
-    type Value;
-    const VALUE_COUNT: usize;
-
-    fn get_point(&self, context: &BaseProviderContext) -> Self::Value;
-    fn get_raw_point(&self) -> Self::Value;
-    fn translate(&self, values: &[f32]) -> Self::Value;
-    fn get_operation(&self) -> Operation;
-    fn has_base_provider(&self) -> bool;
-
-    fn fill_values(&self, ivals: &[ValueProvider], context: &BaseProviderContext) -> Vec<f32> {
-        let mut values = Vec::with_capacity(Self::VALUE_COUNT);
-        for value in ivals {
-            for v in value.values(context).iter().copied() {
-                if values.len() < Self::VALUE_COUNT {
-                    values.push(v);
-                } else {
-                    return values;
-                }
-            }
-        }
-        values
-    }
-
-    fn convert(&self, ivals: &[ValueProvider], context: &BaseProviderContext) -> Self::Value {
-        let values = self.fill_values(ivals, context);
-        self.translate(&values)
-    }
-}
-
-pub fn shared_has_base_provider(is_dynamic: bool, modifiers: &Vec<Modifier>) -> bool {
+pub fn shared_has_base_provider<T: TrackValue>(
+    is_dynamic: bool,
+    modifiers: &[Modifier<T>],
+) -> bool {
     match is_dynamic {
         true => true,
         false => modifiers.iter().any(|m| m.has_base_provider()),
@@ -1,3 +1,5 @@
+use std::ops::{Add, Div, Mul, Sub};
+
 #[derive(Clone, Copy, PartialEq)]
 pub enum Operation {
     None = 0,
@@ -7,6 +9,35 @@ pub enum Operation {
     Div,
 }
 
+impl Operation {
+    /// Combines a modifier's `value` into `acc`, `None` replaces it
+    pub fn apply<T>(self, acc: T, value: T) -> T
+    where
+        T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        match self {
+            Operation::Add => acc + value,
+            Operation::Sub => acc - value,
+            Operation::Mul => acc * value,
+            Operation::Div => acc / value,
+            Operation::None => value,
+        }
+    }
+}
+
+impl Operation {
+    /// The JSON flag of the operation, `None` has none
+    pub fn name(self) -> Option<&'static str> {
+        match self {
+            Operation::Add => Some("opAdd"),
+            Operation::Sub => Some("opSub"),
+            Operation::Mul => Some("opMul"),
+            Operation::Div => Some("opDiv"),
+            Operation::None => None,
+        }
+    }
+}
+
 impl std::str::FromStr for Operation {
     type Err = ();
 
@@ -16,7 +47,7 @@ impl std::str::FromStr for Operation {
             "opSub" => Ok(Self::Sub),
             "opMul" => Ok(Self::Mul),
             "opDiv" => Ok(Self::Div),
-            _ => Ok(Self::None),
+            _ => Err(()),
         }
     }
 }
@@ -0,0 +1,5 @@
+use super::{Modifier, ModifierValues};
+use glam::Vec2;
+
+pub type Vector2Values = ModifierValues<Vec2>;
+pub type Vector2Modifier = Modifier<Vec2>;
@@ -0,0 +1,3 @@
+use super::PointData;
+
+pub type BoolPointData = PointData<bool>;
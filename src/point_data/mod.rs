@@ -1,85 +1,68 @@
+pub mod bool_point_data;
 pub mod float_point_data;
 pub mod quaternion_point_data;
+pub mod vector2_point_data;
 pub mod vector3_point_data;
 pub mod vector4_point_data;
 
-use float_point_data::FloatPointData;
-use glam::{Quat, Vec3, Vec4};
-use quaternion_point_data::QuaternionPointData;
-use vector3_point_data::Vector3PointData;
-use vector4_point_data::Vector4PointData;
-
 use crate::easings::functions::Functions;
+use crate::modifiers::{Modifier, ModifierValues, operation::Operation};
+use crate::point_definition::TrackValue;
 use crate::values::base_provider_context::BaseProviderContext;
+#[cfg(feature = "json")]
+use serde_json::Value as JsonValue;
 
-pub enum PointData {
-    Float(FloatPointData),
-    Vector3(Vector3PointData),
-    Vector4(Vector4PointData),
-    Quaternion(QuaternionPointData),
+/// A point of a definition, its value at `time` and how to get there
+pub struct PointData<T: TrackValue> {
+    base_modifier: Modifier<T>,
+    easing: Functions,
+    /// Whether the point has its type's [`TrackValue::SMOOTH_FLAG`]
+    pub smooth: bool,
+    time: f32,
 }
 
-impl PointData {
-    pub fn get_easing(&self) -> Functions {
-        match self {
-            PointData::Float(point_data) => point_data.get_easing(),
-            PointData::Vector3(point_data) => point_data.get_easing(),
-            PointData::Vector4(point_data) => point_data.get_easing(),
-            PointData::Quaternion(point_data) => point_data.get_easing(),
+impl<T: TrackValue> PointData<T> {
+    pub fn new(
+        point: ModifierValues<T::Raw>,
+        smooth: bool,
+        time: f32,
+        modifiers: Vec<Modifier<T>>,
+        easing: Functions,
+    ) -> Self {
+        Self {
+            base_modifier: Modifier::new(point, modifiers, Operation::None),
+            easing,
+            smooth,
+            time,
         }
     }
 
-    pub fn get_time(&self) -> f32 {
-        match self {
-            PointData::Float(point_data) => point_data.get_time(),
-            PointData::Vector3(point_data) => point_data.get_time(),
-            PointData::Vector4(point_data) => point_data.get_time(),
-            PointData::Quaternion(point_data) => point_data.get_time(),
-        }
+    pub fn get_easing(&self) -> Functions {
+        self.easing.clone()
     }
 
-    pub fn has_base_provider(&self) -> bool {
-        match self {
-            PointData::Float(point_data) => point_data.has_base_provider(),
-            PointData::Vector3(point_data) => point_data.has_base_provider(),
-            PointData::Vector4(point_data) => point_data.has_base_provider(),
-            PointData::Quaternion(point_data) => point_data.has_base_provider(),
-        }
+    pub fn get_time(&self) -> f32 {
+        self.time
     }
 
-    pub fn get_float(&self, context: &BaseProviderContext) -> f32 {
-        match self {
-            PointData::Float(point_data) => point_data.get_point(context),
-            _ => panic!("PointData is not a FloatPointData"),
-        }
+    pub fn has_base_provider(&self) -> bool {
+        self.base_modifier.has_base_provider()
     }
 
-    pub fn get_vector3(&self, context: &BaseProviderContext) -> Vec3 {
-        match self {
-            PointData::Vector3(point_data) => point_data.get_point(context),
-            _ => panic!("PointData is not a Vector3PointData"),
-        }
+    pub fn get_point(&self, context: &BaseProviderContext) -> T {
+        self.base_modifier.get_point(context)
     }
 
-    pub fn get_vector4(&self, context: &BaseProviderContext) -> Vec4 {
-        match self {
-            PointData::Vector4(point_data) => point_data.get_point(context),
-            _ => panic!("PointData is not a Vector4PointData"),
+    /// The point as JSON, `[values..., time, modifiers..., flags...]`
+    #[cfg(feature = "json")]
+    pub fn to_json(&self, context: &BaseProviderContext) -> JsonValue {
+        let mut json = self.base_modifier.json_values(context, Some(self.time));
+        if !matches!(self.easing, Functions::EaseLinear) {
+            json.push(self.easing.name().into());
         }
-    }
-
-    pub fn get_quaternion(&self, context: &BaseProviderContext) -> Quat {
-        match self {
-            PointData::Quaternion(point_data) => point_data.get_point(context),
-            _ => panic!("PointData is not a QuaternionPointData"),
+        if let Some(flag) = T::SMOOTH_FLAG.filter(|_| self.smooth) {
+            json.push(flag.into());
         }
+        JsonValue::Array(json)
     }
 }
-
-// Generic trait for point data
-pub trait BasePointData<T> {
-    fn get_easing(&self) -> Functions;
-    fn has_base_provider(&self) -> bool;
-    fn get_point(&self, context: &BaseProviderContext) -> T;
-    fn get_time(&self) -> f32;
-}
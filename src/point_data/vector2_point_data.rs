@@ -0,0 +1,5 @@
+use glam::Vec2;
+
+use super::PointData;
+
+pub type Vector2PointData = PointData<Vec2>;
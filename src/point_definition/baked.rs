@@ -0,0 +1,94 @@
+use crate::{
+    easings::functions::Functions, point_data::PointData,
+    values::base_provider_context::BaseProviderContext,
+};
+
+use super::{TrackValue, interpolate_segment};
+
+/// Points of a definition without base providers, flattened once parsing is done.
+///
+/// Their values only depend on time, so interpolating them is a search over
+/// `times`, one easing and one mix of two values, no modifiers involved.
+#[derive(Clone, Debug)]
+pub struct BakedPoints<T> {
+    pub(crate) times: Vec<f32>,
+    pub(crate) values: Vec<T>,
+    pub(crate) easings: Vec<Functions>,
+    /// The point's [`TrackValue::SMOOTH_FLAG`]
+    pub(crate) flags: Vec<bool>,
+}
+
+impl<T: TrackValue> BakedPoints<T> {
+    /// `None` if there is nothing to bake or a point depends on base providers
+    pub(crate) fn bake(points: &[PointData<T>], context: &BaseProviderContext) -> Option<Self> {
+        if points.is_empty() || points.iter().any(|p| p.has_base_provider()) {
+            return None;
+        }
+
+        Some(Self {
+            times: points.iter().map(|p| p.get_time()).collect(),
+            values: points.iter().map(|p| p.get_point(context)).collect(),
+            easings: points.iter().map(|p| p.get_easing()).collect(),
+            flags: points.iter().map(|p| p.smooth).collect(),
+        })
+    }
+
+    /// Same search as [`PointDefinition::search_index_from`](super::PointDefinition::search_index_from)
+    /// over the baked times
+    fn search_index_from(&self, time: f32, hint: usize) -> (usize, usize) {
+        let times = &self.times;
+        let mut l = if hint < times.len() && times[hint] < time {
+            hint
+        } else {
+            0
+        };
+        let mut r = times.len();
+
+        if l + 1 < r && times[l + 1] >= time {
+            return (l, l + 1);
+        }
+
+        while l < r - 1 {
+            let m = (l + r) / 2;
+            if times[m] < time {
+                l = m;
+            } else {
+                r = m;
+            }
+        }
+
+        (l, r)
+    }
+
+    /// Returns a tuple (interpolated value, is_last_point)
+    pub(crate) fn interpolate(&self, time: f32, hint: &mut usize) -> (T, bool) {
+        let last = self.times.len() - 1;
+        if self.times[last] <= time {
+            return (self.values[last], true);
+        }
+        if self.times[0] >= time {
+            return (self.values[0], false);
+        }
+
+        let (l, r) = self.search_index_from(time, *hint);
+        *hint = l;
+
+        let (time_l, time_r) = (self.times[l], self.times[r]);
+        let normal_time = if time_r - time_l != 0.0 {
+            (time - time_l) / (time_r - time_l)
+        } else {
+            0.0
+        };
+
+        let eased_time = self.easings[r].interpolate(normal_time);
+        let value = interpolate_segment(
+            self.values.len(),
+            l,
+            r,
+            eased_time,
+            |i| self.values[i],
+            |i| self.flags[i],
+        );
+        (value, false)
+    }
+}
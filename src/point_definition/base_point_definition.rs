@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use crate::values::{
+    base_provider_context::BaseProviderContext,
+    value::{BaseValue, BaseValueType},
+};
+
+use super::{
+    bool_point_definition::BoolPointDefinition, float_point_definition::FloatPointDefinition,
+    quaternion_point_definition::QuaternionPointDefinition,
+    vector2_point_definition::Vector2PointDefinition,
+    vector3_point_definition::Vector3PointDefinition,
+    vector4_point_definition::Vector4PointDefinition,
+};
+
+/// A shared point definition of any value type
+#[derive(Clone)]
+pub enum BasePointDefinition {
+    Float(Arc<FloatPointDefinition>),
+    Vector3(Arc<Vector3PointDefinition>),
+    Vector4(Arc<Vector4PointDefinition>),
+    Quaternion(Arc<QuaternionPointDefinition>),
+    Vector2(Arc<Vector2PointDefinition>),
+    Bool(Arc<BoolPointDefinition>),
+}
+
+impl BasePointDefinition {
+    pub fn get_type(&self) -> BaseValueType {
+        match self {
+            BasePointDefinition::Float(_) => BaseValueType::Float,
+            BasePointDefinition::Vector3(_) => BaseValueType::Vector3,
+            BasePointDefinition::Vector4(_) => BaseValueType::Vector4,
+            BasePointDefinition::Quaternion(_) => BaseValueType::Quaternion,
+            BasePointDefinition::Vector2(_) => BaseValueType::Vector2,
+            BasePointDefinition::Bool(_) => BaseValueType::Bool,
+        }
+    }
+
+    pub fn get_count(&self) -> usize {
+        match self {
+            BasePointDefinition::Float(d) => d.get_count(),
+            BasePointDefinition::Vector3(d) => d.get_count(),
+            BasePointDefinition::Vector4(d) => d.get_count(),
+            BasePointDefinition::Quaternion(d) => d.get_count(),
+            BasePointDefinition::Vector2(d) => d.get_count(),
+            BasePointDefinition::Bool(d) => d.get_count(),
+        }
+    }
+
+    pub fn has_base_provider(&self) -> bool {
+        match self {
+            BasePointDefinition::Float(d) => d.has_base_provider(),
+            BasePointDefinition::Vector3(d) => d.has_base_provider(),
+            BasePointDefinition::Vector4(d) => d.has_base_provider(),
+            BasePointDefinition::Quaternion(d) => d.has_base_provider(),
+            BasePointDefinition::Vector2(d) => d.has_base_provider(),
+            BasePointDefinition::Bool(d) => d.has_base_provider(),
+        }
+    }
+
+    pub fn as_float(&self) -> Option<&Arc<FloatPointDefinition>> {
+        match self {
+            BasePointDefinition::Float(d) => Some(d),
+            _ => None,
+        }
+    }
+
+    pub fn as_vector3(&self) -> Option<&Arc<Vector3PointDefinition>> {
+        match self {
+            BasePointDefinition::Vector3(d) => Some(d),
+            _ => None,
+        }
+    }
+
+    pub fn as_vector4(&self) -> Option<&Arc<Vector4PointDefinition>> {
+        match self {
+            BasePointDefinition::Vector4(d) => Some(d),
+            _ => None,
+        }
+    }
+
+    pub fn as_quaternion(&self) -> Option<&Arc<QuaternionPointDefinition>> {
+        match self {
+            BasePointDefinition::Quaternion(d) => Some(d),
+            _ => None,
+        }
+    }
+
+    pub fn as_vector2(&self) -> Option<&Arc<Vector2PointDefinition>> {
+        match self {
+            BasePointDefinition::Vector2(d) => Some(d),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<&Arc<BoolPointDefinition>> {
+        match self {
+            BasePointDefinition::Bool(d) => Some(d),
+            _ => None,
+        }
+    }
+
+    /// Returns a tuple (interpolated value, is_last_point)
+    pub fn interpolate(&self, time: f32, context: &BaseProviderContext) -> (BaseValue, bool) {
+        match self {
+            BasePointDefinition::Float(d) => {
+                let (value, is_last) = d.interpolate(time, context);
+                (value.into(), is_last)
+            }
+            BasePointDefinition::Vector3(d) => {
+                let (value, is_last) = d.interpolate(time, context);
+                (value.into(), is_last)
+            }
+            BasePointDefinition::Vector4(d) => {
+                let (value, is_last) = d.interpolate(time, context);
+                (value.into(), is_last)
+            }
+            BasePointDefinition::Quaternion(d) => {
+                let (value, is_last) = d.interpolate(time, context);
+                (value.into(), is_last)
+            }
+            BasePointDefinition::Vector2(d) => {
+                let (value, is_last) = d.interpolate(time, context);
+                (value.into(), is_last)
+            }
+            BasePointDefinition::Bool(d) => {
+                let (value, is_last) = d.interpolate(time, context);
+                (value.into(), is_last)
+            }
+        }
+    }
+
+    /// See [`PointDefinition::to_json`](super::PointDefinition::to_json)
+    #[cfg(feature = "json")]
+    pub fn to_json(&self, context: &BaseProviderContext) -> serde_json::Value {
+        match self {
+            BasePointDefinition::Float(d) => d.to_json(context),
+            BasePointDefinition::Vector3(d) => d.to_json(context),
+            BasePointDefinition::Vector4(d) => d.to_json(context),
+            BasePointDefinition::Quaternion(d) => d.to_json(context),
+            BasePointDefinition::Vector2(d) => d.to_json(context),
+            BasePointDefinition::Bool(d) => d.to_json(context),
+        }
+    }
+}
+
+impl From<FloatPointDefinition> for BasePointDefinition {
+    fn from(value: FloatPointDefinition) -> Self {
+        BasePointDefinition::Float(Arc::new(value))
+    }
+}
+
+impl From<Vector3PointDefinition> for BasePointDefinition {
+    fn from(value: Vector3PointDefinition) -> Self {
+        BasePointDefinition::Vector3(Arc::new(value))
+    }
+}
+
+impl From<Vector4PointDefinition> for BasePointDefinition {
+    fn from(value: Vector4PointDefinition) -> Self {
+        BasePointDefinition::Vector4(Arc::new(value))
+    }
+}
+
+impl From<QuaternionPointDefinition> for BasePointDefinition {
+    fn from(value: QuaternionPointDefinition) -> Self {
+        BasePointDefinition::Quaternion(Arc::new(value))
+    }
+}
+
+impl From<Vector2PointDefinition> for BasePointDefinition {
+    fn from(value: Vector2PointDefinition) -> Self {
+        BasePointDefinition::Vector2(Arc::new(value))
+    }
+}
+
+impl From<BoolPointDefinition> for BasePointDefinition {
+    fn from(value: BoolPointDefinition) -> Self {
+        BasePointDefinition::Bool(Arc::new(value))
+    }
+}
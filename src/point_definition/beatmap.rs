@@ -0,0 +1,349 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use serde_json::Value as JsonValue;
+use tracing::error;
+
+use crate::{
+    error::{JsonPath, ParseError},
+    tracks::{path_property::PathPropertyNames, property::PropertyNames},
+    values::{base_provider_context::BaseProviderContext, value::BaseValueType},
+};
+
+use super::{
+    base_point_definition::BasePointDefinition, bool_point_definition::BoolPointDefinition,
+    float_point_definition::FloatPointDefinition,
+    quaternion_point_definition::QuaternionPointDefinition,
+    vector2_point_definition::Vector2PointDefinition,
+    vector3_point_definition::Vector3PointDefinition,
+    vector4_point_definition::Vector4PointDefinition,
+};
+
+/// Layout of a beatmap's custom data
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum BeatmapVersion {
+    /// `_customData._pointDefinitions` as an array of `{_name, _points}`
+    V2,
+    /// `customData.pointDefinitions` as an object keyed by name
+    V3,
+}
+
+impl BeatmapVersion {
+    /// v2 beatmaps are the ones with a `_version`
+    pub fn detect(beatmap: &JsonValue) -> Self {
+        match beatmap.get("_version") {
+            Some(_) => BeatmapVersion::V2,
+            None => BeatmapVersion::V3,
+        }
+    }
+
+    /// `name` as a key of this version, v2 keys start with `_`
+    pub fn key(self, name: &str) -> String {
+        match self {
+            BeatmapVersion::V2 => format!("_{name}"),
+            BeatmapVersion::V3 => name.to_owned(),
+        }
+    }
+
+    /// `key` without the `_` of v2 keys, the inverse of [`key`](Self::key)
+    pub fn strip_key(self, key: &str) -> &str {
+        match self {
+            BeatmapVersion::V2 => key.strip_prefix('_').unwrap_or(key),
+            BeatmapVersion::V3 => key,
+        }
+    }
+
+    /// The data of every `AnimateTrack` and `AssignPathAnimation` event,
+    /// then every object animation, each with its path
+    pub fn animations(self, beatmap: &JsonValue) -> Vec<(JsonPath, &JsonValue)> {
+        let mut animations = vec![];
+        let custom_data_key = self.key("customData");
+        let events_key = self.key("customEvents");
+        let events_path = JsonPath::root().key(&custom_data_key).key(&events_key);
+
+        let events = beatmap
+            .get(&custom_data_key)
+            .and_then(|c| c.get(&events_key))
+            .and_then(JsonValue::as_array);
+        for (i, event) in events.into_iter().flatten().enumerate() {
+            let ty = event.get(self.event_type_key()).and_then(JsonValue::as_str);
+            if matches!(ty, Some("AnimateTrack" | "AssignPathAnimation"))
+                && let Some(data) = event.get(self.event_data_key())
+            {
+                animations.push((events_path.index(i).key(self.event_data_key()), data));
+            }
+        }
+
+        let animation_key = self.key("animation");
+        for array_name in self.object_arrays() {
+            let objects = beatmap.get(array_name).and_then(JsonValue::as_array);
+            for (i, object) in objects.into_iter().flatten().enumerate() {
+                if let Some(animation) = object
+                    .get(&custom_data_key)
+                    .and_then(|c| c.get(&animation_key))
+                {
+                    let path = JsonPath::root()
+                        .key(array_name)
+                        .index(i)
+                        .key(&custom_data_key)
+                        .key(&animation_key);
+                    animations.push((path, animation));
+                }
+            }
+        }
+
+        animations
+    }
+
+    /// The named point definitions of `beatmap` in beatmap order, unparsed.
+    /// Malformed v2 entries and definitions of the wrong JSON type are errors.
+    pub fn point_definitions(
+        self,
+        beatmap: &JsonValue,
+    ) -> Vec<Result<RawPointDefinition<'_>, ParseError>> {
+        let custom_data_key = self.key("customData");
+        let definitions_key = self.key("pointDefinitions");
+        let definitions_path = JsonPath::root().key(&custom_data_key).key(&definitions_key);
+
+        let definitions = beatmap
+            .get(&custom_data_key)
+            .and_then(|c| c.get(&definitions_key));
+        match (self, definitions) {
+            (_, None) => vec![],
+            (BeatmapVersion::V3, Some(JsonValue::Object(definitions))) => definitions
+                .iter()
+                .map(|(name, points)| {
+                    Ok(RawPointDefinition {
+                        name,
+                        points,
+                        path: definitions_path.key(name),
+                    })
+                })
+                .collect(),
+            (BeatmapVersion::V2, Some(JsonValue::Array(definitions))) => definitions
+                .iter()
+                .enumerate()
+                .map(|(i, definition)| {
+                    let path = definitions_path.index(i);
+                    let name = definition.get("_name").and_then(JsonValue::as_str);
+                    match (name, definition.get("_points")) {
+                        (Some(name), Some(points)) => Ok(RawPointDefinition {
+                            name,
+                            points,
+                            path: path.key("_points"),
+                        }),
+                        _ => Err(ParseError::UnexpectedType {
+                            path,
+                            expected: "an object with _name and _points",
+                        }),
+                    }
+                })
+                .collect(),
+            (_, Some(_)) => {
+                let expected = match self {
+                    BeatmapVersion::V2 => "an array of point definitions",
+                    BeatmapVersion::V3 => "an object of point definitions",
+                };
+                vec![Err(ParseError::UnexpectedType {
+                    path: definitions_path,
+                    expected,
+                })]
+            }
+        }
+    }
+
+    /// Arrays of beatmap objects that can carry an animation
+    fn object_arrays(self) -> &'static [&'static str] {
+        match self {
+            BeatmapVersion::V2 => &["_notes", "_obstacles"],
+            BeatmapVersion::V3 => &[
+                "colorNotes",
+                "bombNotes",
+                "obstacles",
+                "sliders",
+                "burstSliders",
+            ],
+        }
+    }
+
+    fn event_type_key(self) -> &'static str {
+        match self {
+            BeatmapVersion::V2 => "_type",
+            BeatmapVersion::V3 => "t",
+        }
+    }
+
+    fn event_data_key(self) -> &'static str {
+        match self {
+            BeatmapVersion::V2 => "_data",
+            BeatmapVersion::V3 => "d",
+        }
+    }
+}
+
+/// A named point definition as written in a beatmap
+#[derive(Clone, Debug)]
+pub struct RawPointDefinition<'a> {
+    pub name: &'a str,
+    pub points: &'a JsonValue,
+    /// Location of `points` in the beatmap
+    pub path: JsonPath,
+}
+
+/// Value type of an animated property, for both track and path animations
+pub fn property_type(name: &str) -> Option<BaseValueType> {
+    PropertyNames::from_str(name)
+        .map(|p| p.get_type())
+        .or_else(|_| PathPropertyNames::from_str(name).map(|p| p.get_type()))
+        .ok()
+}
+
+/// Guess the value type of a point definition from the amount of numbers in its first point,
+/// points holding JSON booleans are [`BaseValueType::Bool`].
+///
+/// Rotations and 3D vectors have the same amount, this picks [`BaseValueType::Vector3`]
+pub fn infer_value_type(
+    points: &JsonValue,
+    context: &BaseProviderContext,
+) -> Option<BaseValueType> {
+    let array = points.as_array()?;
+    let (point, time) = match array.first()? {
+        JsonValue::Array(point) => (point.as_slice(), 0),
+        // a single point without its time
+        _ => (array.as_slice(), 1),
+    };
+
+    if point.iter().any(JsonValue::is_boolean) {
+        return Some(BaseValueType::Bool);
+    }
+
+    match value_count(point, context)? + time {
+        2 => Some(BaseValueType::Float),
+        3 => Some(BaseValueType::Vector2),
+        4 => Some(BaseValueType::Vector3),
+        5 => Some(BaseValueType::Vector4),
+        _ => None,
+    }
+}
+
+/// Numbers in a point, counting the ones its base providers yield
+fn value_count(point: &[JsonValue], context: &BaseProviderContext) -> Option<usize> {
+    let mut count = 0;
+    for v in point {
+        match v {
+            JsonValue::Number(_) => count += 1,
+            JsonValue::String(s) if s.starts_with("base") => {
+                count += context.value_count(s).ok()?
+            }
+            _ => {}
+        }
+    }
+    Some(count)
+}
+
+/// The named point definitions of a v2 or v3 beatmap.
+///
+/// Definitions take the value type of the properties animated with them,
+/// unused ones are typed by [`infer_value_type`].
+/// Malformed definitions are logged and left out.
+pub fn load_point_definitions(
+    beatmap: &JsonValue,
+    context: &mut BaseProviderContext,
+) -> HashMap<String, BasePointDefinition> {
+    parse_definitions(beatmap, context)
+        .into_iter()
+        .filter_map(|(name, definition)| match definition {
+            Ok(definition) => Some((name, definition)),
+            Err(e) => {
+                error!("Failed to load point definition \"{}\": {}", name, e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// [`load_point_definitions`], failing on the first malformed definition
+pub fn try_load_point_definitions(
+    beatmap: &JsonValue,
+    context: &mut BaseProviderContext,
+) -> Result<HashMap<String, BasePointDefinition>, ParseError> {
+    parse_definitions(beatmap, context)
+        .into_iter()
+        .map(|(name, definition)| Ok((name, definition?)))
+        .collect()
+}
+
+/// Every named definition of `beatmap` parsed as its value type, in beatmap order
+fn parse_definitions(
+    beatmap: &JsonValue,
+    context: &mut BaseProviderContext,
+) -> Vec<(String, Result<BasePointDefinition, ParseError>)> {
+    let version = BeatmapVersion::detect(beatmap);
+    let types = used_types(beatmap, version);
+
+    version
+        .point_definitions(beatmap)
+        .into_iter()
+        .map(|definition| {
+            let RawPointDefinition { name, points, path } = match definition {
+                Ok(definition) => definition,
+                Err(e) => return (String::new(), Err(e)),
+            };
+            let ty = types
+                .get(name)
+                .copied()
+                .or_else(|| infer_value_type(points, context));
+            let definition = match ty {
+                Some(ty) => parse_as(ty, points, context).map_err(|e| e.rebase(&path)),
+                None => Err(ParseError::UnknownValueType {
+                    path,
+                    name: name.to_owned(),
+                }),
+            };
+            (name.to_owned(), definition)
+        })
+        .collect()
+}
+
+/// Parses `points` as a definition of `ty`
+pub(crate) fn parse_as(
+    ty: BaseValueType,
+    points: &JsonValue,
+    context: &mut BaseProviderContext,
+) -> Result<BasePointDefinition, ParseError> {
+    let points = points.clone();
+    Ok(match ty {
+        BaseValueType::Float => FloatPointDefinition::try_new(points, context)?.into(),
+        BaseValueType::Vector3 => Vector3PointDefinition::try_new(points, context)?.into(),
+        BaseValueType::Vector4 => Vector4PointDefinition::try_new(points, context)?.into(),
+        BaseValueType::Quaternion => QuaternionPointDefinition::try_new(points, context)?.into(),
+        BaseValueType::Vector2 => Vector2PointDefinition::try_new(points, context)?.into(),
+        BaseValueType::Bool => BoolPointDefinition::try_new(points, context)?.into(),
+    })
+}
+
+/// Value type of each definition name animated by an event or object, the first use wins
+fn used_types(beatmap: &JsonValue, version: BeatmapVersion) -> HashMap<String, BaseValueType> {
+    let mut types = HashMap::new();
+    for (_, animation) in version.animations(beatmap) {
+        add_used_types(animation, version, &mut types);
+    }
+    types
+}
+
+fn add_used_types(
+    animation: &JsonValue,
+    version: BeatmapVersion,
+    types: &mut HashMap<String, BaseValueType>,
+) {
+    let Some(animation) = animation.as_object() else {
+        return;
+    };
+
+    for (key, value) in animation {
+        let property = version.strip_key(key);
+        if let (JsonValue::String(name), Some(ty)) = (value, property_type(property)) {
+            types.entry(name.clone()).or_insert(ty);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use super::{PointDefinition, TrackValue};
+
+pub type BoolPointDefinition = PointDefinition<bool>;
+
+/// Booleans are written as `true`/`false` or numbers and modified as numbers,
+/// anything from 0.5 up is true
+impl TrackValue for bool {
+    type Raw = f32;
+    /// Always zero, steps have no rate of change between them
+    type Velocity = f32;
+    const VALUE_COUNT: usize = 1;
+
+    fn raw_from_slice(values: &[f32]) -> f32 {
+        values[0]
+    }
+
+    fn raw_to_slice(raw: f32, out: &mut [f32]) {
+        out[0] = raw;
+    }
+
+    fn from_raw(raw: f32) -> bool {
+        raw >= 0.5
+    }
+
+    /// Steps, the value only changes once the next point is reached
+    fn lerp(a: bool, b: bool, time: f32) -> bool {
+        if time < 1.0 { a } else { b }
+    }
+
+    fn lerp_velocity(_a: bool, _b: bool, _time: f32) -> f32 {
+        0.0
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use serde_json::Value as JsonValue;
+use tracing::error;
+
+use crate::{
+    error::{JsonPath, ParseError},
+    values::{base_provider_context::BaseProviderContext, value::BaseValueType},
+};
+
+use super::{
+    base_point_definition::BasePointDefinition,
+    beatmap::{load_point_definitions, parse_as, try_load_point_definitions},
+};
+
+/// Every point definition of a map, named ones and the inline ones animations used.
+///
+/// Animations give a definition either as the name of one in the map's
+/// point definitions or as an inline array, [`resolve`](Self::resolve) takes both.
+#[derive(Clone, Default)]
+pub struct PointDefinitionLibrary {
+    named: HashMap<String, BasePointDefinition>,
+    /// Inline definitions by value type and JSON text, parsed once
+    inline: HashMap<(BaseValueType, String), BasePointDefinition>,
+}
+
+impl PointDefinitionLibrary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The named definitions of a v2 or v3 beatmap, see [`load_point_definitions`]
+    pub fn from_beatmap(beatmap: &JsonValue, context: &mut BaseProviderContext) -> Self {
+        Self {
+            named: load_point_definitions(beatmap, context),
+            inline: HashMap::new(),
+        }
+    }
+
+    pub fn try_from_beatmap(
+        beatmap: &JsonValue,
+        context: &mut BaseProviderContext,
+    ) -> Result<Self, ParseError> {
+        Ok(Self {
+            named: try_load_point_definitions(beatmap, context)?,
+            inline: HashMap::new(),
+        })
+    }
+
+    /// Adds or replaces a named definition, returning the one it replaced
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        definition: impl Into<BasePointDefinition>,
+    ) -> Option<BasePointDefinition> {
+        self.named.insert(name.into(), definition.into())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&BasePointDefinition> {
+        self.named.get(name)
+    }
+
+    /// The named definition, failing if there is none or it holds other values than `ty`
+    pub fn try_get(
+        &self,
+        name: &str,
+        ty: BaseValueType,
+    ) -> Result<&BasePointDefinition, ParseError> {
+        let definition =
+            self.named
+                .get(name)
+                .ok_or_else(|| ParseError::UnknownPointDefinition {
+                    path: JsonPath::root(),
+                    name: name.to_owned(),
+                })?;
+
+        if definition.get_type() != ty {
+            return Err(ParseError::ValueTypeMismatch {
+                path: JsonPath::root(),
+                name: name.to_owned(),
+                expected: ty,
+                found: definition.get_type(),
+            });
+        }
+        Ok(definition)
+    }
+
+    /// Names of the named definitions, in no particular order
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.named.keys().map(String::as_str)
+    }
+
+    /// Amount of named definitions
+    pub fn len(&self) -> usize {
+        self.named.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.named.is_empty()
+    }
+
+    /// The definition an animation `value` of type `ty` refers to.
+    ///
+    /// Errors are logged and result in `None`, like `null` which clears the property.
+    pub fn resolve(
+        &mut self,
+        value: &JsonValue,
+        ty: BaseValueType,
+        context: &mut BaseProviderContext,
+    ) -> Option<BasePointDefinition> {
+        self.try_resolve(value, ty, context).unwrap_or_else(|e| {
+            error!("Failed to resolve point definition: {}", e);
+            None
+        })
+    }
+
+    /// [`resolve`](Self::resolve) reporting why `value` did not resolve,
+    /// `Ok(None)` is only returned for `null`
+    pub fn try_resolve(
+        &mut self,
+        value: &JsonValue,
+        ty: BaseValueType,
+        context: &mut BaseProviderContext,
+    ) -> Result<Option<BasePointDefinition>, ParseError> {
+        match value {
+            JsonValue::Null => Ok(None),
+            JsonValue::String(name) => self.try_get(name, ty).cloned().map(Some),
+            _ => {
+                let key = (ty, value.to_string());
+                if let Some(definition) = self.inline.get(&key) {
+                    return Ok(Some(definition.clone()));
+                }
+
+                let definition = parse_as(ty, value, context)?;
+                self.inline.insert(key, definition.clone());
+                Ok(Some(definition))
+            }
+        }
+    }
+}
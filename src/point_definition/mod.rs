@@ -1,21 +1,36 @@
+pub mod baked;
+pub mod base_point_definition;
+#[cfg(feature = "json")]
+pub mod beatmap;
+pub mod bool_point_definition;
 pub mod float_point_definition;
+#[cfg(feature = "json")]
+pub mod library;
 pub mod quaternion_point_definition;
+pub mod track_value;
+pub mod vector2_point_definition;
 pub mod vector3_point_definition;
 pub mod vector4_point_definition;
 
+pub use track_value::TrackValue;
+
 use std::str::FromStr;
 
 use serde_json::Value as JsonValue;
 use serde_json::json;
 
+use tracing::error;
+
+use crate::error::{JsonPath, ParseError};
 use crate::point_data::PointData;
+use crate::point_definition::baked::BakedPoints;
 use crate::{
     easings::functions::Functions,
-    modifiers::{Modifier, operation::Operation},
+    modifiers::{Modifier, ModifierValues, operation::Operation},
     values::{
-        ValueProvider,
+        AbstractValueProvider, ValueProvider,
         base_provider_context::{BaseProviderContext},
-        deserialize_values,
+        try_deserialize_values,
     },
 };
 
@@ -26,47 +41,151 @@ pub enum GroupType {
     Modifier,
 }
 
-// The combined PointDefinition trait (acting as both BasePointDefinition and the templated PointDefinition<T>)
-pub trait PointDefinition {
-    type Value: Default + Clone;
+/// Points of type `T` interpolated over time, everything specific to `T` lives in its [`TrackValue`] impl
+pub struct PointDefinition<T: TrackValue> {
+    points: Vec<PointData<T>>,
+    baked: Option<BakedPoints<T>>,
+}
+
+impl<T: TrackValue> Default for PointDefinition<T> {
+    fn default() -> Self {
+        Self {
+            points: Vec::new(),
+            baked: None,
+        }
+    }
+}
+
+impl<T: TrackValue> PointDefinition<T> {
+    /// Amount of numbers in a value, a point adds one more for its time
+    pub const VALUE_COUNT: usize = T::VALUE_COUNT;
+
+    /// Malformed JSON is logged and results in an empty definition.
+    #[cfg(feature = "json")]
+    pub fn new(value: JsonValue, context: &mut BaseProviderContext) -> Self {
+        Self::try_new(value, context).unwrap_or_else(|e| {
+            error!("Failed to parse point definition: {}", e);
+            Self::default()
+        })
+    }
+
+    #[cfg(feature = "json")]
+    pub fn try_new(
+        value: JsonValue,
+        context: &mut BaseProviderContext,
+    ) -> Result<Self, ParseError> {
+        let mut instance = Self::default();
+        instance.try_parse(value, context)?;
+        Ok(instance)
+    }
+
+    pub fn get_count(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn has_base_provider(&self) -> bool {
+        self.points.iter().any(|p| p.has_base_provider())
+    }
+
+    pub fn get_points(&self) -> &Vec<PointData<T>> {
+        &self.points
+    }
+
+    pub fn get_points_mut(&mut self) -> &mut Vec<PointData<T>> {
+        // the points may change, bake again once they are done
+        self.baked = None;
+        &mut self.points
+    }
+
+    /// The definition as JSON that parses back to the same points,
+    /// base providers are named as registered in `context`
+    #[cfg(feature = "json")]
+    pub fn to_json(&self, context: &BaseProviderContext) -> JsonValue {
+        JsonValue::Array(self.points.iter().map(|p| p.to_json(context)).collect())
+    }
+
+    /// Bakes the points once parsing is done, see [`BakedPoints`]
+    pub fn bake(&mut self, context: &BaseProviderContext) {
+        self.baked = BakedPoints::bake(&self.points, context);
+    }
+
+    /// The baked points, `None` if the definition depends on base providers
+    pub fn get_baked(&self) -> Option<&BakedPoints<T>> {
+        self.baked.as_ref()
+    }
 
-    // Required methods common to all definitions
-    fn get_count(&self) -> usize;
-    fn has_base_provider(&self) -> bool;
-    fn interpolate_points(
-        &self,
-        points: &[PointData],
-        l: usize,
-        r: usize,
-        time: f32,
-        context: &BaseProviderContext,
-    ) -> Self::Value;
     fn create_modifier(
-        &self,
         values: Vec<ValueProvider>,
-        modifiers: Vec<Modifier>,
+        modifiers: Vec<Modifier<T>>,
         operation: Operation,
-        context: &BaseProviderContext,
-    ) -> Modifier;
+    ) -> Modifier<T> {
+        let values = match values.as_slice() {
+            [ValueProvider::Static(static_val)] if static_val.values.len() == T::VALUE_COUNT => {
+                ModifierValues::Static(T::raw_from_slice(&static_val.values))
+            }
+            _ => ModifierValues::Dynamic(values),
+        };
+
+        Modifier::new(values, modifiers, operation)
+    }
+
     fn create_point_data(
-        &self,
         values: Vec<ValueProvider>,
         flags: Vec<String>,
-        modifiers: Vec<Modifier>,
+        modifiers: Vec<Modifier<T>>,
         easing: Functions,
         context: &BaseProviderContext,
-    ) -> PointData;
-    fn get_points_mut(&mut self) -> &mut Vec<PointData>;
-    fn get_points(&self) -> &Vec<PointData>;
-    fn get_point(&self, point: &PointData, context: &BaseProviderContext) -> Self::Value;
+    ) -> PointData<T> {
+        let (values, time) = match values.as_slice() {
+            [ValueProvider::Static(static_val)]
+                if static_val.values.len() == T::VALUE_COUNT + 1 =>
+            {
+                let values = &static_val.values;
+                (
+                    ModifierValues::Static(T::raw_from_slice(&values[..T::VALUE_COUNT])),
+                    values[T::VALUE_COUNT],
+                )
+            }
+            _ => {
+                let values_len: usize = values.iter().map(|v| v.values(context).len()).sum();
+
+                let time = if values_len == T::VALUE_COUNT + 1 {
+                    values
+                        .last()
+                        .and_then(|v| v.values(context).last().copied())
+                        .unwrap_or(0.0)
+                } else {
+                    0.0
+                };
+
+                (ModifierValues::Dynamic(values), time)
+            }
+        };
+
+        let smooth = T::SMOOTH_FLAG.is_some_and(|smooth| flags.iter().any(|f| f == smooth));
+        PointData::new(values, smooth, time, modifiers, easing)
+    }
 
+    /// Errors are logged and result in `None`
     #[cfg(feature = "json")]
-    fn deserialize_modifier(
+    pub fn deserialize_modifier(
         &self,
         list: &JsonValue,
         context: &mut BaseProviderContext,
-    ) -> Modifier {
-        let mut modifiers: Option<Vec<Modifier>> = None;
+    ) -> Option<Modifier<T>> {
+        self.try_deserialize_modifier(list, &JsonPath::root(), context)
+            .map_err(|e| error!("Failed to deserialize modifier: {}", e))
+            .ok()
+    }
+
+    #[cfg(feature = "json")]
+    pub fn try_deserialize_modifier(
+        &self,
+        list: &JsonValue,
+        path: &JsonPath,
+        context: &mut BaseProviderContext,
+    ) -> Result<Modifier<T>, ParseError> {
+        let mut modifiers: Option<Vec<Modifier<T>>> = None;
         let mut operation: Option<Operation> = None;
         let mut values: Option<Vec<ValueProvider>> = None;
 
@@ -74,61 +193,97 @@ pub trait PointDefinition {
         for group in Self::group_values(list) {
             match group.0 {
                 GroupType::Value => {
-                    values = Some(deserialize_values(&group.1, context));
+                    let vals = try_deserialize_values(&group.1, path, context)?;
+                    check_value_count(&vals, T::VALUE_COUNT, path, context)?;
+                    values = Some(vals);
                 }
                 GroupType::Modifier => {
                     modifiers = Some(
                         group
                             .1
                             .iter()
-                            .map(|m| self.deserialize_modifier(m, context))
-                            .collect(),
+                            .map(|(i, m)| self.try_deserialize_modifier(m, &path.index(*i), context))
+                            .collect::<Result<_, _>>()?,
                     );
                 }
                 GroupType::Flag => {
-                    operation = Some(Operation::from_str(group.1[0].as_str().unwrap()).unwrap());
+                    for (i, flag) in &group.1 {
+                        let flag = flag.as_str().unwrap_or_default();
+                        let op = Operation::from_str(flag).map_err(|_| {
+                            ParseError::UnknownOperation {
+                                path: path.index(*i),
+                                operation: flag.to_owned(),
+                            }
+                        })?;
+                        operation.get_or_insert(op);
+                    }
                 }
             }
         }
 
-        // Validate required fields
-        //let values = values.expect("No points found.");
-        //let operation = operation.expect("No operation found.");
+        let values = values.ok_or_else(|| ParseError::MissingValues { path: path.clone() })?;
+        let operation =
+            operation.ok_or_else(|| ParseError::MissingOperation { path: path.clone() })?;
 
         // Create modifier with collected values
-        self.create_modifier(
-            values.unwrap(),
+        Ok(Self::create_modifier(
+            values,
             modifiers.unwrap_or_default(),
-            operation.unwrap(),
-            context,
-        )
+            operation,
+        ))
+    }
+
+    #[cfg(feature = "json")]
+    pub fn parse(&mut self, value: JsonValue, context: &mut BaseProviderContext) {
+        if let Err(e) = self.try_parse(value, context) {
+            error!("Failed to parse point definition: {}", e);
+        }
     }
 
-    // Shared parse implementation
     #[cfg(feature = "json")]
-    fn parse(
+    pub fn try_parse(
         &mut self,
         value: JsonValue,
         context: &mut BaseProviderContext,
-    ) {
-        let root: JsonValue = match value.as_array().unwrap()[0] {
-            JsonValue::Array(_) => value,
-            _ => {
-                let mut cloned = value.as_array().unwrap().clone();
+    ) -> Result<(), ParseError> {
+        let root_path = JsonPath::root();
+        let Some(array) = value.as_array() else {
+            return Err(ParseError::UnexpectedType {
+                path: root_path,
+                expected: "an array of points",
+            });
+        };
+
+        // A single point can be written without the outer array and its time
+        let single_point: JsonValue;
+        let raw_points: Vec<(JsonPath, &JsonValue)> = match array.first() {
+            None => return Ok(()),
+            Some(JsonValue::Array(_)) => array
+                .iter()
+                .enumerate()
+                .map(|(i, raw_point)| (root_path.index(i), raw_point))
+                .collect(),
+            Some(_) => {
+                let mut cloned = array.clone();
                 cloned.push(json!(0));
-                json!([cloned])
+                single_point = JsonValue::Array(cloned);
+                vec![(root_path, &single_point)]
             }
         };
 
-        let Some(array) = root.as_array() else { return };
-
-        for raw_point in array {
+        for (path, raw_point) in raw_points {
             if raw_point.is_null() {
                 continue;
             }
+            if !raw_point.is_array() {
+                return Err(ParseError::UnexpectedType {
+                    path,
+                    expected: "a point array",
+                });
+            }
 
-            let mut easing = Functions::EaseLinear;
-            let mut modifiers: Option<Vec<Modifier>> = None;
+            let mut easing: Option<Functions> = None;
+            let mut modifiers: Option<Vec<Modifier<T>>> = None;
             let mut flags: Option<Vec<String>> = None;
             let mut vals: Option<Vec<ValueProvider>> = None;
 
@@ -136,15 +291,19 @@ pub trait PointDefinition {
             for group in Self::group_values(raw_point) {
                 match group.0 {
                     GroupType::Value => {
-                        vals = Some(deserialize_values(&group.1, context));
+                        let values = try_deserialize_values(&group.1, &path, context)?;
+                        check_value_count(&values, T::VALUE_COUNT + 1, &path, context)?;
+                        vals = Some(values);
                     }
                     GroupType::Modifier => {
                         modifiers = Some(
                             group
                                 .1
                                 .iter()
-                                .map(|m| self.deserialize_modifier(m, context))
-                                .collect(),
+                                .map(|(i, m)| {
+                                    self.try_deserialize_modifier(m, &path.index(*i), context)
+                                })
+                                .collect::<Result<_, _>>()?,
                         );
                     }
                     GroupType::Flag => {
@@ -152,43 +311,68 @@ pub trait PointDefinition {
                         let flags_vec: Vec<String> = group
                             .1
                             .iter()
-                            .filter_map(|v| v.as_str().map(String::from))
+                            .filter_map(|(_, v)| v.as_str().map(String::from))
                             .collect();
 
                         // Set the flags collected from the group.
                         flags = Some(flags_vec);
 
-                        // Find the first flag starting with "ease" just like in the C# code.
-                        if let Some(ref flags_inner) = flags
-                            && let Some(easing_string) =
-                                flags_inner.iter().find(|flag| flag.starts_with("ease"))
-                        {
-                            easing =
-                                Functions::from_str(easing_string).unwrap_or(Functions::EaseLinear);
+                        // Use the first easing flag just like in the C# code,
+                        // but reject any that do not parse.
+                        for (i, flag) in &group.1 {
+                            let Some(flag) = flag.as_str().filter(|f| Functions::is_easing_flag(f))
+                            else {
+                                continue;
+                            };
+                            let parsed = Functions::from_str(flag).map_err(|_| {
+                                ParseError::InvalidEasing {
+                                    path: path.index(*i),
+                                    easing: flag.to_owned(),
+                                }
+                            })?;
+                            easing.get_or_insert(parsed);
                         }
                     }
                 }
             }
 
-            // Create point data only if we have values
-            let Some(vs) = vals else { continue };
+            let vs = vals.ok_or_else(|| ParseError::MissingValues { path: path.clone() })?;
 
-            let point_data = self.create_point_data(
+            let point_data = Self::create_point_data(
                 vs,
                 flags.unwrap_or_default(),
                 modifiers.unwrap_or_default(),
-                easing,
+                easing.unwrap_or(Functions::EaseLinear),
                 context,
             );
             self.get_points_mut().push(point_data);
         }
+
+        self.bake(context);
+        Ok(())
     }
 
     // Binary search algorithm to find the relevant interval
-    fn search_index(&self, points: &[PointData], time: f32) -> (usize, usize) {
-        let mut l = 0;
+    pub fn search_index(&self, time: f32) -> (usize, usize) {
+        self.search_index_from(time, 0)
+    }
+
+    /// [`search_index`](Self::search_index) starting at the interval found for an earlier,
+    /// smaller time, falls back to searching every point if `hint` is past `time`
+    pub fn search_index_from(&self, time: f32, hint: usize) -> (usize, usize) {
+        let points = &self.points;
+        let mut l = if hint < points.len() && points[hint].get_time() < time {
+            hint
+        } else {
+            0
+        };
         let mut r = points.len();
 
+        // sorted times mostly land in the same or the next interval
+        if l + 1 < r && points[l + 1].get_time() >= time {
+            return (l, l + 1);
+        }
+
         while l < r - 1 {
             let m = (l + r) / 2;
             let point_time = points[m].get_time();
@@ -203,51 +387,86 @@ pub trait PointDefinition {
     }
 
     // Helper method to group values from a JSON value.
-    // In a more complete implementation, you'd examine the JSON structure.
+    // Each value is kept together with its index in the array.
     #[cfg(feature = "json")]
-    fn group_values(value: &JsonValue) -> Vec<(GroupType, Vec<&JsonValue>)> {
+    pub fn group_values(value: &JsonValue) -> Vec<(GroupType, Vec<(usize, &JsonValue)>)> {
         use std::collections::HashMap;
 
         let JsonValue::Array(array) = value else {
             return vec![];
         };
-        let values: Vec<&JsonValue> = array.iter().collect();
 
-        let mut result: HashMap<GroupType, Vec<&JsonValue>> = HashMap::new();
-        for val in &values {
+        let mut result: HashMap<GroupType, Vec<(usize, &JsonValue)>> = HashMap::new();
+        for (i, val) in array.iter().enumerate() {
             // group values by their type in the array
             let entry = match val {
                 JsonValue::String(s) if !s.starts_with("base") => GroupType::Flag,
                 JsonValue::Array(_) => GroupType::Modifier,
                 _ => GroupType::Value,
             };
-            result.entry(entry).or_default().push(val);
+            result.entry(entry).or_default().push((i, val));
         }
 
-        let result: Vec<(GroupType, Vec<&JsonValue>)> = result.into_iter().collect();
+        let result: Vec<(GroupType, Vec<(usize, &JsonValue)>)> = result.into_iter().collect();
 
         result
     }
 
     // The main interpolation method. Returns a tuple (interpolated value, is_last_point)
-    fn interpolate(&self, time: f32, context: &BaseProviderContext) -> (Self::Value, bool) {
+    pub fn interpolate(&self, time: f32, context: &BaseProviderContext) -> (T, bool) {
+        self.interpolate_from(time, &mut 0, context)
+    }
+
+    /// Interpolates every time in `times` into the matching slot of `out`.
+    ///
+    /// Sorted times reuse the interval found for the previous time instead of
+    /// searching from the first point, unsorted times are still correct.
+    ///
+    /// Only the first `min(times.len(), out.len())` times are interpolated,
+    /// returns how many that was.
+    pub fn interpolate_many(
+        &self,
+        times: &[f32],
+        out: &mut [T],
+        context: &BaseProviderContext,
+    ) -> usize {
+        let mut hint = 0;
+        for (time, out) in times.iter().zip(out.iter_mut()) {
+            *out = self.interpolate_from(*time, &mut hint, context).0;
+        }
+        times.len().min(out.len())
+    }
+
+    /// [`interpolate`](Self::interpolate) searching from the interval `hint`,
+    /// which is updated to the interval `time` fell in
+    pub fn interpolate_from(
+        &self,
+        time: f32,
+        hint: &mut usize,
+        context: &BaseProviderContext,
+    ) -> (T, bool) {
+        if let Some(baked) = self.get_baked() {
+            return baked.interpolate(time, hint);
+        }
+
         let points = self.get_points();
 
         if points.is_empty() {
-            return (Self::Value::default(), false);
+            return (T::default(), false);
         }
 
         let last_point = points.last().unwrap();
         if last_point.get_time() <= time {
-            return (self.get_point(last_point, context), true);
+            return (last_point.get_point(context), true);
         }
 
         let first_point = points.first().unwrap();
         if first_point.get_time() >= time {
-            return (self.get_point(first_point, context), false);
+            return (first_point.get_point(context), false);
         }
 
-        let (l, r) = self.search_index(points, time);
+        let (l, r) = self.search_index_from(time, *hint);
+        *hint = l;
         let point_l = &points[l];
         let point_r = &points[r];
 
@@ -259,8 +478,124 @@ pub trait PointDefinition {
 
         let eased_time = point_r.get_easing().interpolate(normal_time);
         (
-            self.interpolate_points(points, l, r, eased_time, context),
+            interpolate_segment(
+                points.len(),
+                l,
+                r,
+                eased_time,
+                |i| points[i].get_point(context),
+                |i| points[i].smooth,
+            ),
             false,
         )
     }
+
+    /// [`interpolate`](Self::interpolate) along with the rate the value changes at,
+    /// per unit of `time`. The velocity is zero before the first and after the last point.
+    ///
+    /// Evaluates the points themselves, baked definitions included.
+    pub fn interpolate_with_velocity(
+        &self,
+        time: f32,
+        context: &BaseProviderContext,
+    ) -> (T, T::Velocity, bool) {
+        let points = self.get_points();
+        let still = T::Velocity::default();
+
+        let Some(last_point) = points.last() else {
+            return (T::default(), still, false);
+        };
+        if last_point.get_time() <= time {
+            return (last_point.get_point(context), still, true);
+        }
+
+        let first_point = points.first().unwrap();
+        if first_point.get_time() >= time {
+            return (first_point.get_point(context), still, false);
+        }
+
+        let (l, r) = self.search_index(time);
+        let point_l = &points[l];
+        let point_r = &points[r];
+        let value = |i: usize| points[i].get_point(context);
+        let smooth = |i: usize| points[i].smooth;
+
+        let duration = point_r.get_time() - point_l.get_time();
+        if duration == 0.0 {
+            let value = interpolate_segment(points.len(), l, r, 0.0, value, smooth);
+            return (value, still, false);
+        }
+
+        let normal_time = (time - point_l.get_time()) / duration;
+        let easing = point_r.get_easing();
+        let eased_time = easing.interpolate(normal_time);
+
+        let velocity = segment_velocity(points.len(), l, r, eased_time, value, smooth);
+        (
+            interpolate_segment(points.len(), l, r, eased_time, value, smooth),
+            velocity * (easing.derivative(normal_time) / duration),
+            false,
+        )
+    }
+}
+
+/// Indices of the points before and after a segment of `len` points
+/// from `l` to `r`, `None` unless the segment is flagged to be smooth
+fn smooth_neighbours<T: TrackValue>(
+    len: usize,
+    l: usize,
+    r: usize,
+    smooth: impl Fn(usize) -> bool,
+) -> Option<(usize, usize)> {
+    let flagged = if T::SMOOTH_FROM_START { l } else { r };
+    smooth(flagged).then(|| (l.saturating_sub(1), (r + 1).min(len - 1)))
+}
+
+/// Mixes the values of points `l` and `r` out of `len`, [`TrackValue::smooth_lerp`]
+/// if the segment is flagged and [`TrackValue::lerp`] otherwise
+pub(crate) fn interpolate_segment<T: TrackValue>(
+    len: usize,
+    l: usize,
+    r: usize,
+    time: f32,
+    value: impl Fn(usize) -> T,
+    smooth: impl Fn(usize) -> bool,
+) -> T {
+    match smooth_neighbours::<T>(len, l, r, smooth) {
+        Some((p0, p3)) => T::smooth_lerp(value(p0), value(l), value(r), value(p3), time),
+        None => T::lerp(value(l), value(r), time),
+    }
+}
+
+/// Derivative of [`interpolate_segment`] with respect to `time`
+fn segment_velocity<T: TrackValue>(
+    len: usize,
+    l: usize,
+    r: usize,
+    time: f32,
+    value: impl Fn(usize) -> T,
+    smooth: impl Fn(usize) -> bool,
+) -> T::Velocity {
+    match smooth_neighbours::<T>(len, l, r, smooth) {
+        Some((p0, p3)) => T::smooth_lerp_velocity(value(p0), value(l), value(r), value(p3), time),
+        None => T::lerp_velocity(value(l), value(r), time),
+    }
+}
+
+/// Validates that `values` provide exactly `expected` numbers
+fn check_value_count(
+    values: &[ValueProvider],
+    expected: usize,
+    path: &JsonPath,
+    context: &BaseProviderContext,
+) -> Result<(), ParseError> {
+    let found: usize = values.iter().map(|v| v.values(context).len()).sum();
+    if found != expected {
+        return Err(ParseError::WrongArity {
+            path: path.clone(),
+            expected,
+            found,
+        });
+    }
+    Ok(())
 }
@@ -0,0 +1,58 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+/// A value type point definitions can interpolate.
+///
+/// Adding a value type only takes implementing this, see
+/// [`PointDefinition`](super::PointDefinition) for how it is used.
+pub trait TrackValue: Copy + Default + Send + Sync + 'static {
+    /// What modifiers operate on. The value itself, except for rotations
+    /// which are modified as euler angles before becoming quaternions.
+    type Raw: Copy
+        + Default
+        + Send
+        + Sync
+        + Add<Output = Self::Raw>
+        + Sub<Output = Self::Raw>
+        + Mul<Output = Self::Raw>
+        + Div<Output = Self::Raw>;
+
+    /// Rate of change of a value over time
+    type Velocity: Copy + Default + Send + Sync + Mul<f32, Output = Self::Velocity>;
+
+    /// Amount of numbers in a value, a point adds one more for its time
+    const VALUE_COUNT: usize;
+
+    /// Point flag switching its segment from [`lerp`](Self::lerp) to
+    /// [`smooth_lerp`](Self::smooth_lerp)
+    const SMOOTH_FLAG: Option<&'static str> = None;
+
+    /// Whether the flag is read from the point a segment starts at instead of the one it ends at
+    const SMOOTH_FROM_START: bool = false;
+
+    /// Reads a raw value from exactly [`VALUE_COUNT`](Self::VALUE_COUNT) numbers
+    fn raw_from_slice(values: &[f32]) -> Self::Raw;
+
+    /// Writes a raw value to exactly [`VALUE_COUNT`](Self::VALUE_COUNT) numbers,
+    /// the inverse of [`raw_from_slice`](Self::raw_from_slice)
+    fn raw_to_slice(raw: Self::Raw, out: &mut [f32]);
+
+    fn from_raw(raw: Self::Raw) -> Self;
+
+    fn lerp(a: Self, b: Self, time: f32) -> Self;
+
+    /// Interpolates a flagged segment from `a` to `b`,
+    /// `p0` and `p3` being the points around it
+    fn smooth_lerp(p0: Self, a: Self, b: Self, p3: Self, time: f32) -> Self {
+        let _ = (p0, p3);
+        Self::lerp(a, b, time)
+    }
+
+    /// Derivative of [`lerp`](Self::lerp) with respect to `time`
+    fn lerp_velocity(a: Self, b: Self, time: f32) -> Self::Velocity;
+
+    /// Derivative of [`smooth_lerp`](Self::smooth_lerp) with respect to `time`
+    fn smooth_lerp_velocity(p0: Self, a: Self, b: Self, p3: Self, time: f32) -> Self::Velocity {
+        let _ = (p0, p3);
+        Self::lerp_velocity(a, b, time)
+    }
+}
@@ -0,0 +1,31 @@
+use glam::Vec2;
+
+use super::{PointDefinition, TrackValue};
+
+pub type Vector2PointDefinition = PointDefinition<Vec2>;
+
+impl TrackValue for Vec2 {
+    type Raw = Vec2;
+    type Velocity = Vec2;
+    const VALUE_COUNT: usize = 2;
+
+    fn raw_from_slice(values: &[f32]) -> Vec2 {
+        Vec2::from_slice(values)
+    }
+
+    fn raw_to_slice(raw: Vec2, out: &mut [f32]) {
+        raw.write_to_slice(out);
+    }
+
+    fn from_raw(raw: Vec2) -> Vec2 {
+        raw
+    }
+
+    fn lerp(a: Vec2, b: Vec2, time: f32) -> Vec2 {
+        a.lerp(b, time)
+    }
+
+    fn lerp_velocity(a: Vec2, b: Vec2, _time: f32) -> Vec2 {
+        b - a
+    }
+}
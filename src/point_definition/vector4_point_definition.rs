@@ -1,21 +1,44 @@
 use glam::{FloatExt, Vec4};
 use palette::{Hsv, IntoColor, LinSrgb, RgbHue, rgb::Rgb};
 
-use crate::{
-    easings::functions::Functions,
-    modifiers::{
-        Modifier,
-        operation::Operation,
-        vector4_modifier::{Vector4Modifier, Vector4Values},
-    },
-    point_data::{PointData, vector4_point_data::Vector4PointData},
-    values::{AbstractValueProvider, ValueProvider, base_provider_context::BaseProviderContext},
-};
-
-use super::PointDefinition;
-
-pub struct Vector4PointDefinition {
-    points: Vec<PointData>,
+use super::{PointDefinition, TrackValue};
+
+pub type Vector4PointDefinition = PointDefinition<Vec4>;
+
+impl TrackValue for Vec4 {
+    type Raw = Vec4;
+    type Velocity = Vec4;
+    const VALUE_COUNT: usize = 4;
+    const SMOOTH_FLAG: Option<&'static str> = Some("lerpHSV");
+    const SMOOTH_FROM_START: bool = true;
+
+    fn raw_from_slice(values: &[f32]) -> Vec4 {
+        Vec4::from_slice(values)
+    }
+
+    fn raw_to_slice(raw: Vec4, out: &mut [f32]) {
+        raw.write_to_slice(out);
+    }
+
+    fn from_raw(raw: Vec4) -> Vec4 {
+        raw
+    }
+
+    fn lerp(a: Vec4, b: Vec4, time: f32) -> Vec4 {
+        a.lerp(b, time)
+    }
+
+    fn smooth_lerp(_p0: Vec4, a: Vec4, b: Vec4, _p3: Vec4, time: f32) -> Vec4 {
+        lerp_hsv_vec4(a, b, time)
+    }
+
+    fn lerp_velocity(a: Vec4, b: Vec4, _time: f32) -> Vec4 {
+        b - a
+    }
+
+    fn smooth_lerp_velocity(_p0: Vec4, a: Vec4, b: Vec4, _p3: Vec4, time: f32) -> Vec4 {
+        lerp_hsv_vec4_velocity(a, b, time)
+    }
 }
 
 pub fn lerp_hsv_vec4(color1: Vec4, color2: Vec4, time: f32) -> Vec4 {
@@ -42,122 +65,36 @@ pub fn lerp_hsv_vec4(color1: Vec4, color2: Vec4, time: f32) -> Vec4 {
     Vec4::new(rgb.red, rgb.green, rgb.blue, alpha)
 }
 
-impl PointDefinition for Vector4PointDefinition {
-    type Value = Vec4;
-
-    fn get_count(&self) -> usize {
-        self.points.len()
-    }
-
-    fn has_base_provider(&self) -> bool {
-        self.points.iter().any(|p| p.has_base_provider())
-    }
-
-    fn get_points_mut(&mut self) -> &mut Vec<PointData> {
-        &mut self.points
-    }
-
-    fn create_modifier(
-        &self,
-        values: Vec<ValueProvider>,
-        modifiers: Vec<Modifier>,
-        operation: Operation,
-        context: &BaseProviderContext,
-    ) -> Modifier {
-        let values = match values.as_slice() {
-            [ValueProvider::Static(static_val)] if static_val.values.len() == 4 => {
-                let vec4 = Vec4::new(
-                    static_val.values(context)[0],
-                    static_val.values(context)[1],
-                    static_val.values(context)[2],
-                    static_val.values(context)[3],
-                );
-
-                Vector4Values::Static(vec4)
-            }
-            _ => {
-                let count: usize = values.iter().map(|v| v.values(context).len()).sum();
-                assert_eq!(count, 4, "Vector4 modifier point must have 4 numbers");
-                Vector4Values::Dynamic(values)
-            }
-        };
-
-        Modifier::Vector4(Vector4Modifier::new(values, modifiers, operation))
-    }
-
-    fn create_point_data(
-        &self,
-        values: Vec<ValueProvider>,
-        flags: Vec<String>,
-        modifiers: Vec<Modifier>,
-        easing: Functions,
-        context: &BaseProviderContext,
-    ) -> PointData {
-        let (values, time) = match values.as_slice() {
-            [ValueProvider::Static(static_val)] if static_val.values(context).len() == 5 => {
-                let values = static_val.values(context);
-                let point = Vec4::new(values[0], values[1], values[2], values[3]);
-                (Vector4Values::Static(point), values[4])
-            }
-            _ => {
-                let values_len: usize = values.iter().map(|v| v.values(context).len()).sum();
-
-                let time = if values_len == 5 {
-                    values
-                        .last()
-                        .and_then(|v| v.values(context).last().copied())
-                        .unwrap_or(0.0)
-                } else {
-                    0.0
-                };
-
-                (Vector4Values::Dynamic(values), time)
-            }
-        };
-
-        PointData::Vector4(Vector4PointData::new(
-            values,
-            flags.iter().any(|f| f == "lerpHSV"),
-            time,
-            modifiers,
-            easing,
-        ))
-    }
-
-    fn interpolate_points(
-        &self,
-        points: &[PointData],
-        l: usize,
-        r: usize,
-        time: f32,
-        context: &BaseProviderContext,
-    ) -> Vec4 {
-        let point_l = points[l].get_vector4(context);
-        let point_r = points[r].get_vector4(context);
-
-        if let PointData::Vector4(vector4_point) = &points[l]
-            && vector4_point.hsv_lerp
-        {
-            lerp_hsv_vec4(point_l, point_r, time)
-        } else {
-            point_l.lerp(point_r, time)
-        }
-    }
-
-    fn get_points(&self) -> &Vec<PointData> {
-        &self.points
-    }
-
-    fn get_point(&self, point: &PointData, context: &BaseProviderContext) -> Vec4 {
-        point.get_vector4(context)
-    }
-}
+/// Derivative of [`lerp_hsv_vec4`] with respect to `time`
+pub fn lerp_hsv_vec4_velocity(color1: Vec4, color2: Vec4, time: f32) -> Vec4 {
+    let hsv1: Hsv<f32> = Rgb::new(color1.x, color1.y, color1.z).into_color();
+    let hsv2: Hsv<f32> = Rgb::new(color2.x, color2.y, color2.z).into_color();
 
-impl Vector4PointDefinition {
-    #[cfg(feature = "json")]
-    pub fn new(value: serde_json::Value, context: &mut BaseProviderContext) -> Self {
-        let mut instance = Self { points: Vec::new() };
-        instance.parse(value, context);
-        instance
-    }
+    let (h1, h2) = (hsv1.hue.into_raw_radians(), hsv2.hue.into_raw_radians());
+    // hue in sixths of a turn
+    let hue = RgbHue::from_radians(h1.lerp(h2, time)).into_positive_degrees() / 60.0;
+    let hue_rate = (h2 - h1).to_degrees() / 60.0;
+    let s = hsv1.saturation.lerp(hsv2.saturation, time);
+    let s_rate = hsv2.saturation - hsv1.saturation;
+    let v = hsv1.value.lerp(hsv2.value, time);
+    let v_rate = hsv2.value - hsv1.value;
+
+    // rates of the chroma, of the middle channel and of the smallest channel
+    let chroma = v * s;
+    let c = v_rate * s + v * s_rate;
+    let falloff = hue - (hue * 0.5).floor() * 2.0 - 1.0;
+    let x = c * (1.0 - falloff.abs()) - chroma * falloff.signum() * hue_rate;
+    let m = v_rate - c;
+
+    // sixths of the turn mix the channels like palette does
+    let (red, green, blue) = match hue.floor() as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+
+    Vec4::new(red + m, green + m, blue + m, color2.w - color1.w)
 }
@@ -0,0 +1,4 @@
+pub mod path_property;
+pub mod property;
+pub mod track;
+pub mod track_registry;
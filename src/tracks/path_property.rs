@@ -0,0 +1,218 @@
+use std::{fmt, str::FromStr};
+
+use glam::FloatExt;
+
+use crate::{
+    point_definition::base_point_definition::BasePointDefinition,
+    values::{
+        base_provider_context::BaseProviderContext,
+        value::{BaseValue, BaseValueType},
+    },
+};
+
+/// Names of the properties a track can assign a path animation to
+///
+/// cbindgen:prefix-with-name
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum PathPropertyNames {
+    Position,
+    Rotation,
+    LocalRotation,
+    Scale,
+    DefinitePosition,
+    Dissolve,
+    DissolveArrow,
+    Interactable,
+    Color,
+}
+
+impl PathPropertyNames {
+    /// The kind of value this path produces
+    pub fn get_type(&self) -> BaseValueType {
+        match self {
+            PathPropertyNames::Position
+            | PathPropertyNames::Scale
+            | PathPropertyNames::DefinitePosition => BaseValueType::Vector3,
+            PathPropertyNames::Rotation | PathPropertyNames::LocalRotation => {
+                BaseValueType::Quaternion
+            }
+            PathPropertyNames::Dissolve
+            | PathPropertyNames::DissolveArrow
+            | PathPropertyNames::Interactable => BaseValueType::Float,
+            PathPropertyNames::Color => BaseValueType::Vector4,
+        }
+    }
+}
+
+impl FromStr for PathPropertyNames {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = match s {
+            "position" | "offsetPosition" => Self::Position,
+            "rotation" | "offsetWorldRotation" => Self::Rotation,
+            "localRotation" => Self::LocalRotation,
+            "scale" => Self::Scale,
+            "definitePosition" => Self::DefinitePosition,
+            "dissolve" => Self::Dissolve,
+            "dissolveArrow" => Self::DissolveArrow,
+            "interactable" | "cuttable" => Self::Interactable,
+            "color" => Self::Color,
+            _ => return Err(()),
+        };
+
+        Ok(name)
+    }
+}
+
+/// A path animation on a track, the `PointDefinitionInterpolation` of Heck.
+///
+/// When a new path is assigned the previous one is kept and blended
+/// into the new one as `time` goes from 0 to 1.
+#[derive(Clone)]
+pub struct PathProperty {
+    point: Option<BasePointDefinition>,
+    prev_point: Option<BasePointDefinition>,
+    time: f32,
+    ty: BaseValueType,
+}
+
+impl PathProperty {
+    pub fn new(ty: BaseValueType) -> Self {
+        Self {
+            point: None,
+            prev_point: None,
+            time: 0.0,
+            ty,
+        }
+    }
+
+    pub fn get_type(&self) -> BaseValueType {
+        self.ty
+    }
+
+    /// Blend factor between the previous and the current path
+    pub fn get_time(&self) -> f32 {
+        self.time
+    }
+
+    pub fn set_time(&mut self, time: f32) {
+        self.time = time;
+    }
+
+    /// Start transitioning from the current path to `point`
+    pub fn init(&mut self, point: Option<BasePointDefinition>) {
+        self.time = 0.0;
+        self.prev_point = self.point.take();
+        self.point = point;
+    }
+
+    /// End the transition, dropping the previous path
+    pub fn finish(&mut self) {
+        self.prev_point = None;
+    }
+
+    /// Sample the path at an object's own normalized lifetime `time`.
+    /// While only one side of a transition exists, that side is used as is.
+    pub fn interpolate(&self, time: f32, context: &BaseProviderContext) -> Option<BaseValue> {
+        let value = self
+            .point
+            .as_ref()
+            .map(|point| point.interpolate(time, context).0);
+
+        let Some(prev_point) = &self.prev_point else {
+            return value;
+        };
+        let prev_value = prev_point.interpolate(time, context).0;
+
+        let Some(value) = value else {
+            return Some(prev_value);
+        };
+
+        Some(Self::interpolate_values(prev_value, value, self.time))
+    }
+
+    fn interpolate_values(a: BaseValue, b: BaseValue, time: f32) -> BaseValue {
+        match (a, b) {
+            (BaseValue::Float(a), BaseValue::Float(b)) => a.lerp(b, time).into(),
+            (BaseValue::Vector3(a), BaseValue::Vector3(b)) => a.lerp(b, time).into(),
+            (BaseValue::Vector4(a), BaseValue::Vector4(b)) => a.lerp(b, time).into(),
+            (BaseValue::Quaternion(a), BaseValue::Quaternion(b)) => a.slerp(b, time).into(),
+            // both sides are checked against the property type when assigned
+            _ => b,
+        }
+    }
+}
+
+/// Point definitions are shown by their value type
+impl fmt::Debug for PathProperty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PathProperty")
+            .field("point", &self.point.as_ref().map(|p| p.get_type()))
+            .field("prev_point", &self.prev_point.as_ref().map(|p| p.get_type()))
+            .field("time", &self.time)
+            .field("ty", &self.ty)
+            .finish()
+    }
+}
+
+/// Every path animation a track owns
+#[derive(Clone, Debug)]
+pub struct PathProperties {
+    pub position: PathProperty,
+    pub rotation: PathProperty,
+    pub local_rotation: PathProperty,
+    pub scale: PathProperty,
+    pub definite_position: PathProperty,
+    pub dissolve: PathProperty,
+    pub dissolve_arrow: PathProperty,
+    pub interactable: PathProperty,
+    pub color: PathProperty,
+}
+
+impl Default for PathProperties {
+    fn default() -> Self {
+        Self {
+            position: PathProperty::new(PathPropertyNames::Position.get_type()),
+            rotation: PathProperty::new(PathPropertyNames::Rotation.get_type()),
+            local_rotation: PathProperty::new(PathPropertyNames::LocalRotation.get_type()),
+            scale: PathProperty::new(PathPropertyNames::Scale.get_type()),
+            definite_position: PathProperty::new(PathPropertyNames::DefinitePosition.get_type()),
+            dissolve: PathProperty::new(PathPropertyNames::Dissolve.get_type()),
+            dissolve_arrow: PathProperty::new(PathPropertyNames::DissolveArrow.get_type()),
+            interactable: PathProperty::new(PathPropertyNames::Interactable.get_type()),
+            color: PathProperty::new(PathPropertyNames::Color.get_type()),
+        }
+    }
+}
+
+impl PathProperties {
+    pub fn get_property(&self, name: PathPropertyNames) -> &PathProperty {
+        match name {
+            PathPropertyNames::Position => &self.position,
+            PathPropertyNames::Rotation => &self.rotation,
+            PathPropertyNames::LocalRotation => &self.local_rotation,
+            PathPropertyNames::Scale => &self.scale,
+            PathPropertyNames::DefinitePosition => &self.definite_position,
+            PathPropertyNames::Dissolve => &self.dissolve,
+            PathPropertyNames::DissolveArrow => &self.dissolve_arrow,
+            PathPropertyNames::Interactable => &self.interactable,
+            PathPropertyNames::Color => &self.color,
+        }
+    }
+
+    pub fn get_property_mut(&mut self, name: PathPropertyNames) -> &mut PathProperty {
+        match name {
+            PathPropertyNames::Position => &mut self.position,
+            PathPropertyNames::Rotation => &mut self.rotation,
+            PathPropertyNames::LocalRotation => &mut self.local_rotation,
+            PathPropertyNames::Scale => &mut self.scale,
+            PathPropertyNames::DefinitePosition => &mut self.definite_position,
+            PathPropertyNames::Dissolve => &mut self.dissolve,
+            PathPropertyNames::DissolveArrow => &mut self.dissolve_arrow,
+            PathPropertyNames::Interactable => &mut self.interactable,
+            PathPropertyNames::Color => &mut self.color,
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::str::FromStr;
+
+use crate::{
+    error::PropertyError,
+    values::value::{BaseValue, BaseValueType},
+};
+
+/// Names of the properties a track can animate
+///
+/// cbindgen:prefix-with-name
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum PropertyNames {
+    Position,
+    LocalPosition,
+    Rotation,
+    LocalRotation,
+    Scale,
+    Dissolve,
+    DissolveArrow,
+    Interactable,
+    Time,
+    Color,
+}
+
+impl PropertyNames {
+    /// The kind of value stored in this property
+    pub fn get_type(&self) -> BaseValueType {
+        match self {
+            PropertyNames::Position | PropertyNames::LocalPosition | PropertyNames::Scale => {
+                BaseValueType::Vector3
+            }
+            PropertyNames::Rotation | PropertyNames::LocalRotation => BaseValueType::Quaternion,
+            PropertyNames::Dissolve
+            | PropertyNames::DissolveArrow
+            | PropertyNames::Interactable
+            | PropertyNames::Time => BaseValueType::Float,
+            PropertyNames::Color => BaseValueType::Vector4,
+        }
+    }
+}
+
+impl FromStr for PropertyNames {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = match s {
+            "position" | "offsetPosition" => Self::Position,
+            "localPosition" => Self::LocalPosition,
+            "rotation" | "offsetWorldRotation" => Self::Rotation,
+            "localRotation" => Self::LocalRotation,
+            "scale" => Self::Scale,
+            "dissolve" => Self::Dissolve,
+            "dissolveArrow" => Self::DissolveArrow,
+            "interactable" | "cuttable" => Self::Interactable,
+            "time" => Self::Time,
+            "color" => Self::Color,
+            _ => return Err(()),
+        };
+
+        Ok(name)
+    }
+}
+
+/// A single animatable value on a track
+///
+/// `None` means the property is not currently animated
+#[derive(Clone, Debug)]
+pub struct Property {
+    value: Option<BaseValue>,
+    ty: BaseValueType,
+}
+
+impl Property {
+    pub fn new(ty: BaseValueType) -> Self {
+        Self { value: None, ty }
+    }
+
+    pub fn get_type(&self) -> BaseValueType {
+        self.ty
+    }
+
+    pub fn get_value(&self) -> Option<BaseValue> {
+        self.value
+    }
+
+    /// Set the value, rejecting values of the wrong kind
+    pub fn set_value(&mut self, value: Option<BaseValue>) -> Result<(), PropertyError> {
+        if let Some(v) = &value
+            && v.get_type() != self.ty
+        {
+            return Err(PropertyError::TypeMismatch {
+                expected: self.ty,
+                found: v.get_type(),
+            });
+        }
+        self.value = value;
+        Ok(())
+    }
+}
+
+/// Every property a track owns
+#[derive(Clone, Debug)]
+pub struct Properties {
+    pub position: Property,
+    pub local_position: Property,
+    pub rotation: Property,
+    pub local_rotation: Property,
+    pub scale: Property,
+    pub dissolve: Property,
+    pub dissolve_arrow: Property,
+    pub interactable: Property,
+    pub time: Property,
+    pub color: Property,
+}
+
+impl Default for Properties {
+    fn default() -> Self {
+        Self {
+            position: Property::new(PropertyNames::Position.get_type()),
+            local_position: Property::new(PropertyNames::LocalPosition.get_type()),
+            rotation: Property::new(PropertyNames::Rotation.get_type()),
+            local_rotation: Property::new(PropertyNames::LocalRotation.get_type()),
+            scale: Property::new(PropertyNames::Scale.get_type()),
+            dissolve: Property::new(PropertyNames::Dissolve.get_type()),
+            dissolve_arrow: Property::new(PropertyNames::DissolveArrow.get_type()),
+            interactable: Property::new(PropertyNames::Interactable.get_type()),
+            time: Property::new(PropertyNames::Time.get_type()),
+            color: Property::new(PropertyNames::Color.get_type()),
+        }
+    }
+}
+
+impl Properties {
+    pub fn get_property(&self, name: PropertyNames) -> &Property {
+        match name {
+            PropertyNames::Position => &self.position,
+            PropertyNames::LocalPosition => &self.local_position,
+            PropertyNames::Rotation => &self.rotation,
+            PropertyNames::LocalRotation => &self.local_rotation,
+            PropertyNames::Scale => &self.scale,
+            PropertyNames::Dissolve => &self.dissolve,
+            PropertyNames::DissolveArrow => &self.dissolve_arrow,
+            PropertyNames::Interactable => &self.interactable,
+            PropertyNames::Time => &self.time,
+            PropertyNames::Color => &self.color,
+        }
+    }
+
+    pub fn get_property_mut(&mut self, name: PropertyNames) -> &mut Property {
+        match name {
+            PropertyNames::Position => &mut self.position,
+            PropertyNames::LocalPosition => &mut self.local_position,
+            PropertyNames::Rotation => &mut self.rotation,
+            PropertyNames::LocalRotation => &mut self.local_rotation,
+            PropertyNames::Scale => &mut self.scale,
+            PropertyNames::Dissolve => &mut self.dissolve,
+            PropertyNames::DissolveArrow => &mut self.dissolve_arrow,
+            PropertyNames::Interactable => &mut self.interactable,
+            PropertyNames::Time => &mut self.time,
+            PropertyNames::Color => &mut self.color,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{
+    error::PropertyError,
+    values::{base_provider_context::BaseProviderContext, value::BaseValue},
+};
+
+use super::{
+    path_property::{PathProperties, PathPropertyNames},
+    property::{Properties, PropertyNames},
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct Track {
+    pub name: String,
+    pub properties: Properties,
+    pub path_properties: PathProperties,
+}
+
+impl Track {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            properties: Properties::default(),
+            path_properties: PathProperties::default(),
+        }
+    }
+
+    pub fn get_property(&self, name: PropertyNames) -> Option<BaseValue> {
+        self.properties.get_property(name).get_value()
+    }
+
+    /// Fails if `value` is not of the property's type, clearing with `None` always succeeds
+    pub fn set_property(
+        &mut self,
+        name: PropertyNames,
+        value: Option<BaseValue>,
+    ) -> Result<(), PropertyError> {
+        self.properties.get_property_mut(name).set_value(value)
+    }
+
+    /// Sample a path animation at an object's normalized lifetime `time`
+    pub fn get_path_property(
+        &self,
+        name: PathPropertyNames,
+        time: f32,
+        context: &BaseProviderContext,
+    ) -> Option<BaseValue> {
+        self.path_properties
+            .get_property(name)
+            .interpolate(time, context)
+    }
+
+    /// Clear every property and path back to its unanimated state
+    pub fn reset(&mut self) {
+        self.properties = Properties::default();
+        self.path_properties = PathProperties::default();
+    }
+}
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use super::track::Track;
+
+/// Stable handle to a track in a [`TrackRegistry`]
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct TrackKey(usize);
+
+impl TrackKey {
+    /// Never handed out by a registry, returned by the C API when adding a track fails
+    pub const INVALID: TrackKey = TrackKey(!0);
+}
+
+/// Owns every track of a map, looked up by name
+#[derive(Default)]
+pub struct TrackRegistry {
+    tracks: Vec<Track>,
+    keys: HashMap<String, TrackKey>,
+}
+
+impl TrackRegistry {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns the key of the track with this name, creating it if needed
+    pub fn add_track(&mut self, name: &str) -> TrackKey {
+        if let Some(key) = self.keys.get(name) {
+            return *key;
+        }
+
+        let key = TrackKey(self.tracks.len());
+        self.tracks.push(Track::new(name.to_owned()));
+        self.keys.insert(name.to_owned(), key);
+        key
+    }
+
+    pub fn get_track_key(&self, name: &str) -> Option<TrackKey> {
+        self.keys.get(name).copied()
+    }
+
+    pub fn get_track(&self, key: TrackKey) -> Option<&Track> {
+        self.tracks.get(key.0)
+    }
+
+    pub fn get_track_mut(&mut self, key: TrackKey) -> Option<&mut Track> {
+        self.tracks.get_mut(key.0)
+    }
+
+    pub fn get_track_by_name(&self, name: &str) -> Option<&Track> {
+        self.get_track_key(name).and_then(|key| self.get_track(key))
+    }
+
+    pub fn get_track_by_name_mut(&mut self, name: &str) -> Option<&mut Track> {
+        self.get_track_key(name)
+            .and_then(move |key| self.get_track_mut(key))
+    }
+
+    pub fn tracks(&self) -> impl Iterator<Item = &Track> {
+        self.tracks.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tracks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tracks.is_empty()
+    }
+
+    /// Reset the properties of every track, keeping the tracks themselves
+    pub fn reset(&mut self) {
+        self.tracks.iter_mut().for_each(Track::reset);
+    }
+}
@@ -2,24 +2,23 @@ use std::borrow::Cow;
 
 use tracing::info;
 
-use crate::values::base_provider_context::BaseProviderContext;
+use crate::values::base_provider_context::{BaseProviderContext, BaseProviderId};
 
 use super::AbstractValueProvider;
 
 #[derive(Clone, Debug)]
 pub struct BaseProviderValues {
-    pub(crate) base: String,
+    pub(crate) base: BaseProviderId,
 }
 
 impl BaseProviderValues {
-    pub fn new(base: String) -> Self {
+    pub fn new(base: BaseProviderId) -> Self {
         Self { base }
     }
 }
 
 impl AbstractValueProvider for BaseProviderValues {
-    fn values<'a>(&'a self, context: &BaseProviderContext) -> Cow<'a, [f32]> {
-        let value = context.get_values(&self.base);
-        value.as_slice().to_vec().into()
+    fn values<'a>(&'a self, context: &'a BaseProviderContext) -> Cow<'a, [f32]> {
+        Cow::Borrowed(context.get_values_slice(self.base))
     }
 }
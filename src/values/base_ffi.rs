@@ -25,7 +25,7 @@ impl BaseFFIProviderValues {
 }
 
 impl AbstractValueProvider for BaseFFIProviderValues {
-    fn values<'a>(&'a self, context: &BaseProviderContext) -> Cow<'a, [f32]> {
+    fn values<'a>(&'a self, context: &'a BaseProviderContext) -> Cow<'a, [f32]> {
         let c_values: WrappedValues = unsafe { (*self.fetch)(context, self.user_data) };
         // move to owned values
         let arr = unsafe { slice::from_raw_parts(c_values.values, c_values.length) };
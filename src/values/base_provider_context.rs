@@ -1,68 +1,187 @@
-use std::{
-    borrow::Borrow,
-    cell::{Ref, RefCell, RefMut},
-    ops::{Deref, DerefMut},
-};
+use std::collections::HashMap;
 
-use glam::{Quat, Vec3, Vec4};
-use tracing::info;
+use glam::{Quat, Vec2, Vec3, Vec4};
 
-use crate::modifiers::quaternion_modifier::QuaternionValues;
+use crate::error::BaseProviderError;
 
 use super::{
-    AbstractValueProvider, UpdatableValueProvider, UpdateableValues, ValueProvider,
+    SmoothingState, UpdatableValueProvider, ValueProvider,
     base::BaseProviderValues,
-    quat::QuaternionProviderValues,
+    partial::PartialProviderValues,
+    quat::{QuaternionProviderValues, euler_degrees},
     smooth::SmoothProvidersValues,
-    value::{BaseValue, BaseValueRef},
+    smooth_rot::SmoothRotationProvidersValues,
+    value::{BaseValue, BaseValueRef, BaseValueType},
 };
 
-#[derive(Default)]
+/// Base providers every context starts with, in registration order
+const BEAT_SABER_PROVIDERS: &[(&str, BaseValueType)] = &[
+    //Score
+    ("baseCombo", BaseValueType::Float),
+    ("baseMultipliedScore", BaseValueType::Float),
+    (
+        "baseImmediateMaxPossibleMultipliedScore",
+        BaseValueType::Float,
+    ),
+    ("baseModifiedScore", BaseValueType::Float),
+    (
+        "baseImmediateMaxPossibleModifiedScore",
+        BaseValueType::Float,
+    ),
+    ("baseRelativeScore", BaseValueType::Float),
+    ("baseMultiplier", BaseValueType::Float),
+    ("baseEnergy", BaseValueType::Float),
+    ("baseSongTime", BaseValueType::Float),
+    ("baseSongLength", BaseValueType::Float),
+    //Colors
+    ("baseEnvironmentColor0", BaseValueType::Vector4),
+    ("baseEnvironmentColor0Boost", BaseValueType::Vector4),
+    ("baseEnvironmentColor1", BaseValueType::Vector4),
+    ("baseEnvironmentColor1Boost", BaseValueType::Vector4),
+    ("baseEnvironmentColorW", BaseValueType::Vector4),
+    ("baseEnvironmentColorWBoost", BaseValueType::Vector4),
+    ("baseNote0Color", BaseValueType::Vector4),
+    ("baseNote1Color", BaseValueType::Vector4),
+    ("baseObstaclesColor", BaseValueType::Vector4),
+    ("baseSaberAColor", BaseValueType::Vector4),
+    ("baseSaberBColor", BaseValueType::Vector4),
+    //Transforms
+    ("baseHeadLocalPosition", BaseValueType::Vector3),
+    ("baseHeadLocalRotation", BaseValueType::Quaternion),
+    ("baseHeadLocalScale", BaseValueType::Vector3),
+    ("baseHeadPosition", BaseValueType::Vector3),
+    ("baseHeadRotation", BaseValueType::Quaternion),
+    ("baseLeftHandLocalPosition", BaseValueType::Vector3),
+    ("baseLeftHandLocalRotation", BaseValueType::Quaternion),
+    ("baseLeftHandLocalScale", BaseValueType::Vector3),
+    ("baseLeftHandPosition", BaseValueType::Vector3),
+    ("baseLeftHandRotation", BaseValueType::Quaternion),
+    ("baseRightHandLocalPosition", BaseValueType::Vector3),
+    ("baseRightHandLocalRotation", BaseValueType::Quaternion),
+    ("baseRightHandLocalScale", BaseValueType::Vector3),
+    ("baseRightHandPosition", BaseValueType::Vector3),
+    ("baseRightHandRotation", BaseValueType::Quaternion),
+];
+
+/// Interned name of a registered base provider.
+///
+/// Ids index into the context that registered them. The built-in providers
+/// get the same ids in every context; ids of providers registered at runtime
+/// depend on registration order.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct BaseProviderId(u32);
+
+impl BaseProviderId {
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Slot of a smoothing provider's state in the context that parsed it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct SmoothingId(u32);
+
+impl SmoothingId {
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Base provider values and the mutable state of the providers parsed against them.
+///
+/// Parsed providers and point definitions never mutate, so they can be
+/// shared across threads and evaluated with a `&BaseProviderContext`;
+/// only `set_values` and `tick` need `&mut`.
 pub struct BaseProviderContext {
-    // TODO: Rc? LinkedList?
-    providers: Vec<RefCell<ValueProvider>>,
+    /// Smoothing providers in creation order, so nested smoothing
+    /// is ticked before whatever smooths it again
+    smoothers: Vec<UpdatableValueProvider>,
+    /// State of `smoothers`, indexed by [`SmoothingId`]
+    smoothing: Vec<SmoothingState>,
+    /// Smoother of each base string prefix and multiplier
+    smoother_ids: HashMap<(String, u32), SmoothingId>,
 
-    //Score
-    base_combo: f32,
-    multiplied_score: f32,
-    immediate_max_possible_multiplied_score: f32,
-    modified_score: f32,
-    immediate_max_possible_modified_score: f32,
-    relative_score: f32,
-    multiplier: f32,
-    energy: f32,
-    song_time: f32,
-    song_length: f32,
+    ids: HashMap<String, BaseProviderId>,
+    names: Vec<String>,
+    values: Vec<BaseValue>,
+    /// Euler angles in degrees of each quaternion in `values`, zero for other types
+    eulers: Vec<[f32; 3]>,
+}
 
-    //Colors
-    environment_color_0: Vec4,
-    environment_color_0_boost: Vec4,
-    environment_color_1: Vec4,
-    environment_color_1_boost: Vec4,
-    environment_color_w: Vec4,
-    environment_color_w_boost: Vec4,
-    note_color_0: Vec4,
-    note_color_1: Vec4,
-    obstacles_color: Vec4,
-    saber_color_a: Vec4,
-    saber_color_b: Vec4,
+impl Default for BaseProviderContext {
+    fn default() -> Self {
+        let mut context = Self {
+            smoothers: Vec::new(),
+            smoothing: Vec::new(),
+            smoother_ids: HashMap::new(),
+            ids: HashMap::new(),
+            names: Vec::new(),
+            values: Vec::new(),
+            eulers: Vec::new(),
+        };
 
-    //Transforms
-    head_local_position: Vec3,
-    head_local_rotation: Quat,
-    head_local_scale: Vec3,
-    head_position: Vec3,
-    head_rotation: Quat,
-    left_hand_local_position: Vec3,
-    left_hand_local_rotation: Quat,
-    left_hand_local_scale: Vec3,
-    left_hand_position: Vec3,
-    left_hand_rotation: Quat,
-    right_hand_local_position: Vec3,
-    right_hand_local_rotation: Quat,
-    right_hand_local_scale: Vec3,
-    right_hand_position: Vec3,
-    right_hand_rotation: Quat,
+        for (name, ty) in BEAT_SABER_PROVIDERS {
+            context.register(name, *ty).unwrap();
+        }
+
+        context
+    }
+}
+
+/// Component index of a swizzle character, e.g. `z` or `b` in `baseNote0Color.rgb`
+fn swizzle_index(c: char) -> Option<usize> {
+    match c {
+        'x' | 'r' => Some(0),
+        'y' | 'g' => Some(1),
+        'z' | 'b' => Some(2),
+        'w' | 'a' => Some(3),
+        _ => None,
+    }
+}
+
+/// Values a provider of `ty` yields, quaternions are read as euler angles
+fn value_len(ty: BaseValueType) -> usize {
+    match ty {
+        BaseValueType::Float | BaseValueType::Bool => 1,
+        BaseValueType::Vector2 => 2,
+        BaseValueType::Vector3 | BaseValueType::Quaternion => 3,
+        BaseValueType::Vector4 => 4,
+    }
+}
+
+/// One `.` separated suffix of a base string
+enum Suffix {
+    /// `s2` or `s0_5`, smoothing by the multiplier
+    Smooth(f32),
+    /// `xz` or `rgb`, the indices of the components to keep
+    Swizzle(Vec<usize>),
+}
+
+impl Suffix {
+    /// `None` if malformed, `len` is the number of values the suffix applies to
+    fn parse(split: &str, len: usize) -> Option<Self> {
+        match split.strip_prefix('s') {
+            Some(mult) => mult.replace('_', ".").parse().ok().map(Suffix::Smooth),
+            None if split.is_empty() => None,
+            None => split
+                .chars()
+                .map(|c| swizzle_index(c).filter(|i| *i < len))
+                .collect::<Option<Vec<usize>>>()
+                .map(Suffix::Swizzle),
+        }
+    }
+}
+
+fn default_value(ty: BaseValueType) -> BaseValue {
+    match ty {
+        BaseValueType::Float => BaseValue::Float(0.0),
+        BaseValueType::Vector2 => BaseValue::Vector2(Vec2::ZERO),
+        BaseValueType::Vector3 => BaseValue::Vector3(Vec3::ZERO),
+        BaseValueType::Vector4 => BaseValue::Vector4(Vec4::ZERO),
+        BaseValueType::Quaternion => BaseValue::Quaternion(Quat::IDENTITY),
+        BaseValueType::Bool => BaseValue::Bool(false),
+    }
 }
 
 impl BaseProviderContext {
@@ -70,226 +189,257 @@ impl BaseProviderContext {
         Default::default()
     }
 
-    pub fn get_values<'a>(&'a self, base: &str) -> BaseValueRef<'a> {
-        match base {
-            "baseCombo" => self.base_combo.borrow().into(),
+    /// Declares a named base provider holding values of `ty`, initialised to zero
+    /// (identity for quaternions).
+    ///
+    /// Registering an existing name again with the same type returns its id.
+    pub fn register(
+        &mut self,
+        name: &str,
+        ty: BaseValueType,
+    ) -> Result<BaseProviderId, BaseProviderError> {
+        if let Some(id) = self.ids.get(name) {
+            let existing = self.values[id.index()].get_type();
+            if existing != ty {
+                return Err(BaseProviderError::AlreadyRegistered {
+                    name: name.to_owned(),
+                    ty: existing,
+                });
+            }
+            return Ok(*id);
+        }
 
-            "baseMultipliedScore" => self.multiplied_score.borrow().into(),
-            "baseImmediateMaxPossibleMultipliedScore" => {
-                self.immediate_max_possible_multiplied_score.borrow().into()
-            }
+        let id = BaseProviderId(self.values.len() as u32);
+        self.ids.insert(name.to_owned(), id);
+        self.names.push(name.to_owned());
+        self.values.push(default_value(ty));
+        self.eulers.push([0.0; 3]);
+        Ok(id)
+    }
 
-            "baseModifiedScore" => self.modified_score.borrow().into(),
-            "baseImmediateMaxPossibleModifiedScore" => {
-                self.immediate_max_possible_modified_score.borrow().into()
-            }
-            "baseRelativeScore" => self.relative_score.borrow().into(),
-            "baseMultiplier" => self.multiplier.borrow().into(),
-            "baseEnergy" => self.energy.borrow().into(),
-            "baseSongTime" => self.song_time.borrow().into(),
-            "baseSongLength" => self.song_length.borrow().into(),
-
-            "baseEnvironmentColor0" => self.environment_color_0.borrow().into(),
-            "baseEnvironmentColor0Boost" => self.environment_color_0_boost.borrow().into(),
-            "baseEnvironmentColor1" => self.environment_color_1.borrow().into(),
-            "baseEnvironmentColor1Boost" => self.environment_color_1_boost.borrow().into(),
-            "baseEnvironmentColorW" => self.environment_color_w.borrow().into(),
-            "baseEnvironmentColorWBoost" => self.environment_color_w_boost.borrow().into(),
-            "baseNote0Color" => self.note_color_0.borrow().into(),
-            "baseNote1Color" => self.note_color_1.borrow().into(),
-            "baseObstaclesColor" => self.obstacles_color.borrow().into(),
-            "baseSaberAColor" => self.saber_color_a.borrow().into(),
-            "baseSaberBColor" => self.saber_color_b.borrow().into(),
-
-            "baseHeadLocalPosition" => self.head_local_position.borrow().into(),
-            "baseHeadLocalRotation" => self.head_local_rotation.borrow().into(),
-            "baseHeadLocalScale" => self.head_local_scale.borrow().into(),
-            "baseHeadPosition" => self.head_position.borrow().into(),
-            "baseHeadRotation" => self.head_rotation.borrow().into(),
-            "baseLeftHandLocalPosition" => self.left_hand_local_position.borrow().into(),
-            "baseLeftHandLocalRotation" => self.left_hand_local_rotation.borrow().into(),
-            "baseLeftHandLocalScale" => self.left_hand_local_scale.borrow().into(),
-            "baseLeftHandPosition" => self.left_hand_position.borrow().into(),
-            "baseLeftHandRotation" => self.left_hand_rotation.borrow().into(),
-            "baseRightHandLocalPosition" => self.right_hand_local_position.borrow().into(),
-            "baseRightHandLocalRotation" => self.right_hand_local_rotation.borrow().into(),
-            "baseRightHandLocalScale" => self.right_hand_local_scale.borrow().into(),
-            "baseRightHandPosition" => self.right_hand_position.borrow().into(),
-            "baseRightHandRotation" => self.right_hand_rotation.borrow().into(),
-            _ => panic!("Base provider not found {}", base),
-        }
+    pub fn get_id(&self, base: &str) -> Option<BaseProviderId> {
+        self.ids.get(base).copied()
     }
 
-    pub fn set_values(&mut self, base: &str, values: BaseValue) {
-        match base {
-            "baseCombo" => {
-                self.base_combo = values[0];
-            }
-            "baseMultipliedScore" => {
-                self.multiplied_score = values[0];
-            }
-            "baseImmediateMaxPossibleMultipliedScore" => {
-                self.immediate_max_possible_multiplied_score = values[0];
-            }
-            "baseModifiedScore" => {
-                self.modified_score = values[0];
-            }
-            "baseImmediateMaxPossibleModifiedScore" => {
-                self.immediate_max_possible_modified_score = values[0];
-            }
-            "baseRelativeScore" => {
-                self.relative_score = values[0];
-            }
-            "baseMultiplier" => {
-                self.multiplier = values[0];
-            }
-            "baseEnergy" => {
-                self.energy = values[0];
-            }
-            "baseSongTime" => {
-                self.song_time = values[0];
-            }
-            "baseSongLength" => {
-                self.song_length = values[0];
-            }
-            "baseEnvironmentColor0" => {
-                self.environment_color_0 = values.as_vec4().unwrap();
-            }
-            "baseEnvironmentColor0Boost" => {
-                self.environment_color_0_boost = values.as_vec4().unwrap();
-            }
-            "baseEnvironmentColor1" => {
-                self.environment_color_1 = values.as_vec4().unwrap();
-            }
-            "baseEnvironmentColor1Boost" => {
-                self.environment_color_1_boost = values.as_vec4().unwrap();
-            }
-            "baseEnvironmentColorW" => {
-                self.environment_color_w = values.as_vec4().unwrap();
-            }
-            "baseEnvironmentColorWBoost" => {
-                self.environment_color_w_boost = values.as_vec4().unwrap();
-            }
-            "baseNote0Color" => {
-                self.note_color_0 = values.as_vec4().unwrap();
-            }
-            "baseNote1Color" => {
-                self.note_color_1 = values.as_vec4().unwrap();
-            }
-            "baseObstaclesColor" => {
-                self.obstacles_color = values.as_vec4().unwrap();
-            }
-            "baseSaberAColor" => {
-                self.saber_color_a = values.as_vec4().unwrap();
-            }
-            "baseSaberBColor" => {
-                self.saber_color_b = values.as_vec4().unwrap();
-            }
-            "baseHeadLocalPosition" => {
-                self.head_local_position = values.as_vec3().unwrap();
-            }
-            "baseHeadLocalRotation" => {
-                self.head_local_rotation = values.as_quat().unwrap();
-            }
-            "baseHeadLocalScale" => {
-                self.head_local_scale = values.as_vec3().unwrap();
-            }
-            "baseHeadPosition" => {
-                self.head_position = values.as_vec3().unwrap();
-            }
-            "baseHeadRotation" => {
-                self.head_rotation = values.as_quat().unwrap();
-            }
-            "baseLeftHandLocalPosition" => {
-                self.left_hand_local_position = values.as_vec3().unwrap();
-            }
-            "baseLeftHandLocalRotation" => {
-                self.left_hand_local_rotation = values.as_quat().unwrap();
-            }
-            "baseLeftHandLocalScale" => {
-                self.left_hand_local_scale = values.as_vec3().unwrap();
-            }
-            "baseLeftHandPosition" => {
-                self.left_hand_position = values.as_vec3().unwrap();
-            }
-            "baseLeftHandRotation" => {
-                self.left_hand_rotation = values.as_quat().unwrap();
-            }
-            "baseRightHandLocalPosition" => {
-                self.right_hand_local_position = values.as_vec3().unwrap();
-            }
-            "baseRightHandLocalRotation" => {
-                self.right_hand_local_rotation = values.as_quat().unwrap();
-            }
-            "baseRightHandLocalScale" => {
-                self.right_hand_local_scale = values.as_vec3().unwrap();
-            }
-            "baseRightHandPosition" => {
-                self.right_hand_position = values.as_vec3().unwrap();
-            }
-            "baseRightHandRotation" => {
-                self.right_hand_rotation = values.as_quat().unwrap();
-            }
-            _ => panic!("Base provider not found"),
-        }
+    pub fn get_name(&self, id: BaseProviderId) -> &str {
+        &self.names[id.index()]
     }
 
-    fn get_modified_provider(&mut self, provider: ValueProvider, split: &str) -> ValueProvider {
-        match split.chars().nth(0) {
-            Some('s') => {
-                let smooth_mult_str = split[1..split.len()].replace("_", ".");
-                println!("smooth_mult_str: {}", smooth_mult_str);
-                let smooth_mult = smooth_mult_str.parse::<f32>().unwrap();
-                println!("smooth_mult: {}", smooth_mult);
-                let smooth = SmoothProvidersValues::new(Box::new(provider), smooth_mult);
-                let smooth_ref = RefCell::new(smooth);
-                ValueProvider::SmoothProviders(smooth_ref)
-            }
-            Some(_) => {
-                //let partial = PartialProviderValues::new(provider, split);
-                //ValueProvider::PartialProvider(partial)
-                provider
-            }
-            None => {
-                eprintln!("Invalid split: {}", split);
-                provider
-            }
+    pub fn get_type(&self, id: BaseProviderId) -> BaseValueType {
+        self.values[id.index()].get_type()
+    }
+
+    pub fn try_get_values<'a>(&'a self, base: &str) -> Option<BaseValueRef<'a>> {
+        self.get_id(base).map(|id| self.get_values_by_id(id))
+    }
+
+    pub fn get_values_by_id(&self, id: BaseProviderId) -> BaseValueRef<'_> {
+        (&self.values[id.index()]).into()
+    }
+
+    /// The raw components of a provider, borrowed from the context
+    pub fn get_values_slice(&self, id: BaseProviderId) -> &[f32] {
+        self.values[id.index()].as_slice()
+    }
+
+    /// A quaternion provider's rotation as euler angles in degrees
+    pub fn get_euler_slice(&self, id: BaseProviderId) -> &[f32] {
+        &self.eulers[id.index()]
+    }
+
+    pub fn set_values(&mut self, base: &str, values: BaseValue) -> Result<(), BaseProviderError> {
+        let id = self
+            .get_id(base)
+            .ok_or_else(|| BaseProviderError::Unknown(base.to_owned()))?;
+        self.set_values_by_id(id, values)
+    }
+
+    /// Errors if `values` is not of the type the provider was registered with
+    pub fn set_values_by_id(
+        &mut self,
+        id: BaseProviderId,
+        values: BaseValue,
+    ) -> Result<(), BaseProviderError> {
+        let slot = &mut self.values[id.index()];
+        if slot.get_type() != values.get_type() {
+            return Err(BaseProviderError::TypeMismatch {
+                name: self.names[id.index()].clone(),
+                expected: slot.get_type(),
+                found: values.get_type(),
+            });
+        }
+
+        if let BaseValue::Quaternion(rotation) = values {
+            self.eulers[id.index()] = euler_degrees(rotation);
         }
+        *slot = values;
+        Ok(())
+    }
+
+    /// Sets a provider from numbers laid out like the type it was registered with,
+    /// see [`BaseValue::from_slice_as`]
+    pub fn set_values_from_slice(
+        &mut self,
+        base: &str,
+        values: &[f32],
+    ) -> Result<(), BaseProviderError> {
+        let id = self
+            .get_id(base)
+            .ok_or_else(|| BaseProviderError::Unknown(base.to_owned()))?;
+        self.set_values_from_slice_by_id(id, values)
+    }
+
+    pub fn set_values_from_slice_by_id(
+        &mut self,
+        id: BaseProviderId,
+        values: &[f32],
+    ) -> Result<(), BaseProviderError> {
+        let ty = self.get_type(id);
+        let value = BaseValue::from_slice_as(ty, values).ok_or_else(|| {
+            BaseProviderError::WrongValueCount {
+                name: self.get_name(id).to_owned(),
+                ty,
+                found: values.len(),
+            }
+        })?;
+        self.set_values_by_id(id, value)
+    }
+
+    /// Splits a base string into its provider and parsed suffixes
+    fn parse_base(&self, base: &str) -> Result<(BaseProviderId, Vec<Suffix>), BaseProviderError> {
+        let mut split = base.split('.');
+        let base_name = split.next().unwrap_or_default();
+        let id = self
+            .get_id(base_name)
+            .ok_or_else(|| BaseProviderError::Unknown(base_name.to_owned()))?;
+
+        let mut len = value_len(self.get_type(id));
+        let suffixes = split
+            .map(|split| {
+                let suffix =
+                    Suffix::parse(split, len).ok_or_else(|| BaseProviderError::InvalidSuffix {
+                        name: base.to_owned(),
+                        suffix: split.to_owned(),
+                    })?;
+                if let Suffix::Swizzle(parts) = &suffix {
+                    len = parts.len();
+                }
+                Ok(suffix)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((id, suffixes))
+    }
+
+    /// Number of values a base string such as `baseHeadPosition.s2.xz` yields,
+    /// without registering the smoothing it would need.
+    /// Fails if the base name is unknown or a suffix is malformed.
+    pub fn value_count(&self, base: &str) -> Result<usize, BaseProviderError> {
+        let (id, suffixes) = self.parse_base(base)?;
+        Ok(suffixes
+            .iter()
+            .rev()
+            .find_map(|suffix| match suffix {
+                Suffix::Swizzle(parts) => Some(parts.len()),
+                Suffix::Smooth(_) => None,
+            })
+            .unwrap_or_else(|| value_len(self.get_type(id))))
     }
 
-    pub fn get_value_provider(&mut self, base: &str) -> RefMut<ValueProvider> {
-        let split_base = base.split(".").collect::<Vec<&str>>();
-        let base_name = split_base[0];
+    /// Builds the provider for a base string such as `baseHeadPosition.s2.xz`.
+    /// Fails if the base name is unknown or a suffix is malformed.
+    ///
+    /// Smoothing is registered once the whole string has parsed and is shared
+    /// by every provider smoothing the same source by the same amount.
+    pub fn try_get_value_provider(
+        &mut self,
+        base: &str,
+    ) -> Result<ValueProvider, BaseProviderError> {
+        let (id, suffixes) = self.parse_base(base)?;
 
-        let base_value = ValueProvider::BaseProvider(BaseProviderValues::new(base_name.to_owned()));
-        let base_value: ValueProvider = match self.get_values(base_name) {
-            BaseValueRef::Quaternion(_) => {
-                info!("Quaternion provider");
+        let base_value = ValueProvider::BaseProvider(BaseProviderValues::new(id));
+        let mut result = match self.get_type(id) {
+            BaseValueType::Quaternion => {
                 ValueProvider::QuaternionProvider(QuaternionProviderValues::new(base_value))
             }
             _ => base_value,
         };
 
-        let mut result = base_value;
+        let mut len = value_len(self.get_type(id));
+        // the base string up to the current suffix, naming what it applies to
+        let mut source_end = base.find('.').unwrap_or(base.len());
+        for (suffix, split) in suffixes.into_iter().zip(base.split('.').skip(1)) {
+            let source = &base[..source_end];
+            source_end += 1 + split.len();
 
-        if split_base.len() > 1 {
-            for i in 1..split_base.len() {
-                result = self.get_modified_provider(result, split_base[i]);
-            }
+            result = match suffix {
+                Suffix::Smooth(mult) => self.get_smoother(source, result, mult, len).into(),
+                Suffix::Swizzle(parts) => {
+                    len = parts.len();
+                    ValueProvider::PartialProvider(PartialProviderValues::new(result, parts))
+                }
+            };
+        }
+
+        Ok(result)
+    }
+
+    /// The smoother for `provider` (parsed from `source`) at `mult`,
+    /// registered on first use
+    fn get_smoother(
+        &mut self,
+        source: &str,
+        provider: ValueProvider,
+        mult: f32,
+        len: usize,
+    ) -> UpdatableValueProvider {
+        let key = (source.to_owned(), mult.to_bits());
+        if let Some(id) = self.smoother_ids.get(&key) {
+            return self.smoothers[id.index()].clone();
         }
 
-        self.providers.push(result.into());
-        self.providers.last().unwrap().borrow_mut()
+        let state = SmoothingId(self.smoothing.len() as u32);
+        // slerp the live quaternion rather than lerping its euler angles
+        let smooth = match provider {
+            ValueProvider::QuaternionProvider(quat) => {
+                let smooth = SmoothRotationProvidersValues::new(*quat.source, mult, state);
+                UpdatableValueProvider::SmoothRotationProviders(smooth)
+            }
+            provider => {
+                let smooth = SmoothProvidersValues::new(Box::new(provider), mult, state);
+                UpdatableValueProvider::SmoothProviders(smooth)
+            }
+        };
+        self.smoothing.push(SmoothingState {
+            values: vec![0.0; len],
+            ..Default::default()
+        });
+        self.smoothers.push(smooth.clone());
+        self.smoother_ids.insert(key, state);
+        smooth
+    }
+
+    /// Number of distinct smoothed providers parsed against this context,
+    /// each is advanced by [`tick`](Self::tick)
+    pub fn smoother_count(&self) -> usize {
+        self.smoothers.len()
     }
 
-    pub fn update(&mut self, delta: f32, context: &mut BaseProviderContext) {
-        for provider in self.providers.iter_mut() {
-            provider.borrow_mut().update(delta, context);
+    /// Advances every smoothed provider parsed against this context.
+    ///
+    /// Call once per frame with the frame's duration,
+    /// after the base values for that frame have been set.
+    pub fn tick(&mut self, delta_seconds: f32) {
+        for i in 0..self.smoothers.len() {
+            // taken out so the smoother can read the rest of the context
+            let mut state = std::mem::take(&mut self.smoothing[i]);
+            self.smoothers[i].update(delta_seconds, self, &mut state);
+            self.smoothing[i] = state;
         }
     }
 
-    pub fn add_provider(&mut self, provider: RefCell<ValueProvider>) -> RefCell<ValueProvider> {
-        self.providers.push(provider);
-        self.providers.last().unwrap().clone()
+    /// Ids only mean something to the context that parsed the provider
+    pub(crate) fn get_smoothing_state(&self, id: SmoothingId) -> Option<&SmoothingState> {
+        self.smoothing.get(id.index())
     }
 }
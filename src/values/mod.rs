@@ -1,10 +1,10 @@
+use crate::error::{BaseProviderError, JsonPath, ParseError};
 use crate::values::base_provider_context::BaseProviderContext;
 use base::BaseProviderValues;
+use glam::Quat;
 use serde_json::Value as JsonValue;
-use std::{
-    borrow::Cow,
-    cell::{RefCell, RefMut},
-};
+use std::borrow::Cow;
+use tracing::error;
 
 pub mod base;
 #[cfg(feature = "ffi")]
@@ -22,7 +22,7 @@ pub mod value;
 /// based on the context
 /// and the values
 pub trait AbstractValueProvider {
-    fn values<'a>(&'a self, context: &BaseProviderContext) -> Cow<'a, [f32]>;
+    fn values<'a>(&'a self, context: &'a BaseProviderContext) -> Cow<'a, [f32]>;
 }
 
 /// Update values on demand
@@ -32,55 +32,111 @@ pub trait AbstractValueProvider {
 pub trait UpdateableValues: AbstractValueProvider {
     /// Update the values from the source
     /// delta is the amount to progress from the source to target
-    fn update(&mut self, delta: f32, context: &BaseProviderContext);
+    ///
+    /// The provider itself is immutable, its progress is kept in `state`
+    fn update(&self, delta: f32, context: &BaseProviderContext, state: &mut SmoothingState);
 }
 
 /// Value provider
 /// without virtual dispatch
+///
+/// Providers are immutable once parsed, smoothing state
+/// lives in the [`BaseProviderContext`] that created them
 #[derive(Clone, Debug)]
 pub enum ValueProvider {
     Static(r#static::StaticValues),
     BaseProvider(base::BaseProviderValues),
     QuaternionProvider(quat::QuaternionProviderValues),
     PartialProvider(partial::PartialProviderValues),
-    SmoothProviders(RefCell<smooth::SmoothProvidersValues>),
-    SmoothRotationProviders(RefCell<smooth_rot::SmoothRotationProvidersValues>),
+    SmoothProviders(smooth::SmoothProvidersValues),
+    SmoothRotationProviders(smooth_rot::SmoothRotationProvidersValues),
 }
 
 #[derive(Clone, Debug)]
 pub enum UpdatableValueProvider {
-    SmoothProviders(RefCell<smooth::SmoothProvidersValues>),
-    SmoothRotationProviders(RefCell<smooth_rot::SmoothRotationProvidersValues>),
+    SmoothProviders(smooth::SmoothProvidersValues),
+    SmoothRotationProviders(smooth_rot::SmoothRotationProvidersValues),
 }
 
-impl ValueProvider {
-    fn update(&mut self, delta: f32, context: &BaseProviderContext) {
+impl From<UpdatableValueProvider> for ValueProvider {
+    fn from(value: UpdatableValueProvider) -> Self {
+        match value {
+            UpdatableValueProvider::SmoothProviders(v) => ValueProvider::SmoothProviders(v),
+            UpdatableValueProvider::SmoothRotationProviders(v) => {
+                ValueProvider::SmoothRotationProviders(v)
+            }
+        }
+    }
+}
+
+impl UpdatableValueProvider {
+    pub(crate) fn update(
+        &self,
+        delta: f32,
+        context: &BaseProviderContext,
+        state: &mut SmoothingState,
+    ) {
         match self {
-            ValueProvider::SmoothProviders(v) => v.borrow_mut().update(delta, context),
-            _ => {}
+            UpdatableValueProvider::SmoothProviders(v) => v.update(delta, context, state),
+            UpdatableValueProvider::SmoothRotationProviders(v) => v.update(delta, context, state),
         }
     }
 }
 
+/// Progress of a smoothing provider, stored per context
+#[derive(Clone, Debug, Default)]
+pub struct SmoothingState {
+    pub(crate) values: Vec<f32>,
+    /// Only used by rotation smoothing
+    pub(crate) rotation: Quat,
+}
+
 impl AbstractValueProvider for ValueProvider {
-    fn values<'a>(&'a self, context: &BaseProviderContext) -> Cow<'a, [f32]> {
+    fn values<'a>(&'a self, context: &'a BaseProviderContext) -> Cow<'a, [f32]> {
         match self {
             ValueProvider::Static(v) => v.values(context),
             ValueProvider::BaseProvider(v) => v.values(context),
             ValueProvider::QuaternionProvider(v) => v.values(context),
             ValueProvider::PartialProvider(v) => v.values(context),
-            ValueProvider::SmoothProviders(v) => {
-                let borrowed = v.borrow();
-                Cow::Owned(borrowed.values(context).to_vec())
-            }
-            ValueProvider::SmoothRotationProviders(v) => {
-                let borrowed = v.borrow();
-                Cow::Owned(borrowed.values(context).to_vec())
+            ValueProvider::SmoothProviders(v) => v.values(context),
+            ValueProvider::SmoothRotationProviders(v) => v.values(context),
+        }
+    }
+}
+
+impl ValueProvider {
+    /// The base string this provider was parsed from, e.g. `baseHeadPosition.s2.xz`,
+    /// `None` for static values
+    pub fn base_name(&self, context: &BaseProviderContext) -> Option<String> {
+        match self {
+            ValueProvider::Static(_) => None,
+            ValueProvider::BaseProvider(v) => Some(context.get_name(v.base).to_owned()),
+            // quaternion bases are wrapped implicitly, there is no suffix for it
+            ValueProvider::QuaternionProvider(v) => v.source.base_name(context),
+            ValueProvider::PartialProvider(v) => {
+                let swizzle: String = v
+                    .parts
+                    .iter()
+                    .map(|&part| ['x', 'y', 'z', 'w'][part])
+                    .collect();
+                Some(format!("{}.{swizzle}", v.source.base_name(context)?))
             }
+            ValueProvider::SmoothProviders(v) => smoothed_name(&v.source, v.mult, context),
+            ValueProvider::SmoothRotationProviders(v) => smoothed_name(&v.source, v.mult, context),
         }
     }
 }
 
+/// `.s` suffix of a smoothed provider, the multiplier's `.` is written as `_`
+fn smoothed_name(
+    source: &ValueProvider,
+    mult: f32,
+    context: &BaseProviderContext,
+) -> Option<String> {
+    let mult = mult.to_string().replace('.', "_");
+    Some(format!("{}.s{mult}", source.base_name(context)?))
+}
+
 // Helper function for linear interpolation
 fn clamp_lerp(start: f32, end: f32, t: f32) -> f32 {
     start + (end - start) * t.clamp(0.0, 1.0)
@@ -104,7 +160,7 @@ impl JsonPointValues {
     /// based on the context
     ///
     /// array
-    pub fn to_raw_values<'a>(&'a self, context: &BaseProviderContext) -> Cow<'a, [f32]> {
+    pub fn to_raw_values<'a>(&'a self, context: &'a BaseProviderContext) -> Cow<'a, [f32]> {
         match self {
             JsonPointValues::Static(v) => Cow::Borrowed(v.as_slice()),
             JsonPointValues::BaseProvider(v) => v.values(context),
@@ -118,30 +174,71 @@ impl JsonPointValues {
 /// # Arguments
 ///
 /// * `base` - Clone of the base values used to initialize the provider.
+///
+/// Errors are logged and result in `None`.
 #[cfg(feature = "json")]
 pub fn deserialize_values(
     value: &[&JsonValue],
     context: &mut BaseProviderContext,
-) -> Vec<ValueProvider> {
+) -> Option<Vec<ValueProvider>> {
+    let indexed: Vec<(usize, &JsonValue)> = value.iter().copied().enumerate().collect();
+    try_deserialize_values(&indexed, &JsonPath::root(), context)
+        .map_err(|e| error!("Failed to deserialize values: {}", e))
+        .ok()
+}
+
+/// Fallible [`deserialize_values`], `value` holds each value with its index in `path`
+#[cfg(feature = "json")]
+pub fn try_deserialize_values(
+    value: &[(usize, &JsonValue)],
+    path: &JsonPath,
+    context: &mut BaseProviderContext,
+) -> Result<Vec<ValueProvider>, ParseError> {
     let mut result = Vec::new();
     let mut start = 0;
 
-    for (i, v) in value.iter().enumerate() {
-        if let JsonValue::String(s) = v {
-            close(&mut result, value.to_vec(), start, i);
-            start = i + 1;
+    for (i, (index, v)) in value.iter().enumerate() {
+        match v {
+            JsonValue::String(s) => {
+                close(&mut result, value, start, i);
+                start = i + 1;
 
-            let base = context.get_value_provider(s);
-            result.push(base);
+                let base = context.try_get_value_provider(s).map_err(|e| match e {
+                    BaseProviderError::InvalidSuffix { name, suffix } => {
+                        ParseError::InvalidBaseSuffix {
+                            path: path.index(*index),
+                            name,
+                            suffix,
+                        }
+                    }
+                    _ => ParseError::UnknownBaseProvider {
+                        path: path.index(*index),
+                        name: s.clone(),
+                    },
+                })?;
+                result.push(base);
+            }
+            JsonValue::Number(_) | JsonValue::Bool(_) => {}
+            _ => {
+                return Err(ParseError::UnexpectedType {
+                    path: path.index(*index),
+                    expected: "a number or base provider",
+                });
+            }
         }
     }
 
-    close(&mut result, value.to_vec(), start, value.len());
-    result
+    close(&mut result, value, start, value.len());
+    Ok(result)
 }
 
 #[cfg(feature = "json")]
-fn close(result: &mut Vec<ValueProvider>, raw_values: Vec<&JsonValue>, open: usize, end: usize) {
+fn close(
+    result: &mut Vec<ValueProvider>,
+    raw_values: &[(usize, &JsonValue)],
+    open: usize,
+    end: usize,
+) {
     use r#static::StaticValues;
 
     if end <= open {
@@ -150,7 +247,37 @@ fn close(result: &mut Vec<ValueProvider>, raw_values: Vec<&JsonValue>, open: usi
 
     let values: Vec<f32> = raw_values[open..end]
         .iter()
-        .filter_map(|v| v.as_f64().map(|i| i as f32))
+        .filter_map(|(_, v)| match v {
+            JsonValue::Bool(b) => Some(*b as u8 as f32),
+            _ => v.as_f64().map(|i| i as f32),
+        })
         .collect();
     result.push(ValueProvider::Static(StaticValues { values }));
 }
+
+/// Inverse of [`deserialize_values`], base providers are written back as their base strings
+#[cfg(feature = "json")]
+pub fn serialize_values(values: &[ValueProvider], context: &BaseProviderContext) -> Vec<JsonValue> {
+    let mut result = Vec::new();
+    for value in values {
+        match value {
+            ValueProvider::Static(s) => result.extend(s.values.iter().copied().map(json_number)),
+            provider => result.push(JsonValue::String(
+                provider.base_name(context).unwrap_or_default(),
+            )),
+        }
+    }
+    result
+}
+
+/// A JSON number written as the shortest decimal that reads back as `value`,
+/// `0.1` rather than `0.10000000149011612`
+#[cfg(feature = "json")]
+pub fn json_number(value: f32) -> JsonValue {
+    value
+        .to_string()
+        .parse::<f64>()
+        .ok()
+        .and_then(serde_json::Number::from_f64)
+        .map_or(JsonValue::Null, JsonValue::Number)
+}
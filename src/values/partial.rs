@@ -1,38 +1,36 @@
-use std::{borrow::Cow, cell::RefCell};
-
-use super::UpdateableValues;
+use std::borrow::Cow;
 
 use crate::values::base_provider_context::BaseProviderContext;
 
-use super::AbstractValueProvider;
+use super::{AbstractValueProvider, ValueProvider};
 
+/// Swizzled subset of another provider's values, e.g. `baseHeadPosition.xz`
 #[derive(Clone, Debug)]
 pub struct PartialProviderValues {
-    pub(crate) source: Vec<f32>,
+    pub(crate) source: Box<ValueProvider>,
     pub(crate) parts: Vec<usize>,
-    pub(crate) values: Vec<f32>,
+    /// Whether `parts` are ascending neighbours, so the values can be a subslice of the source
+    contiguous: bool,
 }
 
 impl PartialProviderValues {
-    pub fn new(source: Vec<f32>, parts: Vec<usize>) -> Self {
+    pub fn new(source: ValueProvider, parts: Vec<usize>) -> Self {
+        let contiguous = parts.windows(2).all(|w| w[1] == w[0] + 1);
         Self {
-            source,
-            values: vec![0.0; parts.len()],
+            source: Box::new(source),
             parts,
+            contiguous,
         }
     }
 }
 
 impl AbstractValueProvider for PartialProviderValues {
-    fn values<'a>(&'a self, _context: &BaseProviderContext) -> Cow<'a, [f32]> {
-        std::borrow::Cow::Borrowed(&self.source)
-    }
-}
-
-impl UpdateableValues for PartialProviderValues {
-    fn update(&mut self, _delta: f32, _context: &BaseProviderContext) {
-        for (i, &part) in self.parts.iter().enumerate() {
-            self.values[i] = self.source[part];
+    fn values<'a>(&'a self, context: &'a BaseProviderContext) -> Cow<'a, [f32]> {
+        match self.source.values(context) {
+            Cow::Borrowed(source) if self.contiguous => {
+                Cow::Borrowed(&source[self.parts[0]..self.parts[0] + self.parts.len()])
+            }
+            source => Cow::Owned(self.parts.iter().map(|&part| source[part]).collect()),
         }
     }
 }
@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use super::{ValueProvider, value::BaseValue};
+use super::ValueProvider;
 
 use crate::{
     modifiers::quaternion_modifier::TRACKS_EULER_ROT,
@@ -25,16 +25,25 @@ impl QuaternionProviderValues {
 }
 
 impl AbstractValueProvider for QuaternionProviderValues {
-    fn values<'a>(&'a self, _context: &BaseProviderContext) -> Cow<'a, [f32]> {
-        let source = self.source.values(_context);
-        let rotation = Quat::from_xyzw(source[0], source[1], source[2], source[3]);
-        let euler = rotation.to_euler(TRACKS_EULER_ROT);
-
-        let values = [
-            euler.0.to_degrees(),
-            euler.1.to_degrees(),
-            euler.2.to_degrees(),
-        ];
-        values.to_vec().into()
+    fn values<'a>(&'a self, context: &'a BaseProviderContext) -> Cow<'a, [f32]> {
+        match self.source.as_ref() {
+            // kept up to date by the context whenever the base is set
+            ValueProvider::BaseProvider(base) => Cow::Borrowed(context.get_euler_slice(base.base)),
+            source => {
+                let source = source.values(context);
+                let rotation = Quat::from_xyzw(source[0], source[1], source[2], source[3]);
+                euler_degrees(rotation).to_vec().into()
+            }
+        }
     }
 }
+
+/// Euler angles of `rotation` in degrees, the order quaternion points are written in
+pub(crate) fn euler_degrees(rotation: Quat) -> [f32; 3] {
+    let euler = rotation.to_euler(TRACKS_EULER_ROT);
+    [
+        euler.0.to_degrees(),
+        euler.1.to_degrees(),
+        euler.2.to_degrees(),
+    ]
+}
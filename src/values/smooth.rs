@@ -1,11 +1,8 @@
-use std::{
-    borrow::Cow,
-    cell::{RefCell, RefMut},
-};
+use std::borrow::Cow;
 
-use super::{UpdateableValues, ValueProvider, clamp_lerp};
+use super::{SmoothingState, UpdateableValues, ValueProvider, clamp_lerp};
 
-use crate::values::base_provider_context::BaseProviderContext;
+use crate::values::base_provider_context::{BaseProviderContext, SmoothingId};
 
 use super::AbstractValueProvider;
 
@@ -13,42 +10,39 @@ use super::AbstractValueProvider;
 pub struct SmoothProvidersValues {
     pub(crate) source: Box<ValueProvider>,
     pub(crate) mult: f32,
-    pub(crate) values: Vec<f32>,
-    pub(crate) has_updated: bool,
+    pub(crate) state: SmoothingId,
 }
 
 impl SmoothProvidersValues {
-    pub fn new(source: Box<ValueProvider>, mult: f32) -> Self {
+    pub fn new(source: Box<ValueProvider>, mult: f32, state: SmoothingId) -> Self {
         Self {
             source,
             mult,
-            values: Vec::new(),
-            has_updated: false,
+            state,
         }
     }
 }
 
 impl AbstractValueProvider for SmoothProvidersValues {
-    fn values<'a>(&'a self, context: &BaseProviderContext) -> Cow<'a, [f32]> {
-        println!("SmoothProvidersValues values called {}", self.has_updated);
-        let mut values = self.values.clone();
-        if values.len() != self.source.values(context).len() {
-            values = vec![0.0; self.source.values(context).len()];
+    fn values<'a>(&'a self, context: &'a BaseProviderContext) -> Cow<'a, [f32]> {
+        // zeroed to the source's length when the provider is parsed
+        match context.get_smoothing_state(self.state) {
+            Some(state) => Cow::Borrowed(&state.values),
+            None => Cow::Borrowed(&[]),
         }
-        values.into()
     }
 }
 
 impl UpdateableValues for SmoothProvidersValues {
-    fn update(&mut self, delta: f32, context: &BaseProviderContext) {
-        println!("SmoothProvidersValues update called {}", self.has_updated);
-        self.has_updated = true;
+    /// Moves towards the source by `delta * mult`, `delta` being in seconds
+    fn update(&self, delta: f32, context: &BaseProviderContext, state: &mut SmoothingState) {
         let source_values = self.source.values(context);
-        if self.values.len() != source_values.len() {
-            self.values = vec![0.0; source_values.len()];
+        if state.values.len() != source_values.len() {
+            state.values = vec![0.0; source_values.len()];
         }
-        for i in 0..self.values.len() {
-            self.values[i] = clamp_lerp(self.values[i], source_values[i], delta);
+        let t = delta * self.mult;
+        for i in 0..state.values.len() {
+            state.values[i] = clamp_lerp(state.values[i], source_values[i], t);
         }
     }
 }
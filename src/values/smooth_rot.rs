@@ -1,53 +1,57 @@
-use std::{borrow::Cow, cell::RefCell};
+use std::borrow::Cow;
 
-use super::UpdateableValues;
+use super::{SmoothingState, UpdateableValues, ValueProvider};
 
-use crate::{
-    modifiers::quaternion_modifier::TRACKS_EULER_ROT,
-    values::base_provider_context::BaseProviderContext,
+use crate::values::{
+    base_provider_context::{BaseProviderContext, SmoothingId},
+    quat::euler_degrees,
 };
 
 use super::AbstractValueProvider;
 
 use glam::Quat;
 
+/// Smooths a rotation by slerping towards its source quaternion,
+/// so it takes the short way round instead of lerping euler angles through ±180°
 #[derive(Clone, Debug)]
 pub struct SmoothRotationProvidersValues {
-    pub(crate) rotation_values: Quat,
+    /// Yields the raw `x, y, z, w` of the rotation to follow
+    pub(crate) source: Box<ValueProvider>,
     pub(crate) mult: f32,
-    pub(crate) last_quaternion: Quat,
-    pub(crate) values: [f32; 3],
+    pub(crate) state: SmoothingId,
 }
 
 impl SmoothRotationProvidersValues {
-    pub fn new(rotation_values: Quat, mult: f32) -> Self {
+    pub fn new(source: ValueProvider, mult: f32, state: SmoothingId) -> Self {
         Self {
-            rotation_values,
+            source: Box::new(source),
             mult,
-            last_quaternion: Quat::IDENTITY,
-            values: Default::default(),
+            state,
         }
     }
 }
 
 impl AbstractValueProvider for SmoothRotationProvidersValues {
-    fn values<'a>(&'a self, _context: &BaseProviderContext) -> Cow<'a, [f32]> {
-        std::borrow::Cow::Borrowed(&self.values)
+    fn values<'a>(&'a self, context: &'a BaseProviderContext) -> Cow<'a, [f32]> {
+        // starts out at identity, zeroed when the provider is parsed
+        match context.get_smoothing_state(self.state) {
+            Some(state) => Cow::Borrowed(&state.values),
+            None => Cow::Borrowed(&[0.0; 3]),
+        }
     }
 }
 
 impl UpdateableValues for SmoothRotationProvidersValues {
-    fn update(&mut self, delta: f32, _context: &BaseProviderContext) {
-        self.last_quaternion = self
-            .last_quaternion
-            .slerp(self.rotation_values, delta * self.mult);
-
-        let euler = self.last_quaternion.to_euler(TRACKS_EULER_ROT);
-
-        self.values = [
-            euler.0.to_degrees(),
-            euler.1.to_degrees(),
-            euler.2.to_degrees(),
-        ];
+    /// Slerps towards the source by `delta * mult`, `delta` being in seconds
+    fn update(&self, delta: f32, context: &BaseProviderContext, state: &mut SmoothingState) {
+        let source = self.source.values(context);
+        let rotation = Quat::from_xyzw(source[0], source[1], source[2], source[3]);
+
+        state.rotation = state
+            .rotation
+            .slerp(rotation, (delta * self.mult).clamp(0.0, 1.0));
+
+        state.values.clear();
+        state.values.extend(euler_degrees(state.rotation));
     }
 }
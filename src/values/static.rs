@@ -17,7 +17,7 @@ impl StaticValues {
 }
 
 impl AbstractValueProvider for StaticValues {
-    fn values<'a>(&'a self, _context: &BaseProviderContext) -> Cow<'a, [f32]> {
+    fn values<'a>(&'a self, _context: &'a BaseProviderContext) -> Cow<'a, [f32]> {
         std::borrow::Cow::Borrowed(&self.values)
     }
 }
@@ -4,10 +4,13 @@ use std::ops::IndexMut;
 use std::ops::Mul;
 
 use glam::Quat;
+use glam::Vec2;
 use glam::Vec3;
 
 use glam::Vec4;
 
+use crate::error::BaseProviderError;
+
 ///
 /// Time based number
 ///
@@ -18,34 +21,84 @@ pub struct TimeValue(f32);
 #[derive(Clone, Debug, Copy)]
 pub enum BaseValue {
     Float(f32),
+    Vector2(Vec2),
     Vector3(Vec3),
     Vector4(Vec4),
     Quaternion(Quat),
+    Bool(bool),
+}
+
+/// The kind of value a [`BaseValue`] holds, without the value itself
+///
+/// cbindgen:prefix-with-name
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum BaseValueType {
+    Float,
+    Vector3,
+    Vector4,
+    Quaternion,
+    Vector2,
+    Bool,
 }
 
 #[derive(Clone, Debug, Copy)]
 pub enum BaseValueRef<'a> {
     Float(&'a f32),
+    Vector2(&'a Vec2),
     Vector3(&'a Vec3),
     Vector4(&'a Vec4),
     Quaternion(&'a Quat),
+    Bool(&'a bool),
 }
 
 impl BaseValue {
     #[inline(always)]
-    pub fn from_vec(value: Vec<f32>, quat: bool) -> BaseValue {
+    pub fn from_vec(value: Vec<f32>, quat: bool) -> Result<BaseValue, BaseProviderError> {
         Self::from_slice(value.as_slice(), quat)
     }
 
-    pub fn from_slice(value: &[f32], quat: bool) -> BaseValue {
-        match value.len() {
+    /// Fails unless `value` holds 1 to 4 numbers
+    pub fn from_slice(value: &[f32], quat: bool) -> Result<BaseValue, BaseProviderError> {
+        let value = match value.len() {
             1 => BaseValue::Float(value[0]),
+            2 => BaseValue::Vector2(Vec2::new(value[0], value[1])),
             3 => BaseValue::Vector3(Vec3::new(value[0], value[1], value[2])),
             4 if quat => BaseValue::Quaternion(Quat::from_slice(value)),
             4 => BaseValue::Vector4(Vec4::new(value[0], value[1], value[2], value[3])),
-            _ => panic!("Invalid value length"),
+            count => return Err(BaseProviderError::InvalidValueCount(count)),
+        };
+        Ok(value)
+    }
+
+    /// A value of type `ty`, `None` unless `value` holds exactly its numbers.
+    /// Quaternions are `x, y, z, w` and bools are true for any non-zero number.
+    pub fn from_slice_as(ty: BaseValueType, value: &[f32]) -> Option<BaseValue> {
+        let value = match (ty, value) {
+            (BaseValueType::Float, &[x]) => BaseValue::Float(x),
+            (BaseValueType::Bool, &[x]) => BaseValue::Bool(x != 0.0),
+            (BaseValueType::Vector2, &[x, y]) => BaseValue::Vector2(Vec2::new(x, y)),
+            (BaseValueType::Vector3, &[x, y, z]) => BaseValue::Vector3(Vec3::new(x, y, z)),
+            (BaseValueType::Vector4, &[x, y, z, w]) => BaseValue::Vector4(Vec4::new(x, y, z, w)),
+            (BaseValueType::Quaternion, &[x, y, z, w]) => {
+                BaseValue::Quaternion(Quat::from_xyzw(x, y, z, w))
+            }
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    pub fn get_type(&self) -> BaseValueType {
+        match self {
+            BaseValue::Float(_) => BaseValueType::Float,
+            BaseValue::Vector2(_) => BaseValueType::Vector2,
+            BaseValue::Vector3(_) => BaseValueType::Vector3,
+            BaseValue::Vector4(_) => BaseValueType::Vector4,
+            BaseValue::Quaternion(_) => BaseValueType::Quaternion,
+            BaseValue::Bool(_) => BaseValueType::Bool,
         }
     }
+
     pub fn as_float(&self) -> Option<f32> {
         match self {
             BaseValue::Float(v) => Some(*v),
@@ -53,6 +106,13 @@ impl BaseValue {
         }
     }
 
+    pub fn as_vec2(&self) -> Option<Vec2> {
+        match self {
+            BaseValue::Vector2(v) => Some(*v),
+            _ => None,
+        }
+    }
+
     pub fn as_vec3(&self) -> Option<Vec3> {
         match self {
             BaseValue::Vector3(v) => Some(*v),
@@ -74,9 +134,18 @@ impl BaseValue {
         }
     }
 
-    pub fn len(&self) -> usize {
+    pub fn as_bool(&self) -> Option<bool> {
         match self {
-            BaseValue::Float(_) => 1,
+            BaseValue::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// How many numbers [`as_slice`](Self::as_slice) holds
+    pub fn value_count(&self) -> usize {
+        match self {
+            BaseValue::Float(_) | BaseValue::Bool(_) => 1,
+            BaseValue::Vector2(_) => 2,
             BaseValue::Vector3(_) => 3,
             BaseValue::Vector4(_) => 4,
             BaseValue::Quaternion(_) => 4,
@@ -86,14 +155,27 @@ impl BaseValue {
     pub fn as_slice(&self) -> &[f32] {
         match self {
             BaseValue::Float(v) => std::slice::from_ref(v),
+            BaseValue::Vector2(v) => v.as_ref(),
             BaseValue::Vector3(v) => v.as_ref(),
             BaseValue::Vector4(v) => v.as_ref(),
             BaseValue::Quaternion(v) => v.as_ref(),
+            BaseValue::Bool(v) => bool_slice(*v),
         }
     }
 }
 
 impl<'a> BaseValueRef<'a> {
+    pub fn get_type(&self) -> BaseValueType {
+        match self {
+            BaseValueRef::Float(_) => BaseValueType::Float,
+            BaseValueRef::Vector2(_) => BaseValueType::Vector2,
+            BaseValueRef::Vector3(_) => BaseValueType::Vector3,
+            BaseValueRef::Vector4(_) => BaseValueType::Vector4,
+            BaseValueRef::Quaternion(_) => BaseValueType::Quaternion,
+            BaseValueRef::Bool(_) => BaseValueType::Bool,
+        }
+    }
+
     pub fn as_float(&self) -> Option<&f32> {
         match self {
             BaseValueRef::Float(v) => Some(v),
@@ -101,6 +183,13 @@ impl<'a> BaseValueRef<'a> {
         }
     }
 
+    pub fn as_vec2(&self) -> Option<&Vec2> {
+        match self {
+            BaseValueRef::Vector2(v) => Some(v),
+            _ => None,
+        }
+    }
+
     pub fn as_vec3(&self) -> Option<&Vec3> {
         match self {
             BaseValueRef::Vector3(v) => Some(v),
@@ -122,21 +211,32 @@ impl<'a> BaseValueRef<'a> {
         }
     }
 
-    pub fn len(&self) -> usize {
+    pub fn as_bool(&self) -> Option<&bool> {
         match self {
-            BaseValueRef::Float(_) => 1,
+            BaseValueRef::Bool(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// How many numbers [`as_slice`](Self::as_slice) holds
+    pub fn value_count(&self) -> usize {
+        match self {
+            BaseValueRef::Float(_) | BaseValueRef::Bool(_) => 1,
+            BaseValueRef::Vector2(_) => 2,
             BaseValueRef::Vector3(_) => 3,
             BaseValueRef::Vector4(_) => 4,
             BaseValueRef::Quaternion(_) => 4,
         }
     }
 
-    pub fn as_slice<'b>(&'b self) -> &'b [f32] {
+    pub fn as_slice(&self) -> &[f32] {
         match self {
             BaseValueRef::Float(v) => std::slice::from_ref(v),
+            BaseValueRef::Vector2(v) => v.as_ref(),
             BaseValueRef::Vector3(v) => v.as_ref(),
             BaseValueRef::Vector4(v) => v.as_ref(),
             BaseValueRef::Quaternion(v) => v.as_ref(),
+            BaseValueRef::Bool(v) => bool_slice(**v),
         }
     }
 }
@@ -147,6 +247,12 @@ impl From<f32> for BaseValue {
     }
 }
 
+impl From<Vec2> for BaseValue {
+    fn from(v: Vec2) -> Self {
+        BaseValue::Vector2(v)
+    }
+}
+
 impl From<Vec3> for BaseValue {
     fn from(v: Vec3) -> Self {
         BaseValue::Vector3(v)
@@ -165,6 +271,17 @@ impl From<Quat> for BaseValue {
     }
 }
 
+impl From<bool> for BaseValue {
+    fn from(v: bool) -> Self {
+        BaseValue::Bool(v)
+    }
+}
+
+/// A bool read as a number, `1` or `0`
+fn bool_slice(v: bool) -> &'static [f32] {
+    if v { &[1.0] } else { &[0.0] }
+}
+
 // scalar ops
 
 impl Mul<f32> for BaseValue {
@@ -173,9 +290,11 @@ impl Mul<f32> for BaseValue {
     fn mul(self, rhs: f32) -> Self::Output {
         match self {
             BaseValue::Float(v) => BaseValue::Float(v * rhs),
+            BaseValue::Vector2(v) => BaseValue::Vector2(v * rhs),
             BaseValue::Vector3(v) => BaseValue::Vector3(v * rhs),
             BaseValue::Vector4(v) => BaseValue::Vector4(v * rhs),
             BaseValue::Quaternion(v) => BaseValue::Quaternion(v * rhs),
+            BaseValue::Bool(v) => BaseValue::Bool(v),
         }
     }
 }
@@ -186,9 +305,11 @@ impl Div<f32> for BaseValue {
     fn div(self, rhs: f32) -> Self::Output {
         match self {
             BaseValue::Float(v) => BaseValue::Float(v / rhs),
+            BaseValue::Vector2(v) => BaseValue::Vector2(v / rhs),
             BaseValue::Vector3(v) => BaseValue::Vector3(v / rhs),
             BaseValue::Vector4(v) => BaseValue::Vector4(v / rhs),
             BaseValue::Quaternion(v) => BaseValue::Quaternion(v / rhs),
+            BaseValue::Bool(v) => BaseValue::Bool(v),
         }
     }
 }
@@ -199,6 +320,7 @@ impl Index<usize> for BaseValue {
     fn index(&self, index: usize) -> &Self::Output {
         match self {
             BaseValue::Float(f) => f,
+            BaseValue::Vector2(v) => &v[index],
             BaseValue::Vector3(v) => &v[index],
             BaseValue::Vector4(v) => &v[index],
             BaseValue::Quaternion(v) => match index {
@@ -208,6 +330,7 @@ impl Index<usize> for BaseValue {
                 3 => &v.w,
                 _ => panic!("Invalid index for Quaternion"),
             },
+            BaseValue::Bool(v) => &bool_slice(*v)[index],
         }
     }
 }
@@ -215,6 +338,7 @@ impl IndexMut<usize> for BaseValue {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         match self {
             BaseValue::Float(f) => f,
+            BaseValue::Vector2(v) => &mut v[index],
             BaseValue::Vector3(v) => &mut v[index],
             BaseValue::Vector4(v) => &mut v[index],
             BaseValue::Quaternion(v) => match index {
@@ -224,6 +348,7 @@ impl IndexMut<usize> for BaseValue {
                 3 => &mut v.w,
                 _ => panic!("Invalid index for Quaternion"),
             },
+            BaseValue::Bool(_) => panic!("Bool values are not indexable mutably"),
         }
     }
 }
@@ -235,9 +360,11 @@ impl IntoIterator for BaseValue {
     fn into_iter(self) -> Self::IntoIter {
         match self {
             BaseValue::Float(v) => Box::new([v].into_iter()),
+            BaseValue::Vector2(v) => Box::new([v.x, v.y].into_iter()),
             BaseValue::Vector3(v) => Box::new([v.x, v.y, v.z].into_iter()),
             BaseValue::Vector4(v) => Box::new([v.x, v.y, v.z, v.w].into_iter()),
             BaseValue::Quaternion(v) => Box::new([v.x, v.y, v.z, v.w].into_iter()),
+            BaseValue::Bool(v) => Box::new([v as u8 as f32].into_iter()),
         }
     }
 }
@@ -246,9 +373,11 @@ impl<'a> From<&'a BaseValue> for BaseValueRef<'a> {
     fn from(v: &'a BaseValue) -> Self {
         match v {
             BaseValue::Float(v) => BaseValueRef::Float(v),
+            BaseValue::Vector2(v) => BaseValueRef::Vector2(v),
             BaseValue::Vector3(v) => BaseValueRef::Vector3(v),
             BaseValue::Vector4(v) => BaseValueRef::Vector4(v),
             BaseValue::Quaternion(v) => BaseValueRef::Quaternion(v),
+            BaseValue::Bool(v) => BaseValueRef::Bool(v),
         }
     }
 }
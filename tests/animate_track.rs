@@ -0,0 +1,159 @@
+use serde_json::json;
+use tracks_rs::{
+    animation::coroutine_manager::CoroutineManager,
+    easings::functions::Functions,
+    error::PropertyError,
+    point_definition::{
+        base_point_definition::BasePointDefinition, float_point_definition::FloatPointDefinition,
+    },
+    tracks::{property::PropertyNames, track_registry::TrackRegistry},
+    values::{base_provider_context::BaseProviderContext, value::BaseValueType},
+};
+
+fn definition(points: serde_json::Value, context: &mut BaseProviderContext) -> BasePointDefinition {
+    FloatPointDefinition::try_new(points, context)
+        .unwrap()
+        .into()
+}
+
+fn dissolve(registry: &TrackRegistry) -> Option<f32> {
+    registry
+        .get_track_by_name("a")
+        .unwrap()
+        .get_property(PropertyNames::Dissolve)
+        .and_then(|v| v.as_float())
+}
+
+#[test]
+fn animations_run_from_their_start_time_until_they_complete() {
+    let mut context = BaseProviderContext::new();
+    let mut registry = TrackRegistry::new();
+    let mut manager = CoroutineManager::new();
+    let track = registry.add_track("a");
+
+    let fade = definition(json!([[0, 0], [1, 1]]), &mut context);
+    manager
+        .start_animate_track(
+            track,
+            PropertyNames::Dissolve,
+            Some(fade),
+            2.0,
+            4.0,
+            Functions::EaseLinear,
+            0,
+        )
+        .unwrap();
+
+    manager.tick(1.0, &mut registry, &context);
+    assert_eq!(dissolve(&registry), None);
+
+    manager.tick(3.0, &mut registry, &context);
+    assert_eq!(dissolve(&registry), Some(0.25));
+    assert_eq!(manager.len(), 1);
+
+    manager.tick(6.0, &mut registry, &context);
+    assert_eq!(dissolve(&registry), Some(1.0));
+    assert!(manager.is_empty());
+}
+
+#[test]
+fn repeats_restart_the_animation() {
+    let mut context = BaseProviderContext::new();
+    let mut registry = TrackRegistry::new();
+    let mut manager = CoroutineManager::new();
+    let track = registry.add_track("a");
+
+    let fade = definition(json!([[0, 0], [1, 1]]), &mut context);
+    manager
+        .start_animate_track(
+            track,
+            PropertyNames::Dissolve,
+            Some(fade),
+            0.0,
+            1.0,
+            Functions::EaseLinear,
+            2,
+        )
+        .unwrap();
+
+    manager.tick(1.5, &mut registry, &context);
+    assert_eq!(dissolve(&registry), Some(0.5));
+    manager.tick(2.75, &mut registry, &context);
+    assert_eq!(dissolve(&registry), Some(0.75));
+    assert_eq!(manager.len(), 1);
+
+    manager.tick(3.0, &mut registry, &context);
+    assert_eq!(dissolve(&registry), Some(1.0));
+    assert!(manager.is_empty());
+}
+
+#[test]
+fn later_events_replace_earlier_ones_on_the_same_property() {
+    let mut context = BaseProviderContext::new();
+    let mut registry = TrackRegistry::new();
+    let mut manager = CoroutineManager::new();
+    let track = registry.add_track("a");
+
+    let up = definition(json!([[0, 0], [1, 1]]), &mut context);
+    let down = definition(json!([[1, 0], [0, 1]]), &mut context);
+    for fade in [up, down] {
+        manager
+            .start_animate_track(
+                track,
+                PropertyNames::Dissolve,
+                Some(fade),
+                0.0,
+                2.0,
+                Functions::EaseLinear,
+                0,
+            )
+            .unwrap();
+    }
+    assert_eq!(manager.len(), 1);
+
+    manager.tick(0.5, &mut registry, &context);
+    assert_eq!(dissolve(&registry), Some(0.75));
+
+    // a null definition clears the property on its first tick
+    manager
+        .start_animate_track(
+            track,
+            PropertyNames::Dissolve,
+            None,
+            0.0,
+            2.0,
+            Functions::EaseLinear,
+            0,
+        )
+        .unwrap();
+    manager.tick(0.6, &mut registry, &context);
+    assert_eq!(dissolve(&registry), None);
+    assert!(manager.is_empty());
+}
+
+#[test]
+fn mismatched_value_types_are_not_started() {
+    let mut context = BaseProviderContext::new();
+    let mut registry = TrackRegistry::new();
+    let mut manager = CoroutineManager::new();
+    let track = registry.add_track("a");
+
+    let fade = definition(json!([[0, 0], [1, 1]]), &mut context);
+    let started = manager.start_animate_track(
+        track,
+        PropertyNames::Position,
+        Some(fade),
+        0.0,
+        1.0,
+        Functions::EaseLinear,
+        0,
+    );
+    assert_eq!(
+        started,
+        Err(PropertyError::TypeMismatch {
+            expected: BaseValueType::Vector3,
+            found: BaseValueType::Float,
+        })
+    );
+    assert!(manager.is_empty());
+}
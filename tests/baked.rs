@@ -0,0 +1,116 @@
+use serde_json::{Value, json};
+use tracks_rs::{
+    point_definition::{
+        PointDefinition, TrackValue, float_point_definition::FloatPointDefinition,
+        quaternion_point_definition::QuaternionPointDefinition,
+        vector3_point_definition::Vector3PointDefinition,
+        vector4_point_definition::Vector4PointDefinition,
+    },
+    values::base_provider_context::BaseProviderContext,
+};
+
+const SAMPLES: usize = 500;
+
+/// Adds a modifier reading a base provider that stays zero to every point,
+/// which gives the same values through the unbaked path
+fn with_zero_base(points: &Value, base: &str) -> Value {
+    let points = points
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|point| {
+            let mut point = point.as_array().unwrap().clone();
+            point.push(json!([base, "opAdd"]));
+            Value::Array(point)
+        })
+        .collect();
+    Value::Array(points)
+}
+
+fn assert_baked_matches<T: TrackValue + PartialEq + std::fmt::Debug>(
+    points: Value,
+    base: &str,
+    parse: impl Fn(Value, &mut BaseProviderContext) -> PointDefinition<T>,
+) {
+    let mut context = BaseProviderContext::new();
+    let baked = parse(points.clone(), &mut context);
+    let unbaked = parse(with_zero_base(&points, base), &mut context);
+    assert!(baked.get_baked().is_some());
+    assert!(unbaked.get_baked().is_none());
+
+    for i in 0..=SAMPLES {
+        let time = i as f32 / SAMPLES as f32 * 1.2 - 0.1;
+        assert_eq!(
+            baked.interpolate(time, &context),
+            unbaked.interpolate(time, &context),
+            "at {time}"
+        );
+    }
+}
+
+#[test]
+fn float() {
+    assert_baked_matches(
+        json!([
+            [0, 0],
+            [1, 0.25, "easeInOutSine"],
+            [-2, 0.5],
+            [4, 1, "easeOutBounce"]
+        ]),
+        "baseCombo",
+        |points, context| FloatPointDefinition::try_new(points, context).unwrap(),
+    );
+}
+
+#[test]
+fn vector3_with_splines() {
+    assert_baked_matches(
+        json!([
+            [0, 0, 0, 0],
+            [1, 2, 3, 0.25, "splineCatmullRom"],
+            [1, 2, 3, 0.25],
+            [-1, 0, 5, 0.5, "easeInCubic", "splineCatmullRom"],
+            [4, 4, 4, 1, "splineCatmullRom"]
+        ]),
+        "baseHeadPosition",
+        |points, context| Vector3PointDefinition::try_new(points, context).unwrap(),
+    );
+}
+
+#[test]
+fn vector4_with_hsv() {
+    assert_baked_matches(
+        json!([
+            [1, 0, 0, 1, 0, "lerpHSV"],
+            [0, 0, 1, 0.5, 0.5, "easeInQuad"],
+            [0, 1, 0, 1, 1]
+        ]),
+        "baseNote0Color",
+        |points, context| Vector4PointDefinition::try_new(points, context).unwrap(),
+    );
+}
+
+#[test]
+fn quaternion() {
+    assert_baked_matches(
+        json!([
+            [0, 0, 0, 0],
+            [0, 90, 0, 0.5, "easeOutQuad"],
+            [180, 0, 45, 1]
+        ]),
+        "baseHeadRotation",
+        |points, context| QuaternionPointDefinition::try_new(points, context).unwrap(),
+    );
+}
+
+#[test]
+fn reparsing_bakes_again() {
+    let mut context = BaseProviderContext::new();
+    let mut definition =
+        FloatPointDefinition::try_new(json!([[0, 0], [1, 1]]), &mut context).unwrap();
+    definition.try_parse(json!([[3, 2]]), &mut context).unwrap();
+
+    assert_eq!(definition.get_count(), 3);
+    assert_eq!(definition.interpolate(1.5, &context).0, 2.0);
+    assert_eq!(definition.interpolate(3.0, &context), (3.0, true));
+}
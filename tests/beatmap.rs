@@ -0,0 +1,162 @@
+use glam::{Quat, Vec3};
+use serde_json::json;
+use tracks_rs::{
+    error::ParseError,
+    point_definition::beatmap::{
+        BeatmapVersion, load_point_definitions, try_load_point_definitions,
+    },
+    values::{base_provider_context::BaseProviderContext, value::BaseValueType},
+};
+
+fn v3_beatmap() -> serde_json::Value {
+    json!({
+        "version": "3.2.0",
+        "colorNotes": [
+            { "b": 4, "customData": { "animation": { "dissolve": "fadeIn" } } }
+        ],
+        "customData": {
+            "customEvents": [
+                { "b": 2, "t": "AnimateTrack", "d": { "track": "a", "localRotation": "spin", "duration": 4 } },
+                { "b": 3, "t": "AssignPathAnimation", "d": { "track": "a", "color": "flash" } }
+            ],
+            "pointDefinitions": {
+                "spin": [[0, 0, 0, 0], [0, 90, 0, 1]],
+                "fadeIn": [[0, 0], [1, 1, "easeOutQuad"]],
+                "flash": [[1, 1, 1, 1, 0], [0, 0, 0, 1, 1]],
+                "unused": [[0, 0, 0, 0], [1, 1, 1, 1]]
+            }
+        }
+    })
+}
+
+fn v2_beatmap() -> serde_json::Value {
+    json!({
+        "_version": "2.6.0",
+        "_notes": [
+            { "_time": 4, "_customData": { "_animation": { "_dissolve": "fadeIn" } } }
+        ],
+        "_customData": {
+            "_customEvents": [
+                { "_time": 2, "_type": "AnimateTrack", "_data": { "_track": "a", "_localRotation": "spin" } }
+            ],
+            "_pointDefinitions": [
+                { "_name": "spin", "_points": [[0, 0, 0, 0], [0, 90, 0, 1]] },
+                { "_name": "fadeIn", "_points": [[0, 0], [1, 1, "easeOutQuad"]] },
+                { "_name": "unused", "_points": [[0, 0, 0, 0], [1, 1, 1, 1]] }
+            ]
+        }
+    })
+}
+
+#[test]
+fn detects_the_version() {
+    assert_eq!(BeatmapVersion::detect(&v2_beatmap()), BeatmapVersion::V2);
+    assert_eq!(BeatmapVersion::detect(&v3_beatmap()), BeatmapVersion::V3);
+}
+
+#[test]
+fn v3_definitions_are_typed_by_their_use() {
+    let mut context = BaseProviderContext::new();
+    let definitions = try_load_point_definitions(&v3_beatmap(), &mut context).unwrap();
+
+    assert_eq!(definitions.len(), 4);
+    assert_eq!(definitions["spin"].get_type(), BaseValueType::Quaternion);
+    assert_eq!(definitions["fadeIn"].get_type(), BaseValueType::Float);
+    assert_eq!(definitions["flash"].get_type(), BaseValueType::Vector4);
+    // nothing animates it, three numbers and a time read as a vector
+    assert_eq!(definitions["unused"].get_type(), BaseValueType::Vector3);
+
+    let (spin, _) = definitions["spin"].interpolate(0.5, &context);
+    let expected = Quat::from_rotation_y(45f32.to_radians());
+    assert!(spin.as_quat().unwrap().angle_between(expected) < 1e-3);
+}
+
+#[test]
+fn v2_and_v3_load_the_same_definitions() {
+    let mut context = BaseProviderContext::new();
+    let v2 = try_load_point_definitions(&v2_beatmap(), &mut context).unwrap();
+    let v3 = try_load_point_definitions(&v3_beatmap(), &mut context).unwrap();
+
+    assert_eq!(v2.len(), 3);
+    for (name, definition) in &v2 {
+        assert_eq!(definition.get_type(), v3[name].get_type(), "{name}");
+        for i in 0..=10 {
+            let time = i as f32 / 10.0;
+            let (v2_value, v2_last) = definition.interpolate(time, &context);
+            let (v3_value, v3_last) = v3[name].interpolate(time, &context);
+            assert_eq!(
+                (v2_value.as_slice(), v2_last),
+                (v3_value.as_slice(), v3_last),
+                "{name} at {time}"
+            );
+        }
+    }
+    assert_eq!(
+        v2["unused"].interpolate(1.0, &context).0.as_vec3(),
+        Some(Vec3::ONE)
+    );
+}
+
+#[test]
+fn errors_point_into_the_beatmap() {
+    let mut context = BaseProviderContext::new();
+    let beatmap = json!({
+        "customData": {
+            "customEvents": [
+                { "t": "AnimateTrack", "d": { "position": "broken" } }
+            ],
+            "pointDefinitions": {
+                "broken": [[0, 0, 0], [1, 1, 1, 1]],
+                "fine": [[0, 0], [1, 1]]
+            }
+        }
+    });
+
+    let error = try_load_point_definitions(&beatmap, &mut context)
+        .err()
+        .unwrap();
+    assert_eq!(
+        error,
+        ParseError::WrongArity {
+            path: tracks_rs::error::JsonPath::root()
+                .key("customData")
+                .key("pointDefinitions")
+                .key("broken")
+                .index(0),
+            expected: 4,
+            found: 3,
+        }
+    );
+
+    // the lenient loader leaves out only the broken definition
+    let definitions = load_point_definitions(&beatmap, &mut context);
+    assert_eq!(definitions.len(), 1);
+    assert!(definitions.contains_key("fine"));
+}
+
+#[test]
+fn unknown_types_and_malformed_entries_are_errors() {
+    let mut context = BaseProviderContext::new();
+    let beatmap = json!({
+        "customData": { "pointDefinitions": { "odd": [[1, 2, 3, 4, 5, 0]] } }
+    });
+    let error = try_load_point_definitions(&beatmap, &mut context)
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.to_string(),
+        "$.customData.pointDefinitions.odd: cannot infer the value type of point definition \"odd\""
+    );
+
+    let beatmap = json!({
+        "_version": "2.0.0",
+        "_customData": { "_pointDefinitions": [{ "_name": "noPoints" }] }
+    });
+    let error = try_load_point_definitions(&beatmap, &mut context)
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.to_string(),
+        "$._customData._pointDefinitions[0]: expected an object with _name and _points"
+    );
+}
@@ -0,0 +1,221 @@
+use std::{ffi::CStr, ptr, str::FromStr};
+
+use serde_json::json;
+use tracks_rs::{
+    easings::functions::Functions, ffi::*,
+    point_definition::float_point_definition::FloatPointDefinition,
+    values::base_provider_context::BaseProviderContext,
+};
+
+/// The point of the bezier curve at parameter `s`
+fn bezier(x1: f32, y1: f32, x2: f32, y2: f32, s: f32) -> (f32, f32) {
+    let axis = |p1: f32, p2: f32| {
+        let r = 1.0 - s;
+        3.0 * r * r * s * p1 + 3.0 * r * s * s * p2 + s * s * s
+    };
+    (axis(x1, x2), axis(y1, y2))
+}
+
+#[test]
+fn parses_parameterised_easings() {
+    assert_eq!(
+        Functions::from_str("cubic-bezier(0.25, 0.1, 0.25, 1)"),
+        Ok(Functions::EaseCubicBezier {
+            x1: 0.25,
+            y1: 0.1,
+            x2: 0.25,
+            y2: 1.0
+        })
+    );
+    assert_eq!(
+        Functions::from_str("cubic-bezier(0,-0.5,1,1.5)"),
+        Functions::cubic_bezier(0.0, -0.5, 1.0, 1.5).ok_or(())
+    );
+    assert_eq!(
+        Functions::from_str("steps(4)"),
+        Ok(Functions::EaseSteps { steps: 4 })
+    );
+
+    for invalid in [
+        "cubic-bezier(1.5, 0, 0, 1)",
+        "cubic-bezier(0, 0, 1)",
+        "cubic-bezier(0, a, 1, 1)",
+        "cubic-bezier(0, 0, 1, 1",
+        "steps(0)",
+        "steps(-1)",
+        "steps(1.5)",
+        "jump(2)",
+    ] {
+        assert_eq!(Functions::from_str(invalid), Err(()), "{invalid}");
+    }
+}
+
+#[test]
+fn names_round_trip() {
+    for easing in [
+        Functions::EaseInOutBack,
+        Functions::EaseSteps { steps: 3 },
+        Functions::EaseCubicBezier {
+            x1: 0.42,
+            y1: 0.0,
+            x2: 0.58,
+            y2: 1.0,
+        },
+    ] {
+        assert_eq!(Functions::from_str(&easing.name()), Ok(easing));
+    }
+}
+
+#[test]
+fn cubic_bezier_follows_the_curve() {
+    let curves = [
+        (0.25, 0.1, 0.25, 1.0),
+        (0.42, 0.0, 0.58, 1.0),
+        (0.0, 0.0, 1.0, 1.0),
+        (0.7, -0.6, 0.3, 1.6),
+        // flat at the start, where Newton's method stalls
+        (1.0, 0.0, 0.0, 1.0),
+    ];
+    for (x1, y1, x2, y2) in curves {
+        let easing = Functions::cubic_bezier(x1, y1, x2, y2).unwrap();
+        for i in 0..=100 {
+            let (x, y) = bezier(x1, y1, x2, y2, i as f32 / 100.0);
+            let eased = easing.interpolate(x);
+            assert!(
+                (eased - y).abs() < 1e-4,
+                "cubic-bezier({x1}, {y1}, {x2}, {y2}) at {x}: {eased} != {y}"
+            );
+        }
+    }
+
+    let linear = Functions::cubic_bezier(0.0, 0.0, 1.0, 1.0).unwrap();
+    assert!((linear.interpolate(0.3) - 0.3).abs() < 1e-5);
+}
+
+#[test]
+fn steps_jump_at_the_end_of_each_interval() {
+    let easing = Functions::steps(4).unwrap();
+    let values: Vec<f32> = [0.0, 0.2, 0.25, 0.6, 0.99, 1.0]
+        .iter()
+        .map(|t| easing.interpolate(*t))
+        .collect();
+    assert_eq!(values, [0.0, 0.0, 0.25, 0.5, 0.75, 1.0]);
+}
+
+#[test]
+fn in_out_circ_joins_two_quarter_circles() {
+    let easing = Functions::EaseInOutCirc;
+    assert_eq!(easing.interpolate(0.0), 0.0);
+    assert_eq!(easing.interpolate(0.5), 0.5);
+    assert_eq!(easing.interpolate(1.0), 1.0);
+    for t in [0.1f32, 0.25, 0.4] {
+        let expected = 0.5 * (1.0 - (1.0 - 4.0 * t * t).sqrt());
+        assert!((easing.interpolate(t) - expected).abs() < 1e-6);
+        // point symmetric around the middle
+        assert!((easing.interpolate(1.0 - t) - (1.0 - expected)).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn point_flags() {
+    let mut context = BaseProviderContext::new();
+    let definition = FloatPointDefinition::try_new(
+        json!([
+            [0, 0],
+            [0.5, 1, "steps(2)"],
+            [1, 2, "cubic-bezier(0.42, 0, 0.58, 1)"]
+        ]),
+        &mut context,
+    )
+    .unwrap();
+
+    assert_eq!(definition.interpolate(0.4, &context).0, 0.0);
+    assert_eq!(definition.interpolate(0.6, &context).0, 0.25);
+    // the curve is symmetric around its middle
+    assert!((definition.interpolate(1.5, &context).0 - 0.75).abs() < 1e-5);
+
+    assert_eq!(
+        definition.to_json(&context),
+        json!([
+            [0.0, 0.0],
+            [0.5, 1.0, "steps(2)"],
+            [1.0, 2.0, "cubic-bezier(0.42, 0, 0.58, 1)"]
+        ])
+    );
+}
+
+#[test]
+fn ffi_easings() {
+    unsafe {
+        let mut easing = WrapEasing::from(Functions::EaseLinear);
+        assert!(tracks_easing_from_str(c"steps(5)".as_ptr(), &mut easing));
+        assert_eq!(
+            Functions::try_from(easing),
+            Ok(Functions::EaseSteps { steps: 5 })
+        );
+        assert_eq!(tracks_easing_interpolate(easing, 0.5), 0.4);
+
+        assert!(!tracks_easing_from_str(c"steps(0)".as_ptr(), &mut easing));
+        let error = CStr::from_ptr(tracks_last_error());
+        assert_eq!(error.to_str().unwrap(), "invalid easing \"steps(0)\"");
+
+        let mut bezier = easing;
+        let valid = tracks_easing_cubic_bezier(0.42, 0.0, 0.58, 1.0, &mut bezier);
+        assert!(valid);
+        assert!((tracks_easing_interpolate(bezier, 0.5) - 0.5).abs() < 1e-5);
+        let valid = tracks_easing_cubic_bezier(-0.1, 0.0, 0.58, 1.0, &mut bezier);
+        assert!(!valid);
+        assert!(!tracks_last_error().is_null());
+
+        assert!(tracks_easing_steps(2, &mut easing));
+        assert_eq!(
+            Functions::try_from(easing),
+            Ok(Functions::EaseSteps { steps: 2 })
+        );
+        assert!(!tracks_easing_steps(0, &mut easing));
+        // failed constructors leave `out` untouched
+        assert_eq!(easing.steps, 2);
+        assert!(!tracks_easing_steps(1, ptr::null_mut()));
+    }
+}
+
+#[test]
+fn ffi_rejects_invalid_easings() {
+    let in_out_back = WrapEasing::from(Functions::EaseInOutBack);
+    assert_eq!(in_out_back.id, 28);
+    assert_eq!(
+        Functions::try_from(in_out_back),
+        Ok(Functions::EaseInOutBack)
+    );
+
+    // values written field by field from C, not by the constructors
+    let invalid = [
+        WrapEasing {
+            id: 34,
+            steps: 0,
+            bezier: [0.0; 4],
+        },
+        WrapEasing {
+            id: EASING_STEPS,
+            steps: 0,
+            bezier: [0.0; 4],
+        },
+        WrapEasing {
+            id: EASING_CUBIC_BEZIER,
+            steps: 0,
+            bezier: [1.5, 0.0, 0.5, 1.0],
+        },
+    ];
+    for easing in invalid {
+        assert!(Functions::try_from(easing).is_err());
+        assert_eq!(tracks_easing_interpolate(easing, 0.5), 0.0);
+        assert!(!tracks_last_error().is_null());
+    }
+}
+
+#[test]
+fn zero_steps_stay_finite() {
+    let steps = Functions::EaseSteps { steps: 0 };
+    assert_eq!(steps.interpolate(0.5), 0.0);
+    assert_eq!(steps.interpolate(1.0), 1.0);
+}
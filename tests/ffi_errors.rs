@@ -0,0 +1,313 @@
+use std::{
+    ffi::{CStr, c_char},
+    ptr,
+};
+
+use glam::Vec4;
+use tracks_rs::{
+    easings::functions::Functions,
+    ffi::*,
+    tracks::{path_property::PathPropertyNames, property::PropertyNames, track_registry::TrackKey},
+    values::value::{BaseValue, BaseValueType},
+};
+
+fn last_error() -> Option<String> {
+    let error = tracks_last_error();
+    if error.is_null() {
+        return None;
+    }
+    Some(
+        unsafe { CStr::from_ptr(error) }
+            .to_str()
+            .unwrap()
+            .to_owned(),
+    )
+}
+
+#[test]
+fn null_handles_fail_instead_of_crashing() {
+    unsafe {
+        let result = tracks_interpolate_float(ptr::null(), 0.5, ptr::null_mut());
+        assert_eq!(result.value, 0.0);
+        assert_eq!(last_error().as_deref(), Some("point_definition is null"));
+
+        assert!(!tracks_context_tick(ptr::null_mut(), 0.1));
+        assert_eq!(last_error().as_deref(), Some("context is null"));
+
+        let registry = tracks_make_track_registry();
+        assert_eq!(
+            tracks_registry_add_track(registry, ptr::null()),
+            TrackKey::INVALID
+        );
+        assert_eq!(last_error().as_deref(), Some("name is null"));
+        assert!(tracks_registry_get_track(registry, TrackKey::INVALID).is_null());
+        tracks_free_track_registry(registry);
+    }
+}
+
+#[test]
+fn success_clears_the_last_error() {
+    unsafe {
+        let context = tracks_make_base_provider_context();
+        assert!(!tracks_context_tick(ptr::null_mut(), 0.1));
+        assert!(last_error().is_some());
+
+        assert!(tracks_context_tick(context, 0.1));
+        assert_eq!(last_error(), None);
+        tracks_free_base_provider_context(context);
+    }
+}
+
+#[test]
+fn property_type_mismatches_are_reported() {
+    unsafe {
+        let registry = tracks_make_track_registry();
+        let key = tracks_registry_add_track(registry, c"a".as_ptr());
+        let track = tracks_registry_get_track(registry, key);
+
+        let float = WrapBaseValue::from(BaseValue::Float(1.0));
+        assert!(!tracks_track_set_property(
+            track,
+            PropertyNames::Position as u32,
+            float
+        ));
+        assert_eq!(
+            last_error().as_deref(),
+            Some("property holds Vector3 values, got Float")
+        );
+        assert!(tracks_track_set_property(
+            track,
+            PropertyNames::Dissolve as u32,
+            float
+        ));
+
+        let context = tracks_make_base_provider_context();
+        let manager = tracks_make_coroutine_manager();
+        let point = [
+            tracks_create_json_number(0.5),
+            tracks_create_json_number(0.0),
+        ];
+        let points = [tracks_create_json_array(point.as_ptr(), point.len())];
+        let mut json = tracks_create_json_array(points.as_ptr(), points.len());
+        let definition = tracks_make_float_point_definition(&json, context);
+        let easing = Functions::EaseLinear.into();
+        assert!(!tracks_animate_track_float(
+            manager,
+            key,
+            PropertyNames::Position as u32,
+            definition,
+            0.0,
+            1.0,
+            easing,
+            0,
+        ));
+        assert_eq!(
+            last_error().as_deref(),
+            Some("property holds Vector3 values, got Float")
+        );
+        assert!(!tracks_assign_path_animation_float(
+            manager,
+            key,
+            PathPropertyNames::Rotation as u32,
+            definition,
+            0.0,
+            1.0,
+            easing,
+        ));
+        assert_eq!(
+            last_error().as_deref(),
+            Some("property holds Quaternion values, got Float")
+        );
+
+        tracks_free_float_point_definition(definition);
+        tracks_free_json_value(&mut json);
+        tracks_free_coroutine_manager(manager);
+        tracks_free_base_provider_context(context);
+        tracks_free_track_registry(registry);
+    }
+}
+
+#[test]
+fn invalid_enum_values_are_reported() {
+    unsafe {
+        let registry = tracks_make_track_registry();
+        let key = tracks_registry_add_track(registry, c"a".as_ptr());
+        let track = tracks_registry_get_track(registry, key);
+        let context = tracks_make_base_provider_context();
+
+        let float = WrapBaseValue::from(BaseValue::Float(1.0));
+        assert!(!tracks_track_set_property(track, 10, float));
+        assert_eq!(
+            last_error().as_deref(),
+            Some("10 is not a valid PropertyNames")
+        );
+        let mut out = float;
+        assert!(!tracks_track_get_path_property(
+            track, 99, 0.0, context, &mut out
+        ));
+        assert_eq!(
+            last_error().as_deref(),
+            Some("99 is not a valid PathPropertyNames")
+        );
+        assert!(!tracks_register_base_provider(
+            context,
+            c"baseFlag".as_ptr(),
+            u32::MAX
+        ));
+        assert_eq!(
+            last_error().as_deref(),
+            Some("4294967295 is not a valid BaseValueType")
+        );
+
+        // the last variants still map back
+        assert!(tracks_track_set_property(
+            track,
+            PropertyNames::Color as u32,
+            WrapBaseValue::from(BaseValue::Vector4(Vec4::ONE))
+        ));
+        assert!(tracks_register_base_provider(
+            context,
+            c"baseFlag".as_ptr(),
+            BaseValueType::Bool as u32
+        ));
+
+        tracks_free_base_provider_context(context);
+        tracks_free_track_registry(registry);
+    }
+}
+
+#[test]
+fn invalid_strings_are_reported() {
+    let name = [0xffu8, 0];
+    unsafe {
+        let context = tracks_make_base_provider_context();
+        let mut id = std::mem::zeroed();
+        assert!(!tracks_get_base_provider_id(
+            context,
+            name.as_ptr() as *const c_char,
+            &mut id
+        ));
+        assert_eq!(last_error().as_deref(), Some("base is not valid UTF-8"));
+        tracks_free_base_provider_context(context);
+    }
+}
+
+#[test]
+fn parse_errors_return_null() {
+    unsafe {
+        let context = tracks_make_base_provider_context();
+
+        let mut json = tracks_create_json_string(c"notAnArray".as_ptr());
+        let definition = tracks_make_vector3_point_definition(&json, context);
+        assert!(definition.is_null());
+        assert_eq!(
+            last_error().as_deref(),
+            Some("$: expected an array of points")
+        );
+
+        tracks_free_json_value(&mut json);
+        tracks_free_base_provider_context(context);
+    }
+}
+
+#[test]
+fn invalid_value_counts_are_reported() {
+    unsafe {
+        let context = tracks_make_base_provider_context();
+        // a position takes three values
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert!(!tracks_set_base_provider(
+            context,
+            c"baseHeadPosition".as_ptr(),
+            values.as_ptr(),
+            values.len(),
+        ));
+        assert_eq!(
+            last_error().as_deref(),
+            Some("base provider \"baseHeadPosition\" holds Vector3 values, got 5 numbers")
+        );
+
+        let mut id = std::mem::zeroed();
+        assert!(tracks_get_base_provider_id(
+            context,
+            c"baseHeadPosition".as_ptr(),
+            &mut id
+        ));
+        assert!(!tracks_set_base_provider_by_id(
+            context,
+            id,
+            values.as_ptr(),
+            0,
+        ));
+        assert_eq!(
+            last_error().as_deref(),
+            Some("base provider \"baseHeadPosition\" holds Vector3 values, got 0 numbers")
+        );
+
+        // the context is still usable afterwards
+        let values = [1.0, 2.0, 3.0];
+        assert!(tracks_set_base_provider(
+            context,
+            c"baseHeadPosition".as_ptr(),
+            values.as_ptr(),
+            values.len(),
+        ));
+        tracks_free_base_provider_context(context);
+    }
+}
+
+#[test]
+fn malformed_json_values_are_reported() {
+    let invalid_utf8 = [0xffu8, 0];
+    let array = JsonArray {
+        elements: ptr::null(),
+        length: 2,
+    };
+    let cases = [
+        (
+            FFIJsonValue {
+                value_type: JsonValueType::String as u32,
+                data: JsonValueData {
+                    string_value: ptr::null(),
+                },
+            },
+            "string is null",
+        ),
+        (
+            FFIJsonValue {
+                value_type: JsonValueType::String as u32,
+                data: JsonValueData {
+                    string_value: invalid_utf8.as_ptr() as *const c_char,
+                },
+            },
+            "string is not valid UTF-8",
+        ),
+        (
+            FFIJsonValue {
+                value_type: JsonValueType::Array as u32,
+                data: JsonValueData { array: &array },
+            },
+            "elements is null",
+        ),
+        (
+            FFIJsonValue {
+                value_type: 6,
+                data: JsonValueData { number_value: 0.0 },
+            },
+            "6 is not a valid JsonValueType",
+        ),
+    ];
+
+    unsafe {
+        let context = tracks_make_base_provider_context();
+        for (json, error) in &cases {
+            assert!(tracks_make_float_point_definition(json, context).is_null());
+            assert_eq!(last_error().as_deref(), Some(*error));
+        }
+        tracks_free_base_provider_context(context);
+
+        let json = tracks_create_json_array(ptr::null(), 2);
+        assert_eq!(json.value_type, JsonValueType::Null as u32);
+        assert_eq!(last_error().as_deref(), Some("elements is null"));
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    ffi::{CStr, c_char},
+    ptr,
+};
+
+use tracks_rs::ffi::*;
+
+const VECTOR3: &str = r#"[[0, 0, 0, 0], [1, 2, 3, 1, "easeInOutSine"]]"#;
+
+fn last_error() -> Option<String> {
+    let error = tracks_last_error();
+    (!error.is_null()).then(|| {
+        unsafe { CStr::from_ptr(error) }
+            .to_string_lossy()
+            .into_owned()
+    })
+}
+
+/// `VECTOR3` built node by node
+unsafe fn vector3_tree() -> FFIJsonValue {
+    unsafe {
+        let first = [0.0, 0.0, 0.0, 0.0].map(|v| tracks_create_json_number(v));
+        let second = [
+            tracks_create_json_number(1.0),
+            tracks_create_json_number(2.0),
+            tracks_create_json_number(3.0),
+            tracks_create_json_number(1.0),
+            tracks_create_json_string(c"easeInOutSine".as_ptr()),
+        ];
+        let points = [
+            tracks_create_json_array(first.as_ptr(), first.len()),
+            tracks_create_json_array(second.as_ptr(), second.len()),
+        ];
+        tracks_create_json_array(points.as_ptr(), points.len())
+    }
+}
+
+#[test]
+fn from_str_matches_tree() {
+    unsafe {
+        let context = tracks_make_base_provider_context();
+
+        let mut tree = vector3_tree();
+        let from_tree = tracks_make_vector3_point_definition(&tree, context);
+        tracks_free_json_value(&mut tree);
+
+        let from_str = tracks_make_vector3_point_definition_from_str(
+            VECTOR3.as_ptr() as *const c_char,
+            VECTOR3.len(),
+            context,
+        );
+        assert!(!from_str.is_null(), "{:?}", last_error());
+
+        for i in 0..=10 {
+            let time = i as f32 / 10.0;
+            let expected = tracks_interpolate_vector3(from_tree, time, context);
+            let actual = tracks_interpolate_vector3(from_str, time, context);
+            assert_eq!(expected.value, actual.value);
+            assert_eq!(expected.is_last, actual.is_last);
+        }
+
+        tracks_free_vector3_point_definition(from_tree);
+        tracks_free_vector3_point_definition(from_str);
+        tracks_free_base_provider_context(context);
+    }
+}
+
+#[test]
+fn from_str_reads_only_len_bytes() {
+    let text = "[[0.5, 0], [1, 1]] trailing garbage";
+    unsafe {
+        let context = tracks_make_base_provider_context();
+        let definition = tracks_make_float_point_definition_from_str(
+            text.as_ptr() as *const c_char,
+            18,
+            context,
+        );
+        assert!(!definition.is_null(), "{:?}", last_error());
+        assert_eq!(tracks_float_count(definition), 2);
+        assert_eq!(
+            tracks_interpolate_float(definition, 0.0, context).value,
+            0.5
+        );
+
+        tracks_free_float_point_definition(definition);
+        tracks_free_base_provider_context(context);
+    }
+}
+
+#[test]
+fn invalid_json_text_returns_null() {
+    let text = "[[0, 0, 0, 0";
+    unsafe {
+        let context = tracks_make_base_provider_context();
+        let definition = tracks_make_quat_point_definition_from_str(
+            text.as_ptr() as *const c_char,
+            text.len(),
+            context,
+        );
+        assert!(definition.is_null());
+        let error = last_error().unwrap();
+        assert!(error.starts_with("invalid JSON: "), "{error}");
+
+        tracks_free_base_provider_context(context);
+    }
+}
+
+#[test]
+fn objects_and_bools_convert() {
+    unsafe {
+        let context = tracks_make_base_provider_context();
+
+        let keys = [c"enabled".as_ptr(), c"points".as_ptr()];
+        let values = [tracks_create_json_bool(true), vector3_tree()];
+        let mut object = tracks_create_json_object(keys.as_ptr(), values.as_ptr(), keys.len());
+
+        // the object itself converts, it just is not a point definition
+        let definition = tracks_make_vector3_point_definition(&object, context);
+        assert!(definition.is_null());
+        assert_eq!(
+            last_error().as_deref(),
+            Some("$: expected an array of points")
+        );
+
+        tracks_free_json_value(&mut object);
+
+        let mut missing_key = tracks_create_json_object(ptr::null(), ptr::null(), 1);
+        assert_eq!(last_error().as_deref(), Some("keys is null"));
+        tracks_free_json_value(&mut missing_key);
+
+        tracks_free_base_provider_context(context);
+    }
+}
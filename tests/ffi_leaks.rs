@@ -0,0 +1,224 @@
+//! Drives the C API through make/free pairs under an allocator that counts live allocations.
+//!
+//! Everything runs in one test so no other thread allocates while counting.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    ffi::CString,
+    sync::atomic::{AtomicIsize, Ordering},
+};
+
+use tracks_rs::{
+    easings::functions::Functions,
+    ffi::*,
+    tracks::{path_property::PathPropertyNames, property::PropertyNames},
+};
+
+struct CountingAllocator;
+
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        LIVE.fetch_add(1, Ordering::SeqCst);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE.fetch_sub(1, Ordering::SeqCst);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Asserts `f` frees everything it allocates
+fn assert_no_leaks(name: &str, f: impl FnOnce()) {
+    let before = LIVE.load(Ordering::SeqCst);
+    f();
+    let leaked = LIVE.load(Ordering::SeqCst) - before;
+    assert_eq!(leaked, 0, "{name} leaked {leaked} allocations");
+}
+
+/// `[[0, 0, 0, 0], ["baseHeadPosition", 1, "easeInOutSine"]]`
+unsafe fn vector3_json() -> FFIJsonValue {
+    let base = CString::new("baseHeadPosition").unwrap();
+    let easing = CString::new("easeInOutSine").unwrap();
+    unsafe {
+        let first = [
+            tracks_create_json_number(0.0),
+            tracks_create_json_number(0.0),
+            tracks_create_json_number(0.0),
+            tracks_create_json_number(0.0),
+        ];
+        let second = [
+            tracks_create_json_string(base.as_ptr()),
+            tracks_create_json_number(1.0),
+            tracks_create_json_string(easing.as_ptr()),
+        ];
+        let points = [
+            tracks_create_json_array(first.as_ptr(), first.len()),
+            tracks_create_json_array(second.as_ptr(), second.len()),
+        ];
+        tracks_create_json_array(points.as_ptr(), points.len())
+    }
+}
+
+unsafe fn json_numbers(values: &[f64]) -> FFIJsonValue {
+    let values: Vec<FFIJsonValue> = values
+        .iter()
+        .map(|v| unsafe { tracks_create_json_number(*v) })
+        .collect();
+    unsafe { tracks_create_json_array(values.as_ptr(), values.len()) }
+}
+
+#[test]
+fn make_and_free_pairs_do_not_leak() {
+    // the first call into the library sets up logging and other one-off statics
+    unsafe {
+        let context = tracks_make_base_provider_context();
+        tracks_free_base_provider_context(context);
+    }
+
+    assert_no_leaks("json tree", || unsafe {
+        let mut json = vector3_json();
+        tracks_free_json_value(&mut json);
+        // freeing resets the value, so a second free is harmless
+        tracks_free_json_value(&mut json);
+
+        let mut empty = tracks_create_json_array(std::ptr::null(), 0);
+        tracks_free_json_value(&mut empty);
+
+        let keys = [c"enabled".as_ptr(), c"points".as_ptr()];
+        let values = [tracks_create_json_bool(true), vector3_json()];
+        let mut object = tracks_create_json_object(keys.as_ptr(), values.as_ptr(), keys.len());
+        tracks_free_json_value(&mut object);
+    });
+
+    assert_no_leaks("context", || unsafe {
+        let context = tracks_make_base_provider_context();
+        let values = [1.0, 2.0, 3.0];
+        tracks_set_base_provider(
+            context,
+            c"baseHeadPosition".as_ptr(),
+            values.as_ptr(),
+            values.len(),
+        );
+        tracks_context_tick(context, 0.1);
+        tracks_free_base_provider_context(context);
+    });
+
+    assert_no_leaks("point definitions", || unsafe {
+        let context = tracks_make_base_provider_context();
+
+        let mut json = json_numbers(&[0.5, 0.0]);
+        let float = tracks_make_float_point_definition(&json, context);
+        tracks_free_json_value(&mut json);
+        tracks_interpolate_float(float, 0.5, context);
+        tracks_free_float_point_definition(float);
+
+        let mut json = vector3_json();
+        let vector3 = tracks_make_vector3_point_definition(&json, context);
+        tracks_free_json_value(&mut json);
+        tracks_interpolate_vector3(vector3, 0.5, context);
+        tracks_free_vector3_point_definition(vector3);
+
+        let text = "[[0, 0, 0, 0], [1, 2, 3, 1]]";
+        let vector3 = tracks_make_vector3_point_definition_from_str(
+            text.as_ptr() as *const std::ffi::c_char,
+            text.len(),
+            context,
+        );
+        tracks_free_vector3_point_definition(vector3);
+
+        let mut json = json_numbers(&[1.0, 0.0, 0.0, 1.0, 0.0]);
+        let vector4 = tracks_make_vector4_point_definition(&json, context);
+        tracks_free_json_value(&mut json);
+        tracks_free_vector4_point_definition(vector4);
+
+        let mut json = json_numbers(&[0.0, 90.0, 0.0, 0.0]);
+        let quat = tracks_make_quat_point_definition(&json, context);
+        tracks_free_json_value(&mut json);
+        tracks_free_quat_point_definition(quat);
+
+        let mut json = json_numbers(&[1.0, 2.0, 0.0]);
+        let vector2 = tracks_make_vector2_point_definition(&json, context);
+        tracks_free_json_value(&mut json);
+        tracks_free_vector2_point_definition(vector2);
+
+        let mut json = json_numbers(&[1.0, 0.0]);
+        let boolean = tracks_make_bool_point_definition(&json, context);
+        tracks_free_json_value(&mut json);
+        tracks_free_bool_point_definition(boolean);
+
+        tracks_free_base_provider_context(context);
+    });
+
+    assert_no_leaks("point definition library", || unsafe {
+        let context = tracks_make_base_provider_context();
+        let beatmap = r#"{"customData": {"pointDefinitions": {"fade": [[0, 0], [1, 1]]}}}"#;
+        let library = tracks_make_point_definition_library_from_beatmap(
+            beatmap.as_ptr().cast(),
+            beatmap.len(),
+            context,
+        );
+
+        let fade = tracks_library_get_float(library, c"fade".as_ptr());
+        tracks_interpolate_float(fade, 0.5, context);
+        tracks_free_float_point_definition(fade);
+
+        let mut json = vector3_json();
+        let inline = tracks_library_resolve_vector3(library, &json, context);
+        let cached = tracks_library_resolve_vector3(library, &json, context);
+        tracks_free_json_value(&mut json);
+        tracks_free_vector3_point_definition(inline);
+        // the library outlives neither handle
+        tracks_free_point_definition_library(library);
+        tracks_free_vector3_point_definition(cached);
+
+        tracks_free_base_provider_context(context);
+    });
+
+    assert_no_leaks("animations", || unsafe {
+        let context = tracks_make_base_provider_context();
+        let registry = tracks_make_track_registry();
+        let manager = tracks_make_coroutine_manager();
+        let track = tracks_registry_add_track(registry, c"track".as_ptr());
+
+        let mut json = vector3_json();
+        let definition = tracks_make_vector3_point_definition(&json, context);
+        tracks_free_json_value(&mut json);
+
+        tracks_animate_track_vector3(
+            manager,
+            track,
+            PropertyNames::Position as u32,
+            definition,
+            0.0,
+            1.0,
+            Functions::EaseLinear.into(),
+            0,
+        );
+        tracks_assign_path_animation_vector3(
+            manager,
+            track,
+            PathPropertyNames::Position as u32,
+            definition,
+            0.0,
+            1.0,
+            Functions::EaseLinear.into(),
+        );
+        // the animations hold their own reference
+        tracks_free_vector3_point_definition(definition);
+        tracks_coroutine_manager_tick(manager, 0.5, registry, context);
+
+        tracks_free_coroutine_manager(manager);
+        tracks_free_track_registry(registry);
+        tracks_free_base_provider_context(context);
+    });
+}
@@ -0,0 +1,93 @@
+use glam::Vec3;
+use serde_json::json;
+use tracks_rs::{
+    ffi::{WrapVec3, tracks_interpolate_vector3, tracks_interpolate_vector3_batch},
+    point_definition::vector3_point_definition::Vector3PointDefinition,
+    values::base_provider_context::BaseProviderContext,
+};
+
+fn definition(context: &mut BaseProviderContext) -> Vector3PointDefinition {
+    Vector3PointDefinition::try_new(
+        json!([
+            [0, 0, 0, 0],
+            [1, 2, 3, 0.25, "easeInQuad"],
+            [1, 2, 3, 0.25],
+            [-1, 0, 5, 0.5, "splineCatmullRom"],
+            [4, 4, 4, 1, "easeOutBounce"]
+        ]),
+        context,
+    )
+    .unwrap()
+}
+
+fn assert_matches_scalar(times: &[f32]) {
+    let mut context = BaseProviderContext::new();
+    let definition = definition(&mut context);
+
+    let mut out = vec![Vec3::ZERO; times.len()];
+    assert_eq!(
+        definition.interpolate_many(times, &mut out, &context),
+        times.len()
+    );
+
+    for (time, value) in times.iter().zip(&out) {
+        assert_eq!(
+            *value,
+            definition.interpolate(*time, &context).0,
+            "at {time}"
+        );
+    }
+}
+
+#[test]
+fn sorted_times() {
+    let times: Vec<f32> = (-10..=110).map(|i| i as f32 / 100.0).collect();
+    assert_matches_scalar(&times);
+}
+
+#[test]
+fn unsorted_times() {
+    assert_matches_scalar(&[
+        0.9, 0.1, 0.25, 0.3, 0.2, 2.0, -1.0, 0.5, 0.49, 0.75, 0.0, 1.0,
+    ]);
+}
+
+#[test]
+fn mismatched_lengths_fill_the_shorter() {
+    let mut context = BaseProviderContext::new();
+    let definition = definition(&mut context);
+    let times = [0.0, 0.5, 1.0];
+
+    let mut short = [Vec3::NAN; 2];
+    assert_eq!(definition.interpolate_many(&times, &mut short, &context), 2);
+    assert_eq!(short[1], definition.interpolate(0.5, &context).0);
+
+    let mut long = [Vec3::NAN; 4];
+    assert_eq!(definition.interpolate_many(&times, &mut long, &context), 3);
+    assert_eq!(long[2], definition.interpolate(1.0, &context).0);
+    assert!(long[3].is_nan());
+}
+
+#[test]
+fn ffi_batch_matches_scalar() {
+    let mut context = BaseProviderContext::new();
+    let definition = definition(&mut context);
+
+    // more than one internal chunk
+    let times: Vec<f32> = (0..200).map(|i| ((i * 37) % 200) as f32 / 199.0).collect();
+    let mut out = vec![WrapVec3::from(Vec3::ZERO); times.len()];
+    unsafe {
+        tracks_interpolate_vector3_batch(
+            &definition,
+            times.as_ptr(),
+            out.as_mut_ptr(),
+            times.len(),
+            &mut context,
+        );
+    }
+
+    for (time, batched) in times.iter().zip(&out) {
+        let scalar = unsafe { tracks_interpolate_vector3(&definition, *time, &mut context) };
+        assert_eq!(batched, &scalar.value, "at {time}");
+    }
+}
@@ -0,0 +1,167 @@
+use std::{ffi::CStr, sync::Arc};
+
+use serde_json::json;
+use tracks_rs::{
+    error::ParseError,
+    ffi::*,
+    point_definition::{
+        float_point_definition::FloatPointDefinition, library::PointDefinitionLibrary,
+    },
+    values::{base_provider_context::BaseProviderContext, value::BaseValueType},
+};
+
+fn library(context: &mut BaseProviderContext) -> PointDefinitionLibrary {
+    let beatmap = json!({
+        "version": "3.2.0",
+        "customData": {
+            "customEvents": [
+                { "b": 0, "t": "AnimateTrack", "d": { "track": "a", "dissolve": "fadeIn" } }
+            ],
+            "pointDefinitions": {
+                "fadeIn": [[0, 0], [1, 1]],
+                "move": [[0, 0, 0, 0], [1, 2, 3, 1]]
+            }
+        }
+    });
+    PointDefinitionLibrary::try_from_beatmap(&beatmap, context).unwrap()
+}
+
+#[test]
+fn resolves_names() {
+    let mut context = BaseProviderContext::new();
+    let mut library = library(&mut context);
+    assert_eq!(library.len(), 2);
+
+    let fade = library
+        .try_resolve(&json!("fadeIn"), BaseValueType::Float, &mut context)
+        .unwrap()
+        .unwrap();
+    let named = library.get("fadeIn").unwrap();
+    assert!(Arc::ptr_eq(
+        fade.as_float().unwrap(),
+        named.as_float().unwrap()
+    ));
+    assert_eq!(fade.interpolate(0.5, &context).0.as_slice(), &[0.5]);
+}
+
+#[test]
+fn inline_definitions_are_parsed_once() {
+    let mut context = BaseProviderContext::new();
+    let mut library = PointDefinitionLibrary::new();
+    let points = json!([[0, 0], [1, 1]]);
+
+    let first = library
+        .resolve(&points, BaseValueType::Float, &mut context)
+        .unwrap();
+    let second = library
+        .resolve(&points, BaseValueType::Float, &mut context)
+        .unwrap();
+    assert!(Arc::ptr_eq(
+        first.as_float().unwrap(),
+        second.as_float().unwrap()
+    ));
+
+    // the same JSON as another type is another definition
+    let points = json!([[0, 0, 0, 0], [1, 1, 1, 1]]);
+    let vector3 = library
+        .resolve(&points, BaseValueType::Vector3, &mut context)
+        .unwrap();
+    let quaternion = library
+        .resolve(&points, BaseValueType::Quaternion, &mut context)
+        .unwrap();
+    assert_eq!(vector3.get_type(), BaseValueType::Vector3);
+    assert_eq!(quaternion.get_type(), BaseValueType::Quaternion);
+    // inline definitions are not named
+    assert!(library.is_empty());
+}
+
+#[test]
+fn reports_unresolved_values() {
+    let mut context = BaseProviderContext::new();
+    let mut library = library(&mut context);
+
+    let missing = library.try_resolve(&json!("missing"), BaseValueType::Float, &mut context);
+    assert!(matches!(
+        missing,
+        Err(ParseError::UnknownPointDefinition { ref name, .. }) if name == "missing"
+    ));
+
+    let mismatch = library.try_resolve(&json!("move"), BaseValueType::Float, &mut context);
+    assert!(matches!(
+        mismatch,
+        Err(ParseError::ValueTypeMismatch {
+            expected: BaseValueType::Float,
+            found: BaseValueType::Vector3,
+            ..
+        })
+    ));
+    assert!(
+        library
+            .resolve(&json!("move"), BaseValueType::Float, &mut context)
+            .is_none()
+    );
+
+    let cleared = library.try_resolve(&json!(null), BaseValueType::Float, &mut context);
+    assert!(matches!(cleared, Ok(None)));
+}
+
+#[test]
+fn inserted_definitions_are_named() {
+    let mut context = BaseProviderContext::new();
+    let mut library = PointDefinitionLibrary::new();
+    let definition = FloatPointDefinition::try_new(json!([[0, 0], [2, 1]]), &mut context).unwrap();
+
+    assert!(library.insert("grow", definition).is_none());
+    assert_eq!(library.names().collect::<Vec<_>>(), ["grow"]);
+    assert!(
+        library
+            .try_resolve(&json!("grow"), BaseValueType::Float, &mut context)
+            .is_ok()
+    );
+}
+
+#[test]
+fn ffi_lookup() {
+    unsafe {
+        let context = tracks_make_base_provider_context();
+        let beatmap =
+            r#"{"customData": {"pointDefinitions": {"fade": [[0, 0], [1, 1]], "bad": 1}}}"#;
+        let library = tracks_make_point_definition_library_from_beatmap(
+            beatmap.as_ptr().cast(),
+            beatmap.len(),
+            context,
+        );
+        // the malformed definition is left out
+        assert_eq!(tracks_library_count(library), 1);
+
+        let mut ty = BaseValueType::Vector3;
+        assert!(tracks_library_get_type(library, c"fade".as_ptr(), &mut ty));
+        assert_eq!(ty, BaseValueType::Float);
+        assert!(!tracks_library_get_type(library, c"bad".as_ptr(), &mut ty));
+
+        let fade = tracks_library_get_float(library, c"fade".as_ptr());
+        assert!(!fade.is_null());
+        assert_eq!(tracks_interpolate_float(fade, 0.25, context).value, 0.25);
+        tracks_free_float_point_definition(fade);
+
+        assert!(tracks_library_get_vector3(library, c"fade".as_ptr()).is_null());
+        let error = CStr::from_ptr(tracks_last_error());
+        assert!(error.to_str().unwrap().contains("expected Vector3"));
+
+        let null = FFIJsonValue {
+            value_type: JsonValueType::Null as u32,
+            data: JsonValueData { number_value: 0.0 },
+        };
+        assert!(tracks_library_resolve_float(library, &null, context).is_null());
+        assert!(tracks_last_error().is_null());
+
+        let mut name = tracks_create_json_string(c"fade".as_ptr());
+        let resolved = tracks_library_resolve_float(library, &name, context);
+        tracks_free_json_value(&mut name);
+        assert!(!resolved.is_null());
+        tracks_free_float_point_definition(resolved);
+
+        tracks_free_point_definition_library(library);
+        tracks_free_base_provider_context(context);
+    }
+}
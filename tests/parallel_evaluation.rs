@@ -0,0 +1,118 @@
+use std::thread;
+
+use glam::{Quat, Vec3, Vec4};
+use serde_json::json;
+use tracks_rs::{
+    point_definition::{
+        base_point_definition::BasePointDefinition, float_point_definition::FloatPointDefinition,
+        quaternion_point_definition::QuaternionPointDefinition,
+        vector3_point_definition::Vector3PointDefinition,
+        vector4_point_definition::Vector4PointDefinition,
+    },
+    values::{base_provider_context::BaseProviderContext, value::BaseValue},
+};
+
+const THREADS: usize = 8;
+const SAMPLES: usize = 256;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn evaluation_core_is_send_sync() {
+    assert_send_sync::<BaseProviderContext>();
+    assert_send_sync::<FloatPointDefinition>();
+    assert_send_sync::<Vector3PointDefinition>();
+    assert_send_sync::<Vector4PointDefinition>();
+    assert_send_sync::<QuaternionPointDefinition>();
+    assert_send_sync::<BasePointDefinition>();
+}
+
+fn definitions(context: &mut BaseProviderContext) -> Vec<BasePointDefinition> {
+    vec![
+        FloatPointDefinition::try_new(
+            json!([
+                [0, 0],
+                ["baseCombo.s2", 0.5, "easeInOutSine"],
+                [1, 1, "splineCatmullRom"]
+            ]),
+            context,
+        )
+        .unwrap()
+        .into(),
+        Vector3PointDefinition::try_new(
+            json!([
+                [0, 0, 0, 0],
+                ["baseHeadPosition.s5", [0, 1, 0, "opAdd"], 0.5],
+                [1, 2, 3, 1, "easeOutBounce"]
+            ]),
+            context,
+        )
+        .unwrap()
+        .into(),
+        Vector4PointDefinition::try_new(
+            json!([[0, 0, 0, 1, 0], ["baseNote0Color.s3", 1, "lerpHSV"]]),
+            context,
+        )
+        .unwrap()
+        .into(),
+        QuaternionPointDefinition::try_new(
+            json!([[0, 0, 0, 0], ["baseHeadRotation.s4", 0.5], [0, 90, 0, 1]]),
+            context,
+        )
+        .unwrap()
+        .into(),
+    ]
+}
+
+/// Every component of every sample, as bits so results must match exactly
+fn evaluate(definitions: &[BasePointDefinition], context: &BaseProviderContext) -> Vec<u32> {
+    definitions
+        .iter()
+        .flat_map(|definition| {
+            (0..SAMPLES).flat_map(move |i| {
+                let time = i as f32 / (SAMPLES - 1) as f32;
+                let value = definition.interpolate(time, context).0;
+                value
+                    .as_slice()
+                    .iter()
+                    .map(|v| v.to_bits())
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn parallel_evaluation_matches_serial() {
+    let mut context = BaseProviderContext::new();
+    let definitions = definitions(&mut context);
+
+    for frame in 0..10 {
+        let t = frame as f32 * 0.1;
+        context
+            .set_values("baseCombo", BaseValue::Float(t * 10.0))
+            .unwrap();
+        context
+            .set_values("baseHeadPosition", Vec3::new(t, 1.0 - t, t * 2.0).into())
+            .unwrap();
+        context
+            .set_values("baseNote0Color", Vec4::new(1.0, t, 0.0, 1.0).into())
+            .unwrap();
+        context
+            .set_values("baseHeadRotation", Quat::from_rotation_y(t * 3.0).into())
+            .unwrap();
+        context.tick(1.0 / 60.0);
+
+        let expected = evaluate(&definitions, &context);
+
+        thread::scope(|scope| {
+            let handles = (0..THREADS)
+                .map(|_| scope.spawn(|| evaluate(&definitions, &context)))
+                .collect::<Vec<_>>();
+
+            for handle in handles {
+                assert_eq!(expected, handle.join().unwrap());
+            }
+        });
+    }
+}
@@ -0,0 +1,116 @@
+use serde_json::json;
+use tracks_rs::{
+    error::{JsonPath, ParseError},
+    point_definition::vector3_point_definition::Vector3PointDefinition,
+    values::base_provider_context::BaseProviderContext,
+};
+
+fn parse(value: serde_json::Value) -> ParseError {
+    let mut context = BaseProviderContext::new();
+    Vector3PointDefinition::try_new(value, &mut context)
+        .err()
+        .unwrap()
+}
+
+#[test]
+fn unknown_base_provider() {
+    let error = parse(json!([[0, 0, 0, 0], ["baseNope", 1]]));
+    assert_eq!(
+        error,
+        ParseError::UnknownBaseProvider {
+            path: JsonPath::root().index(1).index(0),
+            name: "baseNope".to_owned(),
+        }
+    );
+}
+
+#[test]
+fn bad_suffix() {
+    for (base, suffix) in [
+        ("baseHeadPosition.q", "q"),
+        ("baseHeadPosition.w", "w"),
+        ("baseHeadPosition.sx", "sx"),
+        ("baseHeadPosition.", ""),
+    ] {
+        let error = parse(json!([[base, 0]]));
+        assert_eq!(
+            error,
+            ParseError::InvalidBaseSuffix {
+                path: JsonPath::root().index(0).index(0),
+                name: base.to_owned(),
+                suffix: suffix.to_owned(),
+            }
+        );
+    }
+}
+
+#[test]
+fn bad_modifier() {
+    let error = parse(json!([[0, 0, 0, 0], [1, 1, 1, 1, [1, 1, 1, "opNope"]]]));
+    assert_eq!(
+        error,
+        ParseError::UnknownOperation {
+            path: JsonPath::root().index(1).index(4).index(3),
+            operation: "opNope".to_owned(),
+        }
+    );
+
+    let error = parse(json!([[0, 0, 0, 0, [1, 1, 1]]]));
+    assert_eq!(
+        error,
+        ParseError::MissingOperation {
+            path: JsonPath::root().index(0).index(4),
+        }
+    );
+}
+
+#[test]
+fn bad_easing() {
+    for easing in [
+        "easeNope",
+        "cubic-bezier(1.5, 0, 0, 1)",
+        "steps(0)",
+        "steps",
+    ] {
+        let error = parse(json!([
+            [0, 0, 0, 0],
+            [1, 1, 1, 1, "splineCatmullRom", easing]
+        ]));
+        assert_eq!(
+            error,
+            ParseError::InvalidEasing {
+                path: JsonPath::root().index(1).index(5),
+                easing: easing.to_owned(),
+            }
+        );
+    }
+
+    // a later invalid easing is rejected too, not only the one in use
+    let error = parse(json!([[0, 0, 0, 0, "easeInQuad", "easeNope"]]));
+    assert_eq!(error.path(), &JsonPath::root().index(0).index(5));
+}
+
+#[test]
+fn wrong_value_count() {
+    let error = parse(json!([[0, 0, 0, 0], [1, 1, 1]]));
+    assert_eq!(
+        error,
+        ParseError::WrongArity {
+            path: JsonPath::root().index(1),
+            expected: 4,
+            found: 3,
+        }
+    );
+}
+
+#[test]
+fn lenient_wrappers_do_not_panic() {
+    let mut context = BaseProviderContext::new();
+    let definition = Vector3PointDefinition::new(json!([[0, 0, 0, 0]]), &mut context);
+    assert!(
+        definition
+            .deserialize_modifier(&json!([1, 1, 1, "opNope"]), &mut context)
+            .is_none()
+    );
+    assert!(tracks_rs::values::deserialize_values(&[&json!("baseNope")], &mut context).is_none());
+}
@@ -0,0 +1,155 @@
+use serde_json::json;
+use tracks_rs::{
+    animation::coroutine_manager::CoroutineManager,
+    easings::functions::Functions,
+    error::PropertyError,
+    point_definition::{
+        base_point_definition::BasePointDefinition, float_point_definition::FloatPointDefinition,
+    },
+    tracks::{path_property::PathPropertyNames, track_registry::TrackRegistry},
+    values::{base_provider_context::BaseProviderContext, value::BaseValueType},
+};
+
+fn definition(points: serde_json::Value, context: &mut BaseProviderContext) -> BasePointDefinition {
+    FloatPointDefinition::try_new(points, context)
+        .unwrap()
+        .into()
+}
+
+fn sample(registry: &TrackRegistry, time: f32, context: &BaseProviderContext) -> Option<f32> {
+    registry
+        .get_track_by_name("a")
+        .unwrap()
+        .get_path_property(PathPropertyNames::Dissolve, time, context)
+        .and_then(|v| v.as_float())
+}
+
+#[test]
+fn new_paths_blend_in_from_the_previous_one() {
+    let mut context = BaseProviderContext::new();
+    let mut registry = TrackRegistry::new();
+    let mut manager = CoroutineManager::new();
+    let track = registry.add_track("a");
+
+    let low = definition(json!([[0, 0], [1, 1]]), &mut context);
+    let high = definition(json!([[1, 0], [3, 1]]), &mut context);
+
+    manager
+        .start_assign_path_animation(
+            track,
+            PathPropertyNames::Dissolve,
+            Some(low),
+            0.0,
+            0.0,
+            Functions::EaseLinear,
+        )
+        .unwrap();
+    manager.tick(0.0, &mut registry, &context);
+    assert!(manager.is_empty());
+    assert_eq!(sample(&registry, 0.5, &context), Some(0.5));
+
+    manager
+        .start_assign_path_animation(
+            track,
+            PathPropertyNames::Dissolve,
+            Some(high),
+            1.0,
+            2.0,
+            Functions::EaseLinear,
+        )
+        .unwrap();
+    manager.tick(2.0, &mut registry, &context);
+    // halfway between 0.5 on the old path and 2 on the new one
+    assert_eq!(sample(&registry, 0.5, &context), Some(1.25));
+
+    manager.tick(3.0, &mut registry, &context);
+    assert!(manager.is_empty());
+    assert_eq!(sample(&registry, 0.5, &context), Some(2.0));
+}
+
+#[test]
+fn null_paths_fall_back_to_the_previous_one_until_finished() {
+    let mut context = BaseProviderContext::new();
+    let mut registry = TrackRegistry::new();
+    let mut manager = CoroutineManager::new();
+    let track = registry.add_track("a");
+
+    let low = definition(json!([[0, 0], [1, 1]]), &mut context);
+    manager
+        .start_assign_path_animation(
+            track,
+            PathPropertyNames::Dissolve,
+            Some(low),
+            0.0,
+            0.0,
+            Functions::EaseLinear,
+        )
+        .unwrap();
+    manager.tick(0.0, &mut registry, &context);
+
+    manager
+        .start_assign_path_animation(
+            track,
+            PathPropertyNames::Dissolve,
+            None,
+            0.0,
+            1.0,
+            Functions::EaseLinear,
+        )
+        .unwrap();
+    manager.tick(0.5, &mut registry, &context);
+    assert_eq!(sample(&registry, 0.25, &context), Some(0.25));
+
+    manager.tick(1.0, &mut registry, &context);
+    assert_eq!(sample(&registry, 0.25, &context), None);
+}
+
+#[test]
+fn tracks_debug_print_their_paths() {
+    let mut context = BaseProviderContext::new();
+    let mut registry = TrackRegistry::new();
+    let mut manager = CoroutineManager::new();
+    let track = registry.add_track("a");
+
+    let low = definition(json!([[0, 0], [1, 1]]), &mut context);
+    manager
+        .start_assign_path_animation(
+            track,
+            PathPropertyNames::Dissolve,
+            Some(low),
+            0.0,
+            0.0,
+            Functions::EaseLinear,
+        )
+        .unwrap();
+    manager.tick(0.0, &mut registry, &context);
+
+    let debug = format!("{:?}", registry.get_track(track).unwrap());
+    assert!(debug.contains("point: Some(Float)"), "{debug}");
+}
+
+#[test]
+fn mismatched_value_types_are_not_started() {
+    let mut context = BaseProviderContext::new();
+    let mut registry = TrackRegistry::new();
+    let mut manager = CoroutineManager::new();
+    let track = registry.add_track("a");
+
+    let low = definition(json!([[0, 0], [1, 1]]), &mut context);
+    let started = manager.start_assign_path_animation(
+        track,
+        PathPropertyNames::Position,
+        Some(low),
+        0.0,
+        1.0,
+        Functions::EaseLinear,
+    );
+    assert_eq!(
+        started,
+        Err(PropertyError::TypeMismatch {
+            expected: BaseValueType::Vector3,
+            found: BaseValueType::Float,
+        })
+    );
+    assert!(manager.is_empty());
+}
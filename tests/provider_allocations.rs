@@ -0,0 +1,90 @@
+//! Reads providers under an allocator that counts allocations.
+//!
+//! Everything runs in one test so no other thread allocates while counting.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use glam::{Quat, Vec3};
+use serde_json::json;
+use tracks_rs::{
+    point_definition::{
+        quaternion_point_definition::QuaternionPointDefinition,
+        vector3_point_definition::Vector3PointDefinition,
+    },
+    values::{AbstractValueProvider, base_provider_context::BaseProviderContext},
+};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Asserts `f` allocates nothing
+fn assert_no_allocations(name: &str, f: impl FnOnce()) {
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    f();
+    let allocated = ALLOCATIONS.load(Ordering::SeqCst) - before;
+    assert_eq!(allocated, 0, "{name} made {allocated} allocations");
+}
+
+#[test]
+fn reading_providers_does_not_allocate() {
+    let mut context = BaseProviderContext::new();
+    let bases = [
+        "baseHeadRotation",
+        "baseHeadRotation.s2",
+        "baseHeadPosition.s2",
+        "baseHeadPosition.xz.s2",
+        "baseHeadPosition.yz",
+    ];
+    let providers: Vec<_> = bases
+        .iter()
+        .map(|base| context.try_get_value_provider(base).unwrap())
+        .collect();
+    let rotation =
+        QuaternionPointDefinition::try_new(json!(["baseHeadRotation.s2"]), &mut context).unwrap();
+    let position =
+        Vector3PointDefinition::try_new(json!(["baseHeadPosition.s2"]), &mut context).unwrap();
+
+    let read = |context: &BaseProviderContext| {
+        for provider in &providers {
+            std::hint::black_box(provider.values(context));
+        }
+        std::hint::black_box(rotation.interpolate(0.5, context));
+        std::hint::black_box(position.interpolate(0.5, context));
+    };
+
+    assert_no_allocations("reading before the first tick", || read(&context));
+
+    context
+        .set_values("baseHeadRotation", Quat::from_rotation_y(1.0).into())
+        .unwrap();
+    context
+        .set_values("baseHeadPosition", Vec3::new(1.0, 2.0, 3.0).into())
+        .unwrap();
+    context.tick(0.1);
+
+    assert_no_allocations("reading after a tick", || read(&context));
+    assert_eq!(providers[0].values(&context)[1], 1f32.to_degrees());
+}
@@ -0,0 +1,94 @@
+use glam::Quat;
+use serde_json::json;
+use tracks_rs::{
+    modifiers::quaternion_modifier::TRACKS_EULER_ROT,
+    point_definition::quaternion_point_definition::QuaternionPointDefinition,
+    values::base_provider_context::BaseProviderContext,
+};
+
+fn yaw(degrees: f32) -> Quat {
+    Quat::from_euler(TRACKS_EULER_ROT, 0.0, degrees.to_radians(), 0.0)
+}
+
+fn head_rotation(context: &mut BaseProviderContext) -> QuaternionPointDefinition {
+    QuaternionPointDefinition::try_new(json!(["baseHeadRotation.s5"]), context).unwrap()
+}
+
+fn assert_rotation(actual: Quat, expected: Quat) {
+    let angle = actual.angle_between(expected).to_degrees();
+    assert!(
+        angle < 0.1,
+        "expected {expected:?}, got {actual:?} ({angle}° apart)"
+    );
+}
+
+#[test]
+fn starts_at_identity() {
+    let mut context = BaseProviderContext::new();
+    let definition = head_rotation(&mut context);
+    context
+        .set_values("baseHeadRotation", yaw(90.0).into())
+        .unwrap();
+
+    assert_rotation(definition.interpolate(0.0, &context).0, Quat::IDENTITY);
+}
+
+#[test]
+fn follows_live_quaternion() {
+    let mut context = BaseProviderContext::new();
+    let definition = head_rotation(&mut context);
+
+    context
+        .set_values("baseHeadRotation", yaw(90.0).into())
+        .unwrap();
+    // 0.1s at a multiplier of 5 covers half the distance
+    context.tick(0.1);
+    assert_rotation(definition.interpolate(0.0, &context).0, yaw(45.0));
+
+    // the source is read again on every tick
+    context
+        .set_values("baseHeadRotation", yaw(-90.0).into())
+        .unwrap();
+    context.tick(1.0);
+    assert_rotation(definition.interpolate(0.0, &context).0, yaw(-90.0));
+}
+
+#[test]
+fn takes_short_way_across_180() {
+    let mut context = BaseProviderContext::new();
+    let definition = head_rotation(&mut context);
+
+    context
+        .set_values("baseHeadRotation", yaw(170.0).into())
+        .unwrap();
+    context.tick(1.0);
+    assert_rotation(definition.interpolate(0.0, &context).0, yaw(170.0));
+
+    // -170° is 20° away, lerping euler angles would swing back through 0°
+    context
+        .set_values("baseHeadRotation", yaw(-170.0).into())
+        .unwrap();
+    context.tick(0.1);
+    assert_rotation(definition.interpolate(0.0, &context).0, yaw(180.0));
+
+    context.tick(1.0);
+    assert_rotation(definition.interpolate(0.0, &context).0, yaw(-170.0));
+}
+
+#[test]
+fn takes_short_way_with_negated_quaternion() {
+    let mut context = BaseProviderContext::new();
+    let definition = head_rotation(&mut context);
+
+    context
+        .set_values("baseHeadRotation", yaw(10.0).into())
+        .unwrap();
+    context.tick(1.0);
+
+    // -q is the same rotation, so there is nothing to smooth
+    context
+        .set_values("baseHeadRotation", (-yaw(10.0)).into())
+        .unwrap();
+    context.tick(0.1);
+    assert_rotation(definition.interpolate(0.0, &context).0, yaw(10.0));
+}
@@ -0,0 +1,82 @@
+use glam::Vec3;
+use serde_json::json;
+use tracks_rs::{
+    point_definition::{
+        beatmap::infer_value_type, vector3_point_definition::Vector3PointDefinition,
+    },
+    values::{
+        AbstractValueProvider, base_provider_context::BaseProviderContext, value::BaseValueType,
+    },
+};
+
+#[test]
+fn reparsing_reuses_smoothers() {
+    let mut context = BaseProviderContext::new();
+    let definition = json!([["baseHeadPosition.s2", 0], ["baseHeadPosition.xz.s2", 0, 1]]);
+
+    for _ in 0..100 {
+        let parsed = Vector3PointDefinition::try_new(definition.clone(), &mut context).unwrap();
+        drop(parsed);
+    }
+    assert_eq!(context.smoother_count(), 2);
+
+    // the same source at another rate is smoothed separately
+    context
+        .try_get_value_provider("baseHeadPosition.s3")
+        .unwrap();
+    context
+        .try_get_value_provider("baseHeadPosition.s2_0")
+        .unwrap();
+    assert_eq!(context.smoother_count(), 3);
+}
+
+#[test]
+fn shared_smoothers_follow_together() {
+    let mut context = BaseProviderContext::new();
+    let first = context
+        .try_get_value_provider("baseHeadPosition.s2")
+        .unwrap();
+    context
+        .set_values("baseHeadPosition", Vec3::new(1.0, 2.0, 3.0).into())
+        .unwrap();
+    context.tick(0.25);
+
+    // parsed after the tick, still reads the shared progress
+    let second = context
+        .try_get_value_provider("baseHeadPosition.s2")
+        .unwrap();
+    assert_eq!(first.values(&context), second.values(&context));
+    assert_eq!(first.values(&context)[..], [0.5, 1.0, 1.5]);
+}
+
+#[test]
+fn failed_parses_register_nothing() {
+    let mut context = BaseProviderContext::new();
+    assert!(
+        context
+            .try_get_value_provider("baseHeadPosition.s2.q")
+            .is_err()
+    );
+    assert!(
+        Vector3PointDefinition::try_new(
+            json!([["baseHeadPosition.s2", 0], ["baseNope.s2", 1]]),
+            &mut context
+        )
+        .is_err()
+    );
+    assert_eq!(context.smoother_count(), 1);
+}
+
+#[test]
+fn counting_values_registers_nothing() {
+    let context = BaseProviderContext::new();
+    assert_eq!(context.value_count("baseHeadPosition.s2"), Ok(3));
+    assert_eq!(context.value_count("baseNote0Color.s2.rg.s1"), Ok(2));
+    assert_eq!(context.value_count("baseHeadRotation.s1"), Ok(3));
+    assert!(context.value_count("baseHeadPosition.s2.w").is_err());
+    assert_eq!(
+        infer_value_type(&json!([["baseHeadPosition.s2", 0]]), &context),
+        Some(BaseValueType::Vector3)
+    );
+    assert_eq!(context.smoother_count(), 0);
+}
@@ -0,0 +1,118 @@
+use glam::{Quat, Vec3, Vec4};
+use serde_json::{Value, json};
+use tracks_rs::{
+    point_definition::{PointDefinition, TrackValue},
+    values::{base_provider_context::BaseProviderContext, value::BaseValue},
+};
+
+const SAMPLES: usize = 200;
+
+fn context() -> BaseProviderContext {
+    let mut context = BaseProviderContext::new();
+    context
+        .set_values("baseHeadPosition", Vec3::new(1.0, 2.0, 3.0).into())
+        .unwrap();
+    context
+        .set_values(
+            "baseNote0Color",
+            BaseValue::from(Vec4::new(0.2, 0.4, 0.6, 1.0)),
+        )
+        .unwrap();
+    context
+        .set_values("baseHeadRotation", Quat::from_rotation_y(1.0).into())
+        .unwrap();
+    context.set_values("baseCombo", 3.0.into()).unwrap();
+    context
+}
+
+/// Parses `json`, writes it back and parses that again, both must interpolate the same
+fn assert_round_trips<T: TrackValue + PartialEq + std::fmt::Debug>(json: Value) -> Value {
+    let mut context = context();
+    let parsed = PointDefinition::<T>::try_new(json, &mut context).unwrap();
+    let written = parsed.to_json(&context);
+    let reparsed = PointDefinition::<T>::try_new(written.clone(), &mut context).unwrap();
+    assert_eq!(parsed.get_count(), reparsed.get_count());
+
+    // let smoothed providers move away from their start
+    for _ in 0..3 {
+        context.tick(0.1);
+    }
+
+    for i in 0..=SAMPLES {
+        let time = i as f32 / SAMPLES as f32 * 1.2 - 0.1;
+        assert_eq!(
+            parsed.interpolate(time, &context),
+            reparsed.interpolate(time, &context),
+            "at {time} for {written}"
+        );
+    }
+    written
+}
+
+#[test]
+fn float() {
+    let written = assert_round_trips::<f32>(json!([
+        [0, 0],
+        [0.1, 0.25, "easeInOutSine"],
+        ["baseCombo", 0.5, [2, "opMul"], [0.5, "opAdd"]],
+        [4, 1, "easeOutBounce"]
+    ]));
+    assert_eq!(written[1], json!([0.1, 0.25, "easeInOutSine"]));
+    assert_eq!(
+        written[2],
+        json!(["baseCombo", 0.5, [2.0, "opMul"], [0.5, "opAdd"]])
+    );
+}
+
+#[test]
+fn vector3() {
+    let written = assert_round_trips::<Vec3>(json!([
+        [0, 0, 0, 0],
+        [1, 2, 3, 0.25, "splineCatmullRom"],
+        [
+            "baseHeadPosition.s2.xz",
+            5,
+            0.5,
+            [[1, 1, 1, "opAdd"], "baseHeadPosition.s0_5", "opMul"]
+        ],
+        [-1, 0, 5, 0.75, "easeInQuad", "splineCatmullRom"],
+        [4, 4, 4, 1]
+    ]));
+    assert_eq!(
+        written[2],
+        json!([
+            "baseHeadPosition.s2.xz",
+            5.0,
+            0.5,
+            ["baseHeadPosition.s0_5", [1.0, 1.0, 1.0, "opAdd"], "opMul"]
+        ])
+    );
+    assert_eq!(
+        written[3],
+        json!([-1.0, 0.0, 5.0, 0.75, "easeInQuad", "splineCatmullRom"])
+    );
+}
+
+#[test]
+fn vector4() {
+    assert_round_trips::<Vec4>(json!([
+        [1, 0, 0, 1, 0, "lerpHSV"],
+        ["baseNote0Color.rgb", 0.5, 0.5],
+        [0, 0, 1, 1, 1, [0.5, 0.5, 0.5, 1, "opMul"]]
+    ]));
+}
+
+#[test]
+fn quaternion() {
+    assert_round_trips::<Quat>(json!([
+        [0, 0, 0, 0],
+        [0, 90, 0, 0.5, [0, 0, 45, "opAdd"]],
+        ["baseHeadRotation.s3", 1, "easeOutCubic"]
+    ]));
+}
+
+#[test]
+fn single_point() {
+    let written = assert_round_trips::<Vec3>(json!([1, 2, 3]));
+    assert_eq!(written, json!([[1.0, 2.0, 3.0, 0.0]]));
+}
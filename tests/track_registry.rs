@@ -0,0 +1,78 @@
+use glam::Vec3;
+use tracks_rs::{
+    error::PropertyError,
+    tracks::{
+        property::PropertyNames,
+        track_registry::{TrackKey, TrackRegistry},
+    },
+    values::value::{BaseValue, BaseValueType},
+};
+
+#[test]
+fn adding_a_name_twice_returns_the_same_track() {
+    let mut registry = TrackRegistry::new();
+    let a = registry.add_track("a");
+    let b = registry.add_track("b");
+
+    assert_ne!(a, b);
+    assert_eq!(registry.add_track("a"), a);
+    assert_eq!(registry.len(), 2);
+}
+
+#[test]
+fn tracks_are_found_by_name_and_key() {
+    let mut registry = TrackRegistry::new();
+    let key = registry.add_track("notes");
+
+    assert_eq!(registry.get_track_key("notes"), Some(key));
+    assert_eq!(registry.get_track(key).unwrap().name, "notes");
+    assert_eq!(registry.get_track_by_name("notes").unwrap().name, "notes");
+
+    registry
+        .get_track_by_name_mut("notes")
+        .unwrap()
+        .set_property(PropertyNames::Dissolve, Some(0.5.into()))
+        .unwrap();
+    let dissolve = registry
+        .get_track(key)
+        .unwrap()
+        .get_property(PropertyNames::Dissolve);
+    assert_eq!(dissolve.and_then(|v| v.as_float()), Some(0.5));
+}
+
+#[test]
+fn unknown_names_and_invalid_keys_find_nothing() {
+    let mut registry = TrackRegistry::new();
+    registry.add_track("a");
+
+    assert!(registry.get_track_key("missing").is_none());
+    assert!(registry.get_track_by_name("missing").is_none());
+    assert!(registry.get_track(TrackKey::INVALID).is_none());
+    assert!(registry.get_track_mut(TrackKey::INVALID).is_none());
+}
+
+#[test]
+fn properties_keep_their_value_type() {
+    let mut registry = TrackRegistry::new();
+    let key = registry.add_track("a");
+    let track = registry.get_track_mut(key).unwrap();
+
+    track
+        .set_property(PropertyNames::Position, Some(Vec3::ONE.into()))
+        .unwrap();
+    // a float cannot be a position, the previous value stays
+    assert_eq!(
+        track.set_property(PropertyNames::Position, Some(BaseValue::Float(2.0))),
+        Err(PropertyError::TypeMismatch {
+            expected: BaseValueType::Vector3,
+            found: BaseValueType::Float,
+        })
+    );
+    let position = track.get_property(PropertyNames::Position);
+    assert_eq!(position.and_then(|v| v.as_vec3()), Some(Vec3::ONE));
+
+    registry.reset();
+    let track = registry.get_track(key).unwrap();
+    assert!(track.get_property(PropertyNames::Position).is_none());
+    assert_eq!(registry.len(), 1);
+}
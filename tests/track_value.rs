@@ -0,0 +1,75 @@
+use serde_json::json;
+use tracks_rs::{
+    point_definition::{PointDefinition, TrackValue},
+    values::base_provider_context::BaseProviderContext,
+};
+
+/// A value type defined outside the crate, a whole number of steps
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Steps(i32);
+
+impl TrackValue for Steps {
+    type Raw = f32;
+    type Velocity = f32;
+    const VALUE_COUNT: usize = 1;
+    const SMOOTH_FLAG: Option<&'static str> = Some("stepHalfway");
+
+    fn raw_from_slice(values: &[f32]) -> f32 {
+        values[0]
+    }
+
+    fn raw_to_slice(raw: f32, out: &mut [f32]) {
+        out[0] = raw;
+    }
+
+    fn from_raw(raw: f32) -> Steps {
+        Steps(raw.round() as i32)
+    }
+
+    fn lerp(a: Steps, _b: Steps, _time: f32) -> Steps {
+        a
+    }
+
+    fn smooth_lerp(_p0: Steps, a: Steps, b: Steps, _p3: Steps, time: f32) -> Steps {
+        if time < 0.5 { a } else { b }
+    }
+
+    fn lerp_velocity(_a: Steps, _b: Steps, _time: f32) -> f32 {
+        0.0
+    }
+}
+
+#[test]
+fn custom_value_types_parse_and_interpolate() {
+    let mut context = BaseProviderContext::new();
+    let definition = PointDefinition::<Steps>::try_new(
+        json!([[0, 0], [2, 0.5], [4, 1, "stepHalfway"]]),
+        &mut context,
+    )
+    .unwrap();
+
+    assert!(definition.get_baked().is_some());
+    assert_eq!(definition.interpolate(0.25, &context), (Steps(0), false));
+    assert_eq!(definition.interpolate(0.6, &context), (Steps(2), false));
+    assert_eq!(definition.interpolate(0.8, &context), (Steps(4), false));
+    assert_eq!(definition.interpolate(1.0, &context), (Steps(4), true));
+}
+
+#[test]
+fn custom_value_types_apply_modifiers() {
+    let mut context = BaseProviderContext::new();
+    let definition = PointDefinition::<Steps>::try_new(
+        json!([[1.4, 0, [2, "opMul"], [0.4, "opAdd"]], [9, 1]]),
+        &mut context,
+    )
+    .unwrap();
+
+    // modifiers apply to the raw value, it is only rounded afterwards
+    assert_eq!(definition.interpolate(0.0, &context), (Steps(3), false));
+}
+
+#[test]
+fn wrong_arity_is_an_error() {
+    let mut context = BaseProviderContext::new();
+    assert!(PointDefinition::<Steps>::try_new(json!([[1, 2, 0]]), &mut context).is_err());
+}
@@ -0,0 +1,201 @@
+use std::ffi::c_char;
+
+use glam::Vec2;
+use serde_json::json;
+use tracks_rs::{
+    ffi::*,
+    point_definition::{
+        beatmap::load_point_definitions, bool_point_definition::BoolPointDefinition,
+        library::PointDefinitionLibrary, vector2_point_definition::Vector2PointDefinition,
+    },
+    values::{base_provider_context::BaseProviderContext, value::BaseValueType},
+};
+
+#[test]
+fn vector2_interpolates_linearly() {
+    let mut context = BaseProviderContext::new();
+    let definition =
+        Vector2PointDefinition::try_new(json!([[0, 0, 0], [2, 4, 1]]), &mut context).unwrap();
+
+    assert_eq!(
+        definition.interpolate(0.5, &context),
+        (Vec2::new(1.0, 2.0), false)
+    );
+    assert_eq!(
+        definition.interpolate(1.0, &context),
+        (Vec2::new(2.0, 4.0), true)
+    );
+}
+
+#[test]
+fn vector2_applies_modifiers() {
+    let mut context = BaseProviderContext::new();
+    let definition =
+        Vector2PointDefinition::try_new(json!([[1, 1, 0, [2, 3, "opMul"]]]), &mut context).unwrap();
+
+    assert_eq!(definition.interpolate(0.0, &context).0, Vec2::new(2.0, 3.0));
+}
+
+#[test]
+fn bool_steps_between_points() {
+    let mut context = BaseProviderContext::new();
+    let definition = BoolPointDefinition::try_new(
+        json!([[false, 0], [true, 0.5, "easeInOutSine"], [0, 1]]),
+        &mut context,
+    )
+    .unwrap();
+
+    assert_eq!(definition.interpolate(0.0, &context), (false, false));
+    assert_eq!(definition.interpolate(0.49, &context), (false, false));
+    assert_eq!(definition.interpolate(0.5, &context), (true, false));
+    assert_eq!(definition.interpolate(0.99, &context), (true, false));
+    assert_eq!(definition.interpolate(1.0, &context), (false, true));
+}
+
+#[test]
+fn bool_single_point_and_numbers() {
+    let mut context = BaseProviderContext::new();
+    let definition = BoolPointDefinition::try_new(json!([0.75]), &mut context).unwrap();
+    assert_eq!(definition.interpolate(0.3, &context), (true, true));
+
+    assert!(BoolPointDefinition::try_new(json!([[true, false, 0]]), &mut context).is_err());
+}
+
+#[test]
+fn ffi_constructors_and_interpolation() {
+    unsafe {
+        let context = tracks_make_base_provider_context();
+
+        let text = "[[0, 0, 0], [2, 4, 1]]";
+        let vector2 = tracks_make_vector2_point_definition_from_str(
+            text.as_ptr() as *const c_char,
+            text.len(),
+            context,
+        );
+        assert!(!vector2.is_null());
+        assert_eq!(tracks_vector2_count(vector2), 2);
+        assert!(!tracks_vector2_has_base_provider(vector2));
+        let result = tracks_interpolate_vector2(vector2, 0.5, context);
+        assert_eq!(result.value, WrapVec2::from(Vec2::new(1.0, 2.0)));
+        assert!(!result.is_last);
+
+        let text = "[[true, 0], [false, 0.5]]";
+        let boolean = tracks_make_bool_point_definition_from_str(
+            text.as_ptr() as *const c_char,
+            text.len(),
+            context,
+        );
+        assert!(!boolean.is_null());
+        let times = [0.0, 0.25, 0.5, 0.75];
+        let mut out = [false; 4];
+        assert!(tracks_interpolate_bool_batch(
+            boolean,
+            times.as_ptr(),
+            out.as_mut_ptr(),
+            times.len(),
+            context,
+        ));
+        assert_eq!(out, [true, true, false, false]);
+        assert!(tracks_interpolate_bool(boolean, 0.75, context).is_last);
+
+        tracks_free_vector2_point_definition(vector2);
+        tracks_free_bool_point_definition(boolean);
+        tracks_free_base_provider_context(context);
+    }
+}
+
+#[test]
+fn ffi_sets_base_providers_by_their_type() {
+    unsafe {
+        let context = tracks_make_base_provider_context();
+        assert!(tracks_register_base_provider(
+            context,
+            c"baseFlag".as_ptr(),
+            BaseValueType::Bool as u32,
+        ));
+        assert!(tracks_register_base_provider(
+            context,
+            c"baseStick".as_ptr(),
+            BaseValueType::Vector2 as u32,
+        ));
+
+        let on = [1.0];
+        assert!(tracks_set_base_provider(
+            context,
+            c"baseFlag".as_ptr(),
+            on.as_ptr(),
+            on.len(),
+        ));
+        let stick = [0.5, -0.5];
+        assert!(tracks_set_base_provider(
+            context,
+            c"baseStick".as_ptr(),
+            stick.as_ptr(),
+            stick.len(),
+        ));
+        let flag = (*context).try_get_values("baseFlag").unwrap();
+        assert_eq!(flag.as_bool(), Some(&true));
+        let stick = (*context).try_get_values("baseStick").unwrap();
+        assert_eq!(stick.as_vec2(), Some(&Vec2::new(0.5, -0.5)));
+
+        let mut id = std::mem::zeroed();
+        assert!(tracks_get_base_provider_id(
+            context,
+            c"baseFlag".as_ptr(),
+            &mut id
+        ));
+        let off = [0.0];
+        assert!(tracks_set_base_provider_by_id(
+            context,
+            id,
+            off.as_ptr(),
+            off.len()
+        ));
+        let flag = (*context).try_get_values("baseFlag").unwrap();
+        assert_eq!(flag.as_bool(), Some(&false));
+
+        // a bool is a single number
+        let pair = [1.0, 0.0];
+        assert!(!tracks_set_base_provider_by_id(
+            context,
+            id,
+            pair.as_ptr(),
+            pair.len()
+        ));
+        tracks_free_base_provider_context(context);
+    }
+}
+
+#[test]
+fn loaded_from_a_beatmap() {
+    let mut context = BaseProviderContext::new();
+    let beatmap = json!({
+        "customData": {
+            "pointDefinitions": {
+                "offset": [[0, 0, 0], [2, 4, 1]],
+                "visible": [[false, 0], [true, 0.5]]
+            }
+        }
+    });
+
+    let definitions = load_point_definitions(&beatmap, &mut context);
+    let offset = &definitions["offset"];
+    assert_eq!(offset.get_type(), BaseValueType::Vector2);
+    assert_eq!(
+        offset.interpolate(0.5, &context).0.as_vec2(),
+        Some(Vec2::new(1.0, 2.0))
+    );
+    let visible = &definitions["visible"];
+    assert_eq!(visible.get_type(), BaseValueType::Bool);
+    assert_eq!(visible.interpolate(0.75, &context).0.as_bool(), Some(true));
+
+    let library = PointDefinitionLibrary::from_beatmap(&beatmap, &mut context);
+    unsafe {
+        let visible = tracks_library_get_bool(&library, c"visible".as_ptr());
+        assert!(!visible.is_null());
+        assert!(!tracks_interpolate_bool(visible, 0.25, &mut context).value);
+        tracks_free_bool_point_definition(visible);
+
+        assert!(tracks_library_get_vector2(&library, c"visible".as_ptr()).is_null());
+    }
+}
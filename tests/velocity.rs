@@ -0,0 +1,191 @@
+use glam::{Quat, Vec3, Vec4};
+use serde_json::json;
+use tracks_rs::{
+    easings::functions::Functions,
+    point_definition::{
+        float_point_definition::FloatPointDefinition,
+        quaternion_point_definition::QuaternionPointDefinition,
+        vector3_point_definition::Vector3PointDefinition,
+        vector4_point_definition::Vector4PointDefinition,
+    },
+    values::base_provider_context::BaseProviderContext,
+};
+
+const STEP: f32 = 1e-3;
+
+/// Times across 0 to 1 that keep clear of the joins of piecewise easings
+fn sample_times() -> impl Iterator<Item = f32> {
+    (0..40).map(|i| (i as f32 + 0.37) / 40.0)
+}
+
+fn assert_close(analytic: f32, numeric: f32, what: &str) {
+    assert!(
+        (analytic - numeric).abs() <= 1e-2 * analytic.abs().max(1.0),
+        "{what}: {analytic} != {numeric}"
+    );
+}
+
+#[test]
+fn easing_derivatives_match_the_slope() {
+    let easings = [
+        Functions::EaseLinear,
+        Functions::EaseInQuad,
+        Functions::EaseOutQuad,
+        Functions::EaseInOutQuad,
+        Functions::EaseInCubic,
+        Functions::EaseOutCubic,
+        Functions::EaseInOutCubic,
+        Functions::EaseInQuart,
+        Functions::EaseOutQuart,
+        Functions::EaseInOutQuart,
+        Functions::EaseInQuint,
+        Functions::EaseOutQuint,
+        Functions::EaseInOutQuint,
+        Functions::EaseInSine,
+        Functions::EaseOutSine,
+        Functions::EaseInOutSine,
+        Functions::EaseInCirc,
+        Functions::EaseOutCirc,
+        Functions::EaseInOutCirc,
+        Functions::EaseInExpo,
+        Functions::EaseOutExpo,
+        Functions::EaseInOutExpo,
+        Functions::EaseInElastic,
+        Functions::EaseOutElastic,
+        Functions::EaseInOutElastic,
+        Functions::EaseInBack,
+        Functions::EaseOutBack,
+        Functions::EaseInOutBack,
+        Functions::EaseInBounce,
+        Functions::EaseOutBounce,
+        Functions::EaseInOutBounce,
+        Functions::cubic_bezier(0.25, 0.1, 0.25, 1.0).unwrap(),
+        Functions::cubic_bezier(0.7, -0.6, 0.3, 1.6).unwrap(),
+    ];
+
+    for easing in easings {
+        for t in sample_times() {
+            let numeric =
+                (easing.interpolate(t + STEP) - easing.interpolate(t - STEP)) / (2.0 * STEP);
+            assert_close(easing.derivative(t), numeric, &format!("{easing} at {t}"));
+        }
+    }
+}
+
+#[test]
+fn steps_have_no_slope() {
+    for easing in [Functions::EaseStep, Functions::steps(3).unwrap()] {
+        assert!(sample_times().all(|t| easing.derivative(t) == 0.0));
+    }
+}
+
+#[test]
+fn linear_segments_scale_by_easing_and_duration() {
+    let mut context = BaseProviderContext::new();
+    let definition = FloatPointDefinition::try_new(
+        json!([[0, 0], [4, 1, "easeInOutSine"], [2, 3, "easeOutBack"]]),
+        &mut context,
+    )
+    .unwrap();
+
+    for t in sample_times().map(|t| t * 3.0) {
+        let (_, velocity, _) = definition.interpolate_with_velocity(t, &context);
+        let value = |t| definition.interpolate_with_velocity(t, &context).0;
+        let numeric = (value(t + STEP) - value(t - STEP)) / (2.0 * STEP);
+        assert_close(velocity, numeric, &format!("at {t}"));
+    }
+
+    // constant before the first and after the last point
+    assert_eq!(
+        definition.interpolate_with_velocity(-1.0, &context),
+        (0.0, 0.0, false)
+    );
+    assert_eq!(
+        definition.interpolate_with_velocity(5.0, &context),
+        (2.0, 0.0, true)
+    );
+}
+
+#[test]
+fn catmull_rom_segments() {
+    let mut context = BaseProviderContext::new();
+    let definition = Vector3PointDefinition::try_new(
+        json!([
+            [0, 0, 0, 0],
+            [1, 2, 0, 0.5, "splineCatmullRom"],
+            [3, 1, 2, 1, "splineCatmullRom", "easeInQuad"],
+            [3, 0, 4, 1.5]
+        ]),
+        &mut context,
+    )
+    .unwrap();
+
+    let value = |t| definition.interpolate_with_velocity(t, &context).0;
+    for t in sample_times().map(|t| t * 1.5) {
+        let (_, velocity, _) = definition.interpolate_with_velocity(t, &context);
+        let numeric: Vec3 = (value(t + STEP) - value(t - STEP)) / (2.0 * STEP);
+        for axis in 0..3 {
+            assert_close(
+                velocity[axis],
+                numeric[axis],
+                &format!("axis {axis} at {t}"),
+            );
+        }
+    }
+}
+
+#[test]
+fn hsv_segments() {
+    let mut context = BaseProviderContext::new();
+    let definition = Vector4PointDefinition::try_new(
+        json!([
+            [0.9, 0.25, 0.1, 1, 0, "lerpHSV"],
+            [0.4, 0.8, 0.2, 0.5, 1, "lerpHSV"],
+            [0.2, 0.6, 0.5, 0, 2]
+        ]),
+        &mut context,
+    )
+    .unwrap();
+
+    // the hues cross into another sixth of the turn away from the sampled times
+    let value = |t| definition.interpolate_with_velocity(t, &context).0;
+    for t in sample_times().map(|t| t * 2.0) {
+        let (_, velocity, _) = definition.interpolate_with_velocity(t, &context);
+        let numeric: Vec4 = (value(t + STEP) - value(t - STEP)) / (2.0 * STEP);
+        for channel in 0..4 {
+            assert_close(
+                velocity[channel],
+                numeric[channel],
+                &format!("channel {channel} at {t}"),
+            );
+        }
+    }
+}
+
+#[test]
+fn rotations_have_an_angular_velocity() {
+    let mut context = BaseProviderContext::new();
+    let definition = QuaternionPointDefinition::try_new(
+        json!([[0, 0, 0, 0], [0, 90, 0, 1, "easeInQuad"], [45, 90, 0, 2]]),
+        &mut context,
+    )
+    .unwrap();
+
+    let value = |t| definition.interpolate_with_velocity(t, &context).0;
+    for t in sample_times().map(|t| t * 2.0) {
+        let (_, velocity, _) = definition.interpolate_with_velocity(t, &context);
+        let turn: Quat = value(t + STEP) * value(t - STEP).inverse();
+        let numeric = turn.to_scaled_axis() / (2.0 * STEP);
+        for axis in 0..3 {
+            assert_close(
+                velocity[axis],
+                numeric[axis],
+                &format!("axis {axis} at {t}"),
+            );
+        }
+    }
+
+    // a quarter turn around y in a second, at the rate easeInQuad reaches at the end
+    let (_, velocity, _) = definition.interpolate_with_velocity(0.999, &context);
+    assert!(velocity.abs_diff_eq(Vec3::Y * std::f32::consts::PI, 1e-2));
+}
@@ -0,0 +1,50 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Warning => f.write_str("warning"),
+            Severity::Error => f.write_str("error"),
+        }
+    }
+}
+
+/// A single problem found in a map, `message` starts with its JSON path
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+}
+
+#[derive(Default)]
+pub struct Diagnostics {
+    pub entries: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+    pub fn warning(&mut self, message: String) {
+        self.entries.push(Diagnostic {
+            severity: Severity::Warning,
+            message,
+        });
+    }
+
+    pub fn error(&mut self, message: String) {
+        self.entries.push(Diagnostic {
+            severity: Severity::Error,
+            message,
+        });
+    }
+
+    pub fn count(&self, severity: Severity) -> usize {
+        self.entries
+            .iter()
+            .filter(|d| d.severity == severity)
+            .count()
+    }
+}
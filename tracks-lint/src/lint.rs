@@ -0,0 +1,245 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use serde_json::Value as JsonValue;
+use tracks_rs::{
+    easings::functions::Functions,
+    error::{JsonPath, ParseError},
+    point_data::PointData,
+    point_definition::{
+        TrackValue,
+        beatmap::{BeatmapVersion, infer_value_type, property_type},
+        bool_point_definition::BoolPointDefinition,
+        float_point_definition::FloatPointDefinition,
+        quaternion_point_definition::QuaternionPointDefinition,
+        vector2_point_definition::Vector2PointDefinition,
+        vector3_point_definition::Vector3PointDefinition,
+        vector4_point_definition::Vector4PointDefinition,
+    },
+    values::{base_provider_context::BaseProviderContext, value::BaseValueType},
+};
+
+use crate::diagnostics::Diagnostics;
+
+const POINT_FLAGS: [&str; 2] = ["splineCatmullRom", "lerpHSV"];
+
+/// Keys of animation events that are not animated properties
+const EVENT_KEYS: [&str; 4] = ["track", "duration", "easing", "repeat"];
+
+pub struct Linter {
+    pub diagnostics: Diagnostics,
+    context: BaseProviderContext,
+    /// Value type of each named point definition, as used by the animations referencing it
+    references: HashMap<String, (BaseValueType, JsonPath)>,
+}
+
+impl Linter {
+    pub fn new() -> Self {
+        Self {
+            diagnostics: Diagnostics::default(),
+            context: BaseProviderContext::new(),
+            references: HashMap::new(),
+        }
+    }
+
+    /// Lint every point definition of a v2 or v3 beatmap
+    pub fn lint_beatmap(&mut self, root: &JsonValue) {
+        let version = BeatmapVersion::detect(root);
+
+        // animations come first so named definitions know their value type
+        for (path, animation) in version.animations(root) {
+            self.lint_animation(animation, &path, version);
+        }
+
+        let mut defined = HashSet::new();
+        for definition in version.point_definitions(root) {
+            match definition {
+                Ok(definition) => {
+                    defined.insert(definition.name);
+                    self.lint_named_definition(
+                        definition.name,
+                        definition.points,
+                        &definition.path,
+                    );
+                }
+                Err(e) => self.diagnostics.error(e.to_string()),
+            }
+        }
+
+        let mut references: Vec<_> = self.references.drain().collect();
+        references.sort_by(|a, b| a.1.1.as_str().cmp(b.1.1.as_str()));
+        for (name, (_, path)) in references {
+            if !defined.contains(name.as_str()) {
+                self.diagnostics
+                    .error(format!("{path}: unknown point definition \"{name}\""));
+            }
+        }
+    }
+
+    /// Lint the properties of an animation event or object animation
+    fn lint_animation(&mut self, data: &JsonValue, path: &JsonPath, version: BeatmapVersion) {
+        let Some(data) = data.as_object() else {
+            return;
+        };
+
+        for (key, value) in data {
+            let property_path = path.key(key);
+            let name = version.strip_key(key);
+
+            if name == "easing" {
+                if let Some(easing) = value.as_str() {
+                    self.lint_easing(easing, &property_path);
+                }
+                continue;
+            }
+            if EVENT_KEYS.contains(&name) {
+                continue;
+            }
+
+            let Some(ty) = property_type(name) else {
+                self.diagnostics
+                    .warning(format!("{property_path}: unknown property \"{key}\""));
+                continue;
+            };
+
+            match value {
+                JsonValue::String(reference) => {
+                    self.references
+                        .entry(reference.clone())
+                        .or_insert((ty, property_path));
+                }
+                JsonValue::Null => {}
+                _ => self.lint_definition(value, ty, &property_path),
+            }
+        }
+    }
+
+    fn lint_named_definition(&mut self, name: &str, value: &JsonValue, path: &JsonPath) {
+        let ty = match self.references.get(name) {
+            Some((ty, _)) => Some(*ty),
+            None => infer_value_type(value, &self.context),
+        };
+
+        match ty {
+            Some(ty) => self.lint_definition(value, ty, path),
+            None => {
+                self.diagnostics.warning(format!(
+                    "{path}: cannot infer the value type of unused point definition \"{name}\""
+                ));
+                self.lint_flags(value, path, true);
+            }
+        }
+    }
+
+    /// Parse a definition as `ty` and check its points
+    pub fn lint_definition(&mut self, value: &JsonValue, ty: BaseValueType, path: &JsonPath) {
+        let context = &mut self.context;
+        let times: Result<Vec<f32>, ParseError> = match ty {
+            BaseValueType::Float => FloatPointDefinition::try_new(value.clone(), context)
+                .map(|d| point_times(d.get_points())),
+            BaseValueType::Vector3 => Vector3PointDefinition::try_new(value.clone(), context)
+                .map(|d| point_times(d.get_points())),
+            BaseValueType::Vector4 => Vector4PointDefinition::try_new(value.clone(), context)
+                .map(|d| point_times(d.get_points())),
+            BaseValueType::Quaternion => QuaternionPointDefinition::try_new(value.clone(), context)
+                .map(|d| point_times(d.get_points())),
+            BaseValueType::Vector2 => Vector2PointDefinition::try_new(value.clone(), context)
+                .map(|d| point_times(d.get_points())),
+            BaseValueType::Bool => BoolPointDefinition::try_new(value.clone(), context)
+                .map(|d| point_times(d.get_points())),
+        };
+
+        match times {
+            Ok(times) => self.lint_point_order(value, &times, path),
+            Err(e) => self.diagnostics.error(e.rebase(path).to_string()),
+        }
+
+        // parsing already rejects invalid easings
+        self.lint_flags(value, path, false);
+    }
+
+    fn lint_point_order(&mut self, value: &JsonValue, times: &[f32], path: &JsonPath) {
+        let Some(raw_points) = value.as_array() else {
+            return;
+        };
+        if !raw_points.first().is_some_and(JsonValue::is_array) {
+            return;
+        }
+
+        // null points are skipped while parsing
+        let indices = raw_points
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| !p.is_null())
+            .map(|(i, _)| i);
+
+        let mut last_time = f32::NEG_INFINITY;
+        for (index, &time) in indices.zip(times) {
+            if time < last_time {
+                self.diagnostics.warning(format!(
+                    "{}: point time {} is before the previous point time {}",
+                    path.index(index),
+                    time,
+                    last_time
+                ));
+            }
+            last_time = last_time.max(time);
+        }
+    }
+
+    /// Warn about flags the parser silently ignores, and invalid easings if `easings` is set
+    fn lint_flags(&mut self, value: &JsonValue, path: &JsonPath, easings: bool) {
+        let Some(raw_points) = value.as_array() else {
+            return;
+        };
+
+        let points: Vec<(JsonPath, &JsonValue)> =
+            if raw_points.first().is_some_and(JsonValue::is_array) {
+                raw_points
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| (path.index(i), p))
+                    .collect()
+            } else {
+                vec![(path.clone(), value)]
+            };
+
+        for (point_path, point) in points {
+            let Some(point) = point.as_array() else {
+                continue;
+            };
+            for (i, flag) in point.iter().enumerate() {
+                let Some(flag) = flag.as_str() else {
+                    continue;
+                };
+                if flag.starts_with("base") {
+                    continue;
+                }
+                let flag_path = point_path.index(i);
+                if Functions::is_easing_flag(flag) {
+                    if easings && Functions::from_str(flag).is_err() {
+                        self.diagnostics
+                            .warning(format!("{flag_path}: invalid easing \"{flag}\""));
+                    }
+                } else if !POINT_FLAGS.contains(&flag) {
+                    self.diagnostics
+                        .warning(format!("{flag_path}: unknown flag \"{flag}\""));
+                }
+            }
+        }
+    }
+
+    fn lint_easing(&mut self, easing: &str, path: &JsonPath) {
+        if Functions::from_str(easing).is_err() {
+            self.diagnostics.warning(format!(
+                "{path}: unknown easing \"{easing}\", easeLinear is used instead"
+            ));
+        }
+    }
+}
+
+fn point_times<T: TrackValue>(points: &[PointData<T>]) -> Vec<f32> {
+    points.iter().map(PointData::get_time).collect()
+}
@@ -0,0 +1,65 @@
+mod diagnostics;
+mod lint;
+
+use std::{env, fs, process::ExitCode};
+
+use diagnostics::Severity;
+use lint::Linter;
+
+const USAGE: &str = "usage: tracks-lint [--strict] <beatmap.dat>...
+
+Checks every point definition of a Beatmap for errors and warnings.
+  --strict  exit with an error code on warnings too";
+
+fn main() -> ExitCode {
+    let mut strict = false;
+    let mut files = Vec::new();
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--strict" => strict = true,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ => files.push(arg),
+        }
+    }
+
+    if files.is_empty() {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    }
+
+    let mut errors = 0;
+    let mut warnings = 0;
+    for file in &files {
+        let beatmap = match fs::read_to_string(file)
+            .map_err(|e| e.to_string())
+            .and_then(|text| serde_json::from_str(&text).map_err(|e| e.to_string()))
+        {
+            Ok(beatmap) => beatmap,
+            Err(e) => {
+                eprintln!("{file}: error: {e}");
+                errors += 1;
+                continue;
+            }
+        };
+
+        let mut linter = Linter::new();
+        linter.lint_beatmap(&beatmap);
+
+        for diagnostic in &linter.diagnostics.entries {
+            println!("{file}: {}: {}", diagnostic.severity, diagnostic.message);
+        }
+        errors += linter.diagnostics.count(Severity::Error);
+        warnings += linter.diagnostics.count(Severity::Warning);
+    }
+
+    println!("{errors} error(s), {warnings} warning(s)");
+
+    if errors > 0 || (strict && warnings > 0) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,154 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use glam::{Quat, Vec3, Vec4};
+use serde_json::Value as JsonValue;
+use tracks_rs::{
+    modifiers::quaternion_modifier::TRACKS_EULER_ROT,
+    point_definition::beatmap,
+    values::{
+        base_provider_context::BaseProviderContext,
+        value::{BaseValue, BaseValueType},
+    },
+};
+
+use crate::options::GraphType;
+
+/// Where the graphed point definition comes from.
+pub enum DefinitionSource {
+    Inline(JsonValue),
+    /// A JSON file holding just the point definition.
+    File(PathBuf),
+    /// A named definition in a beatmap's point definitions.
+    Map {
+        path: PathBuf,
+        name: String,
+    },
+}
+
+impl DefinitionSource {
+    /// The definition's points and, for beatmap definitions, the value type
+    /// the beatmap loads it as.
+    pub fn load(
+        &self,
+        context: &mut BaseProviderContext,
+    ) -> Result<(JsonValue, Option<BaseValueType>), String> {
+        match self {
+            DefinitionSource::Inline(json) => Ok((json.clone(), None)),
+            DefinitionSource::File(path) => Ok((read_json(path)?, None)),
+            DefinitionSource::Map { path, name } => {
+                let map = read_json(path)?;
+                let definition = beatmap::load_point_definitions(&map, context)
+                    .remove(name)
+                    .ok_or_else(|| {
+                        format!("no point definition named `{name}` in `{}`", path.display())
+                    })?;
+                Ok((definition.to_json(context), Some(definition.get_type())))
+            }
+        }
+    }
+
+    /// The file to watch for changes, if the definition lives on disk.
+    pub fn watched_path(&self) -> Option<&Path> {
+        match self {
+            DefinitionSource::Inline(_) => None,
+            DefinitionSource::File(path) | DefinitionSource::Map { path, .. } => Some(path),
+        }
+    }
+}
+
+fn read_json(path: &Path) -> Result<JsonValue, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("failed to read `{}`: {e}", path.display()))?;
+    serde_json::from_str(&contents)
+        .map_err(|e| format!("failed to parse `{}`: {e}", path.display()))
+}
+
+/// Picks the graph type for `ty`, or for the type [`beatmap::infer_value_type`]
+/// guesses when `None`.
+///
+/// Three values referencing a quaternion base provider are graphed as rotations,
+/// bools are graphed as floats. There is no graph for 2D vectors.
+pub fn detect_type(
+    definition: &JsonValue,
+    ty: Option<BaseValueType>,
+    context: &BaseProviderContext,
+) -> Option<GraphType> {
+    let ty = match ty {
+        Some(ty) => ty,
+        None => beatmap::infer_value_type(definition, context)?,
+    };
+
+    match ty {
+        BaseValueType::Float | BaseValueType::Bool => Some(GraphType::Float),
+        BaseValueType::Vector3 if has_quaternion_base(definition, context) => {
+            Some(GraphType::Quaternion)
+        }
+        BaseValueType::Vector3 => Some(GraphType::Vector3),
+        BaseValueType::Vector4 => Some(GraphType::Color),
+        BaseValueType::Quaternion => Some(GraphType::Quaternion),
+        BaseValueType::Vector2 => None,
+    }
+}
+
+/// Whether the definition's first point reads a quaternion base provider
+fn has_quaternion_base(definition: &JsonValue, context: &BaseProviderContext) -> bool {
+    let Some(array) = definition.as_array() else {
+        return false;
+    };
+    let values = match array.first() {
+        Some(JsonValue::Array(point)) => point.as_slice(),
+        _ => array.as_slice(),
+    };
+
+    values
+        .iter()
+        .filter_map(JsonValue::as_str)
+        .filter_map(|s| context.get_id(s.split('.').next().unwrap_or(s)))
+        .any(|id| context.get_type(id) == BaseValueType::Quaternion)
+}
+
+/// Parses a `--base name=x,y,z` argument into a value of the provider's type.
+///
+/// Quaternion providers accept either euler angles in degrees or raw `x,y,z,w` components.
+pub fn parse_base(arg: &str) -> Result<(String, BaseValue), String> {
+    let (name, values) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected `name=value[,value...]`, found `{arg}`"))?;
+
+    let values = values
+        .split(',')
+        .map(|v| {
+            v.trim()
+                .parse::<f32>()
+                .map_err(|_| format!("invalid number `{v}` for `{name}`"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let context = BaseProviderContext::new();
+    let ty = context
+        .get_id(name)
+        .map(|id| context.get_type(id))
+        .ok_or_else(|| format!("unknown base provider `{name}`"))?;
+
+    let value = match (ty, values.as_slice()) {
+        (BaseValueType::Float, &[x]) => BaseValue::Float(x),
+        (BaseValueType::Vector3, &[x, y, z]) => Vec3::new(x, y, z).into(),
+        (BaseValueType::Vector4, &[x, y, z, w]) => Vec4::new(x, y, z, w).into(),
+        (BaseValueType::Quaternion, &[x, y, z]) => Quat::from_euler(
+            TRACKS_EULER_ROT,
+            x.to_radians(),
+            y.to_radians(),
+            z.to_radians(),
+        )
+        .into(),
+        (BaseValueType::Quaternion, &[x, y, z, w]) => Quat::from_xyzw(x, y, z, w).into(),
+        _ => {
+            return Err(format!("`{name}` does not accept {} values", values.len()));
+        }
+    };
+
+    Ok((name.to_string(), value))
+}
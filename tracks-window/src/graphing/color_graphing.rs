@@ -1,18 +1,16 @@
 use std::cell::RefCell;
 
 use glam::vec4;
-use minifb::Window;
 use plotters::{
-    backend::BGRXPixel,
     chart::{ChartBuilder, ChartState},
     coord::{Shift, types::RangedCoordf64},
-    prelude::{BitMapBackend, Cartesian2d, DiscreteRanged, DrawingArea, IntoLinspace, Rectangle},
+    prelude::{Cartesian2d, DiscreteRanged, DrawingArea, DrawingBackend, IntoLinspace, Rectangle},
     style::{BLACK, Color, RED, RGBAColor, WHITE},
 };
-use serde_json::json;
+use serde_json::{Value as JsonValue, json};
 use tracks_rs::{
-    point_definition::{PointDefinition, vector4_point_definition::Vector4PointDefinition},
-    values::base_provider_context::{BaseProviderContext, UpdatableProviderContext},
+    error::ParseError, point_definition::vector4_point_definition::Vector4PointDefinition,
+    values::base_provider_context::BaseProviderContext,
 };
 
 pub struct ColorContext {
@@ -21,30 +19,28 @@ pub struct ColorContext {
 }
 
 impl ColorContext {
-    pub fn new() -> Self {
-        let mut context = BaseProviderContext::new();
-        let mut updatable_provider = UpdatableProviderContext::new();
-
-        context.set_values("baseNote0Color", vec4(1.0, 0.0, 0.0, 1.0).into());
-        let definition = Vector4PointDefinition::new(
-            json!(["baseNote0Color", [0.4, 0.4, 0.4, 1, "opMul"]]),
-            &mut context,
-            &mut updatable_provider,
-        );
-        Self {
+    pub fn new(
+        definition: Option<JsonValue>,
+        mut context: BaseProviderContext,
+    ) -> Result<Self, ParseError> {
+        let definition = definition.unwrap_or_else(|| {
+            context
+                .set_values("baseNote0Color", vec4(1.0, 0.0, 0.0, 1.0).into())
+                .unwrap();
+            json!(["baseNote0Color", [0.4, 0.4, 0.4, 1, "opMul"]])
+        });
+        let definition = Vector4PointDefinition::try_new(definition, &mut context)?;
+        Ok(Self {
             definition,
             context: RefCell::new(context),
-        }
+        })
     }
 }
 
-pub fn graph_color(
-    root: DrawingArea<BitMapBackend<'_, BGRXPixel>, Shift>,
-) -> (
-    ChartState<Cartesian2d<RangedCoordf64, RangedCoordf64>>,
-    ColorContext,
-) {
-    let mut chart = ChartBuilder::on(&root)
+pub fn graph_color<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+) -> ChartState<Cartesian2d<RangedCoordf64, RangedCoordf64>> {
+    let mut chart = ChartBuilder::on(root)
         .build_cartesian_2d(0.0..1.0, 0.0..1.0)
         .unwrap();
 
@@ -55,18 +51,19 @@ pub fn graph_color(
         .draw()
         .unwrap();
 
-    (chart.into_chart_state(), ColorContext::new())
+    chart.into_chart_state()
 }
 
-pub fn draw_color(
-    root: &DrawingArea<BitMapBackend<'_, BGRXPixel>, Shift>,
+pub fn draw_color<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
     chart: &ChartState<Cartesian2d<RangedCoordf64, RangedCoordf64>>,
     context: &ColorContext,
     _epoch: f64,
-    _window: &Window,
+    delta: f64,
 ) {
     {
-        let mut chart = chart.clone().restore(&root);
+        context.context.borrow_mut().tick(delta as f32);
+        let mut chart = chart.clone().restore(root);
         chart.plotting_area().fill(&WHITE).unwrap();
 
         chart
@@ -1,18 +1,17 @@
 use std::cell::RefCell;
 
 use plotters::{
-    backend::BGRXPixel,
     chart::{ChartBuilder, ChartState},
     coord::{Shift, types::RangedCoordf64},
-    prelude::{BitMapBackend, Cartesian2d, DiscreteRanged, DrawingArea, IntoLinspace},
+    prelude::{Cartesian2d, DiscreteRanged, DrawingArea, DrawingBackend, IntoLinspace},
     series::LineSeries,
     style::{BLACK, BLUE, Color, GREEN, IntoFont, TRANSPARENT},
 };
-use serde_json::json;
+use serde_json::{Value as JsonValue, json};
 
 use tracks_rs::{
-    point_definition::{PointDefinition, float_point_definition::FloatPointDefinition},
-    values::base_provider_context::{BaseProviderContext, UpdatableProviderContext},
+    error::ParseError, point_definition::float_point_definition::FloatPointDefinition,
+    values::base_provider_context::BaseProviderContext,
 };
 
 pub struct FloatContext {
@@ -21,28 +20,25 @@ pub struct FloatContext {
 }
 
 impl FloatContext {
-    pub fn new() -> Self {
-        let mut context = BaseProviderContext::new();
-        let mut updatable_provider = UpdatableProviderContext::new();
-        let definition = FloatPointDefinition::new(
-            json!([[0.0, 0.0], [1.0, 1.0, "easeInOutSine"]]),
+    pub fn new(
+        definition: Option<JsonValue>,
+        mut context: BaseProviderContext,
+    ) -> Result<Self, ParseError> {
+        let definition = FloatPointDefinition::try_new(
+            definition.unwrap_or_else(|| json!([[0.0, 0.0], [1.0, 1.0, "easeInOutSine"]])),
             &mut context,
-            &mut updatable_provider,
-        );
-        Self {
+        )?;
+        Ok(Self {
             definition,
             context: RefCell::new(context),
-        }
+        })
     }
 }
 
-pub fn graph_2d(
-    root: DrawingArea<BitMapBackend<'_, BGRXPixel>, Shift>,
-) -> (
-    ChartState<Cartesian2d<RangedCoordf64, RangedCoordf64>>,
-    FloatContext,
-) {
-    let mut chart = ChartBuilder::on(&root)
+pub fn graph_2d<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+) -> ChartState<Cartesian2d<RangedCoordf64, RangedCoordf64>> {
+    let mut chart = ChartBuilder::on(root)
         .margin(10)
         .set_all_label_area_size(30)
         .build_cartesian_2d(-1.2..1.2, -1.2..12.0)
@@ -55,17 +51,19 @@ pub fn graph_2d(
         .draw()
         .unwrap();
 
-    (chart.into_chart_state(), FloatContext::new())
+    chart.into_chart_state()
 }
 
-pub fn draw_2d(
-    root: &DrawingArea<BitMapBackend<'_, BGRXPixel>, Shift>,
+pub fn draw_2d<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
     chart: &ChartState<Cartesian2d<RangedCoordf64, RangedCoordf64>>,
     context: &FloatContext,
     _epoch: f64,
+    delta: f64,
 ) {
     {
-        let mut chart = chart.clone().restore(&root);
+        context.context.borrow_mut().tick(delta as f32);
+        let mut chart = chart.clone().restore(root);
         chart.plotting_area().fill(&BLACK).unwrap();
 
         chart
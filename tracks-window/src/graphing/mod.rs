@@ -1,5 +1,8 @@
 use std::{
     borrow::{Borrow, BorrowMut},
+    error::Error,
+    fs,
+    path::Path,
     time::SystemTime,
 };
 
@@ -7,11 +10,20 @@ use minifb::{Key, Window, WindowOptions};
 use plotters::{
     backend::BGRXPixel,
     chart::ChartState,
-    coord::{ranged3d::Cartesian3d, types::RangedCoordf64},
-    prelude::{BitMapBackend, Cartesian2d, IntoDrawingArea},
+    coord::{Shift, ranged3d::Cartesian3d, types::RangedCoordf64},
+    prelude::{BitMapBackend, Cartesian2d, DrawingArea, DrawingBackend, IntoDrawingArea},
     style::BLACK,
 };
+use serde_json::Value as JsonValue;
+use tracks_rs::{
+    error::{BaseProviderError, ParseError},
+    values::{base_provider_context::BaseProviderContext, value::BaseValue},
+};
 
+use crate::{
+    definition,
+    options::{GraphType, Options},
+};
 use color_graphing::ColorContext;
 use float_graphing::FloatContext;
 use quat_graphing::QuatContext;
@@ -21,9 +33,6 @@ pub mod float_graphing;
 pub mod quat_graphing;
 pub mod vec3_graphing;
 
-const W: usize = 800;
-const H: usize = 600;
-
 const FRAME_RATE: f64 = 60.0;
 
 struct BufferWrapper(Vec<u32>);
@@ -52,91 +61,139 @@ impl BorrowMut<[u32]> for BufferWrapper {
     }
 }
 
-enum GraphContext<'a> {
+pub enum Graph {
     Float2D(
-        &'a ChartState<Cartesian2d<RangedCoordf64, RangedCoordf64>>,
-        &'a FloatContext,
+        ChartState<Cartesian2d<RangedCoordf64, RangedCoordf64>>,
+        FloatContext,
     ),
     Vector3D(
-        &'a ChartState<Cartesian3d<RangedCoordf64, RangedCoordf64, RangedCoordf64>>,
-        &'a Vec3Context,
+        ChartState<Cartesian3d<RangedCoordf64, RangedCoordf64, RangedCoordf64>>,
+        Vec3Context,
     ),
     Color(
-        &'a ChartState<Cartesian2d<RangedCoordf64, RangedCoordf64>>,
-        &'a ColorContext,
+        ChartState<Cartesian2d<RangedCoordf64, RangedCoordf64>>,
+        ColorContext,
     ),
     Quaternion(
-        &'a ChartState<Cartesian3d<RangedCoordf64, RangedCoordf64, RangedCoordf64>>,
-        &'a QuatContext,
+        ChartState<Cartesian3d<RangedCoordf64, RangedCoordf64, RangedCoordf64>>,
+        QuatContext,
     ),
 }
 
-pub fn graph(context: &str) {
-    let mut window = Window::new("Tracks", W, H, WindowOptions::default()).unwrap();
-    let mut buf = BufferWrapper(vec![0u32; W * H]);
+impl Graph {
+    /// Loads the definition and graph type described by `options` and builds the graph.
+    pub fn load<DB: DrawingBackend>(
+        root: &DrawingArea<DB, Shift>,
+        options: &Options,
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut context = base_context(&options.bases)?;
+        let loaded = options
+            .definition
+            .as_ref()
+            .map(|source| source.load(&mut context))
+            .transpose()?;
 
-    let mut _binding_2d: Option<ChartState<Cartesian2d<RangedCoordf64, RangedCoordf64>>> = None;
-    let mut _binding_3d: Option<
-        ChartState<Cartesian3d<RangedCoordf64, RangedCoordf64, RangedCoordf64>>,
-    > = None;
-    let mut _binding_color: Option<ChartState<Cartesian2d<RangedCoordf64, RangedCoordf64>>> = None;
-    let mut _binding_quaternion: Option<
-        ChartState<Cartesian3d<RangedCoordf64, RangedCoordf64, RangedCoordf64>>,
-    > = None;
-    let mut _float_context: Option<FloatContext> = None;
-    let mut _vec3_context: Option<Vec3Context> = None;
-    let mut _color_context: Option<ColorContext> = None;
-    let mut _quaternion_context: Option<QuatContext> = None;
+        let graph_type = match (options.graph_type, &loaded) {
+            (Some(graph_type), _) => graph_type,
+            (None, None) => GraphType::Vector3,
+            (None, Some((definition, ty))) => definition::detect_type(definition, *ty, &context)
+                .ok_or("could not detect the point definition's type, pass --type")?,
+        };
 
-    let cs: GraphContext = {
-        let root = BitMapBackend::<BGRXPixel>::with_buffer_and_format(
-            buf.borrow_mut(),
-            (W as u32, H as u32),
-        )
-        .unwrap()
-        .into_drawing_area();
+        Ok(Self::new(
+            root,
+            graph_type,
+            loaded.map(|(definition, _)| definition),
+            context,
+        )?)
+    }
 
-        root.fill(&BLACK).unwrap();
+    /// Parses `definition` (or the demo definition for `graph_type` when `None`)
+    /// and draws the chart's static parts onto `root`.
+    ///
+    /// Nothing is drawn if the definition fails to parse.
+    pub fn new<DB: DrawingBackend>(
+        root: &DrawingArea<DB, Shift>,
+        graph_type: GraphType,
+        definition: Option<JsonValue>,
+        context: BaseProviderContext,
+    ) -> Result<Self, ParseError> {
+        Ok(match graph_type {
+            GraphType::Float => {
+                let context = FloatContext::new(definition, context)?;
+                root.fill(&BLACK).unwrap();
+                Graph::Float2D(float_graphing::graph_2d(root), context)
+            }
+            GraphType::Vector3 => {
+                let context = Vec3Context::new(definition, context)?;
+                root.fill(&BLACK).unwrap();
+                Graph::Vector3D(vec3_graphing::graph_vec3(root), context)
+            }
+            GraphType::Color => {
+                let context = ColorContext::new(definition, context)?;
+                root.fill(&BLACK).unwrap();
+                Graph::Color(color_graphing::graph_color(root), context)
+            }
+            GraphType::Quaternion => {
+                let context = QuatContext::new(definition, context)?;
+                root.fill(&BLACK).unwrap();
+                Graph::Quaternion(quat_graphing::graph_quat(root), context)
+            }
+        })
+    }
 
-        match context {
-            "2d" => {
-                let (new_binding, new_context) = float_graphing::graph_2d(root);
-                _binding_2d = Some(new_binding);
-                _float_context = Some(new_context);
-                GraphContext::Float2D(
-                    _binding_2d.as_ref().unwrap(),
-                    _float_context.as_ref().unwrap(),
-                )
+    /// Draws the frame at `epoch`, advancing smoothed providers by `delta` seconds.
+    pub fn draw<DB: DrawingBackend>(&self, root: &DrawingArea<DB, Shift>, epoch: f64, delta: f64) {
+        match self {
+            Graph::Float2D(state, context) => {
+                float_graphing::draw_2d(root, state, context, epoch, delta)
             }
-            "3d" => {
-                let (new_binding, new_context) = vec3_graphing::graph_vec3(root);
-                _binding_3d = Some(new_binding);
-                _vec3_context = Some(new_context);
-                GraphContext::Vector3D(
-                    _binding_3d.as_ref().unwrap(),
-                    _vec3_context.as_ref().unwrap(),
-                )
+            Graph::Vector3D(state, context) => {
+                vec3_graphing::draw_vec3(root, state, context, epoch, delta)
             }
-            "color" => {
-                let (new_binding, new_context) = color_graphing::graph_color(root);
-                _binding_color = Some(new_binding);
-                _color_context = Some(new_context);
-                GraphContext::Color(
-                    _binding_color.as_ref().unwrap(),
-                    _color_context.as_ref().unwrap(),
-                )
+            Graph::Color(state, context) => {
+                color_graphing::draw_color(root, state, context, epoch, delta)
             }
-            "quat" => {
-                let (new_binding, new_context) = quat_graphing::graph_quat(root);
-                _binding_quaternion = Some(new_binding);
-                _quaternion_context = Some(new_context);
-                GraphContext::Quaternion(
-                    _binding_quaternion.as_ref().unwrap(),
-                    _quaternion_context.as_ref().unwrap(),
-                )
+            Graph::Quaternion(state, context) => {
+                quat_graphing::draw_quat(root, state, context, epoch, delta)
             }
-            _ => panic!("Invalid context"),
         }
+    }
+}
+
+fn base_context(bases: &[(String, BaseValue)]) -> Result<BaseProviderContext, BaseProviderError> {
+    let mut context = BaseProviderContext::new();
+    for (name, value) in bases {
+        context.set_values(name, *value)?;
+    }
+    Ok(context)
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Opens a live window graphing `options`, reloading the definition whenever its file changes.
+pub fn graph(options: &Options) -> Result<(), Box<dyn Error>> {
+    let (width, height) = (options.width as usize, options.height as usize);
+    let mut window = Window::new("Tracks", width, height, WindowOptions::default())?;
+    let mut buf = BufferWrapper(vec![0u32; width * height]);
+
+    let watched = options
+        .definition
+        .as_ref()
+        .and_then(|source| source.watched_path());
+    let mut last_modified = watched.and_then(modified_time);
+
+    let mut graph = {
+        let root = BitMapBackend::<BGRXPixel>::with_buffer_and_format(
+            buf.borrow_mut(),
+            (width as u32, height as u32),
+        )
+        .unwrap()
+        .into_drawing_area();
+
+        Graph::load(&root, options)?
     };
 
     let start_ts = SystemTime::now();
@@ -152,29 +209,35 @@ pub fn graph(context: &str) {
             {
                 let root = BitMapBackend::<BGRXPixel>::with_buffer_and_format(
                     buf.borrow_mut(),
-                    (W as u32, H as u32),
+                    (width as u32, height as u32),
                 )
                 .unwrap()
                 .into_drawing_area();
-                match cs {
-                    GraphContext::Float2D(state, context) => {
-                        float_graphing::draw_2d(&root, state, context, epoch)
-                    }
-                    GraphContext::Vector3D(state, context) => {
-                        vec3_graphing::draw_vec3(&root, state, context, epoch, &window)
-                    }
-                    GraphContext::Color(state, context) => {
-                        color_graphing::draw_color(&root, state, context, epoch, &window)
-                    }
-                    GraphContext::Quaternion(state, context) => {
-                        quat_graphing::draw_quat(&root, state, context, epoch, &window)
+
+                if let Some(path) = watched {
+                    let modified = modified_time(path);
+                    if modified != last_modified {
+                        last_modified = modified;
+                        match Graph::load(&root, options) {
+                            Ok(reloaded) => {
+                                graph = reloaded;
+                                println!("reloaded {}", path.display());
+                            }
+                            Err(e) => eprintln!("error: {e}"),
+                        }
                     }
                 }
+
+                graph.draw(&root, epoch, epoch - last_flushed);
                 root.present().unwrap();
             }
 
-            window.update_with_buffer(buf.borrow(), W, H).unwrap();
+            window
+                .update_with_buffer(buf.borrow(), width, height)
+                .unwrap();
             last_flushed = epoch;
         }
     }
+
+    Ok(())
 }
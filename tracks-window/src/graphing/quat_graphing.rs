@@ -1,50 +1,49 @@
 use std::cell::RefCell;
 
 use glam::{Mat4, Vec3};
-use minifb::Window;
 use plotters::{
-    backend::BGRXPixel,
     chart::{ChartBuilder, ChartState},
     coord::{Shift, ranged3d::Cartesian3d, types::RangedCoordf64},
-    prelude::{BitMapBackend, DiscreteRanged, DrawingArea, IntoLinspace},
+    prelude::{DiscreteRanged, DrawingArea, DrawingBackend, IntoLinspace},
     series::LineSeries,
     style::{BLACK, Color, RED, RGBAColor, WHITE},
 };
-use serde_json::json;
+use serde_json::{Value as JsonValue, json};
 
 use tracks_rs::{
-    point_definition::{PointDefinition, quaternion_point_definition::QuaternionPointDefinition},
-    values::base_provider_context::{BaseProviderContext, UpdatableProviderContext},
+    error::ParseError, point_definition::quaternion_point_definition::QuaternionPointDefinition,
+    values::base_provider_context::BaseProviderContext,
 };
 
 pub struct QuatContext {
     pub definition: QuaternionPointDefinition,
     pub context: RefCell<BaseProviderContext>,
+    /// Whether `baseCombo` is animated over time, only done for the demo definition.
+    pub animated: bool,
 }
 
 impl QuatContext {
-    pub fn new() -> Self {
-        let mut context = BaseProviderContext::new();
-        let mut updatable_provider = UpdatableProviderContext::new();
-        let definition = QuaternionPointDefinition::new(
-            json!([0, "baseCombo", 0]),
+    pub fn new(
+        definition: Option<JsonValue>,
+        mut context: BaseProviderContext,
+    ) -> Result<Self, ParseError> {
+        let animated = definition.is_none();
+        let definition = QuaternionPointDefinition::try_new(
+            definition.unwrap_or_else(|| json!([0, "baseCombo", 0])),
             &mut context,
-            &mut updatable_provider,
-        );
-        Self {
+        )?;
+        Ok(Self {
             definition,
             context: RefCell::new(context),
-        }
+            animated,
+        })
     }
 }
 
-pub fn graph_quat(
-    root: DrawingArea<BitMapBackend<'_, BGRXPixel>, Shift>,
-) -> (
-    ChartState<Cartesian3d<RangedCoordf64, RangedCoordf64, RangedCoordf64>>,
-    QuatContext,
-) {
-    let mut chart = ChartBuilder::on(&root)
+pub fn graph_quat<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+) -> ChartState<Cartesian3d<RangedCoordf64, RangedCoordf64, RangedCoordf64>> {
+    let mut chart = ChartBuilder::on(root)
         .build_cartesian_3d(0.0..3.0, 0.0..3.0, 0.0..3.0)
         .unwrap();
 
@@ -61,22 +60,30 @@ pub fn graph_quat(
         .draw()
         .unwrap();
 
-    (chart.into_chart_state(), QuatContext::new())
+    chart.into_chart_state()
 }
 
-pub fn draw_quat(
-    root: &DrawingArea<BitMapBackend<'_, BGRXPixel>, Shift>,
+pub fn draw_quat<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
     chart: &ChartState<Cartesian3d<RangedCoordf64, RangedCoordf64, RangedCoordf64>>,
     context: &QuatContext,
     epoch: f64,
-    _window: &Window,
+    delta: f64,
 ) {
     {
-        context.context.borrow_mut().set_values(
-            "baseCombo",
-            ((epoch.sin() as f32 + 1.0) * 0.5 * 45.0).into(),
-        );
-        let mut chart = chart.clone().restore(&root);
+        if context.animated {
+            context
+                .context
+                .borrow_mut()
+                .set_values(
+                    "baseCombo",
+                    ((epoch.sin() as f32 + 1.0) * 0.5 * 45.0).into(),
+                )
+                .unwrap();
+        }
+        context.context.borrow_mut().tick(delta as f32);
+
+        let mut chart = chart.clone().restore(root);
         chart.plotting_area().fill(&WHITE).unwrap();
 
         chart.with_projection(|mut pb| {
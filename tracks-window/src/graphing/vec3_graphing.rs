@@ -1,58 +1,57 @@
 use std::cell::RefCell;
 
 use glam::Vec3;
-use minifb::Window;
 use plotters::{
-    backend::BGRXPixel,
     chart::{ChartBuilder, ChartState},
     coord::{Shift, ranged3d::Cartesian3d, types::RangedCoordf64},
-    prelude::{
-        BitMapBackend, Circle, DiscreteRanged, DrawingArea, EmptyElement, IntoLinspace, Text,
-    },
-    series::LineSeries,
+    prelude::{Circle, DrawingArea, DrawingBackend, EmptyElement, Text},
     style::{BLACK, BLUE, Color, IntoFont, RED, RGBColor, ShapeStyle, WHITE},
 };
-use serde_json::json;
+use serde_json::{Value as JsonValue, json};
 
 use tracks_rs::{
-    point_definition::{PointDefinition, vector3_point_definition::Vector3PointDefinition},
-    values::base_provider_context::{BaseProviderContext, UpdatableProviderContext},
+    error::ParseError, point_definition::vector3_point_definition::Vector3PointDefinition,
+    values::base_provider_context::BaseProviderContext,
 };
 
+const COLORS: [RGBColor; 2] = [RED, BLUE];
+
 pub struct Vec3Context {
-    pub definition: Vector3PointDefinition,
-    pub definition2: Vector3PointDefinition,
+    pub definitions: Vec<Vector3PointDefinition>,
     pub context: RefCell<BaseProviderContext>,
-    pub last_epoch: RefCell<f64>,
+    /// Whether `baseLeftHandPosition` is animated over time, only done for the demo definition.
+    pub animated: bool,
 }
 
 impl Vec3Context {
-    pub fn new() -> Self {
-        let mut context = BaseProviderContext::new();
-        let definition = Vector3PointDefinition::new(
-            json!(["baseLeftHandPosition"]),
-            &mut context,
-        );
-        let definition2 = Vector3PointDefinition::new(
-            json!(["baseLeftHandPosition.s10", [0, 0.2, 0, "opAdd"]]),
-            &mut context,
-        );
-        Self {
-            definition,
-            definition2,
+    pub fn new(
+        definition: Option<JsonValue>,
+        mut context: BaseProviderContext,
+    ) -> Result<Self, ParseError> {
+        let animated = definition.is_none();
+        let definitions = match definition {
+            Some(definition) => vec![definition],
+            None => vec![
+                json!(["baseLeftHandPosition"]),
+                json!(["baseLeftHandPosition.s10", [0, 0.2, 0, "opAdd"]]),
+            ],
+        };
+        let definitions = definitions
+            .into_iter()
+            .map(|definition| Vector3PointDefinition::try_new(definition, &mut context))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            definitions,
             context: RefCell::new(context),
-            last_epoch: RefCell::new(0.0),
-        }
+            animated,
+        })
     }
 }
 
-pub fn graph_vec3(
-    root: DrawingArea<BitMapBackend<'_, BGRXPixel>, Shift>,
-) -> (
-    ChartState<Cartesian3d<RangedCoordf64, RangedCoordf64, RangedCoordf64>>,
-    Vec3Context,
-) {
-    let mut chart = ChartBuilder::on(&root)
+pub fn graph_vec3<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+) -> ChartState<Cartesian3d<RangedCoordf64, RangedCoordf64, RangedCoordf64>> {
+    let mut chart = ChartBuilder::on(root)
         .caption("3D Plot Test", ("sans", 20))
         .build_cartesian_3d(0.0..3.0, 0.0..3.0, 0.0..3.0)
         .unwrap();
@@ -70,26 +69,30 @@ pub fn graph_vec3(
         .draw()
         .unwrap();
 
-    (chart.into_chart_state(), Vec3Context::new())
+    chart.into_chart_state()
 }
 
-pub fn draw_vec3(
-    root: &DrawingArea<BitMapBackend<'_, BGRXPixel>, Shift>,
+pub fn draw_vec3<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
     chart: &ChartState<Cartesian3d<RangedCoordf64, RangedCoordf64, RangedCoordf64>>,
     context: &Vec3Context,
     epoch: f64,
-    _window: &Window,
+    delta: f64,
 ) {
     {
-        context.context.borrow_mut().set_values(
-            "baseLeftHandPosition",
-            Vec3::new(epoch.sin() as f32 + 1.0, 2.0, 3.0).into(),
-        );
-        let mut chart: plotters::prelude::ChartContext<
-            '_,
-            BitMapBackend<'_, BGRXPixel>,
-            Cartesian3d<RangedCoordf64, RangedCoordf64, RangedCoordf64>,
-        > = chart.clone().restore(&root);
+        if context.animated {
+            context
+                .context
+                .borrow_mut()
+                .set_values(
+                    "baseLeftHandPosition",
+                    Vec3::new(epoch.sin() as f32 + 1.0, 2.0, 3.0).into(),
+                )
+                .unwrap();
+        }
+        context.context.borrow_mut().tick(delta as f32);
+
+        let mut chart = chart.clone().restore(root);
         chart.plotting_area().fill(&WHITE).unwrap();
 
         chart.with_projection(|mut pb| {
@@ -105,13 +108,8 @@ pub fn draw_vec3(
             .draw()
             .unwrap();
 
-        let delta = epoch - context.last_epoch.borrow().clone();
-        context.last_epoch.replace(epoch);
-
-        context.updatable_provider.update(delta as f32, &mut context.context.borrow_mut());
-
         let dot_and_label = |x: f64, y: f64, z: f64, color: RGBColor| {
-            return EmptyElement::<(f64, f64, f64), BitMapBackend<BGRXPixel>>::at((x, y, z))
+            return EmptyElement::<(f64, f64, f64), DB>::at((x, y, z))
                 + Circle::new((0, 0), 3, ShapeStyle::from(&color).filled())
                 + Text::new(
                     format!("({:.2},{:.2},{:.2})", x, y, z),
@@ -121,30 +119,17 @@ pub fn draw_vec3(
         };
 
         let mut draw_t = |x: f32| {
-            let point = context
-                .definition
-                .interpolate(x as f32, &context.context.borrow())
-                .0;
-            let point2 = context
-                .definition2
-                .interpolate(x as f32, &context.context.borrow())
-                .0;
-            chart
-                .draw_series(std::iter::once(dot_and_label(
-                    point.x as f64,
-                    point.y as f64,
-                    point.z as f64,
-                    RED,
-                )))
-                .unwrap();
-            chart
-                .draw_series(std::iter::once(dot_and_label(
-                    point2.x as f64,
-                    point2.y as f64,
-                    point2.z as f64,
-                    BLUE,
-                )))
-                .unwrap();
+            for (definition, color) in context.definitions.iter().zip(COLORS.iter().cycle()) {
+                let point = definition.interpolate(x, &context.context.borrow()).0;
+                chart
+                    .draw_series(std::iter::once(dot_and_label(
+                        point.x as f64,
+                        point.y as f64,
+                        point.z as f64,
+                        *color,
+                    )))
+                    .unwrap();
+            }
         };
 
         draw_t(0.0);
@@ -0,0 +1,60 @@
+use std::error::Error;
+
+use plotters::{
+    coord::Shift,
+    prelude::{BitMapBackend, DrawingArea, DrawingBackend, IntoDrawingArea, SVGBackend},
+};
+
+use crate::{
+    graphing::Graph,
+    options::{Options, Output, OutputFormat},
+};
+
+/// Renders the graph to `output` without opening a window.
+///
+/// PNG and SVG outputs are a single frame at `options.time`; GIF outputs are
+/// `options.frames` frames starting at `options.time`, spaced `1 / options.fps` apart.
+pub fn render(output: &Output, options: &Options) -> Result<(), Box<dyn Error>> {
+    let size = (options.width, options.height);
+
+    match output.format {
+        OutputFormat::Png => {
+            let root = BitMapBackend::new(&output.path, size).into_drawing_area();
+            render_frames(&root, options, &[options.time])
+        }
+        OutputFormat::Svg => {
+            let root = SVGBackend::new(&output.path, size).into_drawing_area();
+            render_frames(&root, options, &[options.time])
+        }
+        OutputFormat::Gif => {
+            let frame_delay = (1000.0 / options.fps).round() as u32;
+            let root = BitMapBackend::gif(&output.path, size, frame_delay)?.into_drawing_area();
+            let epochs = (0..options.frames)
+                .map(|frame| options.time + frame as f64 / options.fps)
+                .collect::<Vec<_>>();
+            render_frames(&root, options, &epochs)
+        }
+    }
+}
+
+/// Draws one frame per epoch, presenting after each so animated backends emit a frame.
+fn render_frames<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    options: &Options,
+    epochs: &[f64],
+) -> Result<(), Box<dyn Error>>
+where
+    DB::ErrorType: 'static,
+{
+    let graph = Graph::load(root, options)?;
+
+    // smoothing starts from rest at time zero
+    let mut last_epoch = 0.0;
+    for &epoch in epochs {
+        graph.draw(root, epoch, epoch - last_epoch);
+        last_epoch = epoch;
+        root.present()?;
+    }
+
+    Ok(())
+}
@@ -1,8 +1,35 @@
+use std::process::ExitCode;
+
+use options::Options;
+
+mod definition;
 mod graphing;
+mod headless;
+mod options;
+
+fn main() -> ExitCode {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", options::USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("error: {e}\n\n{}", options::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match &options.output {
+        Some(output) => headless::render(output, &options),
+        None => graphing::graph(&options),
+    };
 
-fn main() {
-    //graphing::graph("2d");
-    graphing::graph("3d");
-    //graphing::graph("color");
-    //graphing::graph("quat");
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
 }
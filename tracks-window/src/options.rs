@@ -0,0 +1,196 @@
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use serde_json::json;
+use tracks_rs::{easings::functions::Functions, values::value::BaseValue};
+
+use crate::definition::{self, DefinitionSource};
+
+pub const USAGE: &str = "\
+Usage: tracks-window [options]
+
+Options:
+  -t, --type <float|vec3|color|quat>  Kind of point definition to graph (default: detected from arity)
+  -d, --definition <json|file|name>   Point definition as inline JSON, a path to a JSON file,
+                                      or the name of a definition in --map
+  -m, --map <file>                    Beatmap to look up named point definitions in
+  -e, --easing <name>                 Graph an easing from 0 to 1, e.g. easeOutBack or cubic-bezier(0.3,0,0.2,1)
+  -b, --base <name=x[,y...]>          Set a base provider value, e.g. baseHeadPosition=0,1.5,0
+  -o, --output <file>                 Render headlessly to a .png, .svg or .gif instead of opening a window
+      --width <px>                    Output width (default: 800)
+      --height <px>                   Output height (default: 600)
+      --time <seconds>                Time to render a still image at (default: 0)
+      --frames <count>                Number of frames in an animated GIF (default: 120)
+      --fps <rate>                    Frame rate of an animated GIF (default: 30)
+  -h, --help                          Print this message";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphType {
+    Float,
+    Vector3,
+    Color,
+    Quaternion,
+}
+
+impl FromStr for GraphType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "float" | "2d" => Ok(GraphType::Float),
+            "vec3" | "vector3" | "3d" => Ok(GraphType::Vector3),
+            "color" | "vec4" | "vector4" => Ok(GraphType::Color),
+            "quat" | "quaternion" => Ok(GraphType::Quaternion),
+            _ => Err(format!("unknown graph type `{s}`")),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    Png,
+    Svg,
+    Gif,
+}
+
+impl OutputFormat {
+    fn from_path(path: &Path) -> Result<Self, String> {
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_ascii_lowercase());
+
+        match extension.as_deref() {
+            Some("png") => Ok(OutputFormat::Png),
+            Some("svg") => Ok(OutputFormat::Svg),
+            Some("gif") => Ok(OutputFormat::Gif),
+            _ => Err(format!(
+                "cannot infer output format of `{}`, expected .png, .svg or .gif",
+                path.display()
+            )),
+        }
+    }
+}
+
+pub struct Output {
+    pub path: PathBuf,
+    pub format: OutputFormat,
+}
+
+pub struct Options {
+    /// `None` detects the type from the definition.
+    pub graph_type: Option<GraphType>,
+    /// `None` graphs the built-in demo definition for the graph type.
+    pub definition: Option<DefinitionSource>,
+    pub bases: Vec<(String, BaseValue)>,
+    pub output: Option<Output>,
+    pub width: u32,
+    pub height: u32,
+    pub time: f64,
+    pub frames: u32,
+    pub fps: f64,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            graph_type: None,
+            definition: None,
+            bases: Vec::new(),
+            output: None,
+            width: 800,
+            height: 600,
+            time: 0.0,
+            frames: 120,
+            fps: 30.0,
+        }
+    }
+}
+
+impl Options {
+    /// Parses the command line, returning `Ok(None)` when help was requested.
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut options = Options::default();
+        let mut definition = None;
+        let mut map = None;
+        let mut easing = None;
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .ok_or_else(|| format!("missing value for `{name}`"))
+            };
+
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "-t" | "--type" => options.graph_type = Some(value(&arg)?.parse()?),
+                "-d" | "--definition" => definition = Some(value(&arg)?),
+                "-m" | "--map" => map = Some(PathBuf::from(value(&arg)?)),
+                "-e" | "--easing" => easing = Some(value(&arg)?),
+                "-b" | "--base" => options.bases.push(definition::parse_base(&value(&arg)?)?),
+                "-o" | "--output" => {
+                    let path = PathBuf::from(value(&arg)?);
+                    let format = OutputFormat::from_path(&path)?;
+                    options.output = Some(Output { path, format });
+                }
+                "--width" => options.width = parse_number(&arg, &value(&arg)?)?,
+                "--height" => options.height = parse_number(&arg, &value(&arg)?)?,
+                "--time" => options.time = parse_number(&arg, &value(&arg)?)?,
+                "--frames" => options.frames = parse_number(&arg, &value(&arg)?)?,
+                "--fps" => options.fps = parse_number(&arg, &value(&arg)?)?,
+                _ => return Err(format!("unexpected argument `{arg}`")),
+            }
+        }
+
+        options.definition = match (definition, map, easing) {
+            (None, None, None) => None,
+            (None, None, Some(easing)) => {
+                options.graph_type.get_or_insert(GraphType::Float);
+                Some(easing_source(&easing)?)
+            }
+            (Some(_), _, Some(_)) => {
+                return Err("`--easing` cannot be combined with `--definition`".to_string());
+            }
+            (None, Some(_), _) => return Err("`--map` needs a definition name".to_string()),
+            (Some(definition), map, None) => Some(definition_source(definition, map)),
+        };
+
+        if options.width == 0 || options.height == 0 {
+            return Err("output size must be non-zero".to_string());
+        }
+        if options.frames == 0 || options.fps <= 0.0 {
+            return Err("animations need at least one frame and a positive frame rate".to_string());
+        }
+
+        Ok(Some(options))
+    }
+}
+
+fn parse_number<T: FromStr>(name: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value `{value}` for `{name}`"))
+}
+
+/// Inline JSON wins; otherwise the value names a definition in the map, or a JSON file.
+fn definition_source(value: String, map: Option<PathBuf>) -> DefinitionSource {
+    if let Ok(json) = serde_json::from_str(&value) {
+        return DefinitionSource::Inline(json);
+    }
+
+    match map {
+        Some(path) => DefinitionSource::Map { path, name: value },
+        None => DefinitionSource::File(PathBuf::from(value)),
+    }
+}
+
+/// A float definition going from 0 to 1 with `easing`.
+fn easing_source(easing: &str) -> Result<DefinitionSource, String> {
+    Functions::from_str(easing).map_err(|_| format!("unknown easing `{easing}`"))?;
+    Ok(DefinitionSource::Inline(json!([
+        [0.0, 0.0],
+        [1.0, 1.0, easing]
+    ])))
+}